@@ -2,18 +2,56 @@
 //!
 //! The builder exposes the BoardBuilder, which can be used to create custom games
 
-use super::{Board, Color, Piece, Square, BLACK, WHITE};
+use super::{Board, Color, Piece, PieceKind, Position, VariantRules, BLACK, WHITE};
+
+/// ## PositionError
+///
+/// Describes why the position set up with `BoardBuilder::try_build` is not valid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionError {
+    /// The piece at this position has a different position
+    PieceMismatch(Position),
+    /// The player owning the king at this position has more than one king
+    TooManyKings(Position),
+    /// The player has no king, but the rules require one
+    MissingKing(Color),
+    /// There's a pawn on the first or on the last rank at this position
+    PawnOnBackRank(Position),
+    /// The en passant square is not consistent with the position
+    BadEnPassant(Position),
+    /// The king at this position is in check, but its player is not moving
+    OpponentInCheck(Position),
+}
+
+impl core::fmt::Display for PositionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::PieceMismatch(pos) => write!(f, "The piece at {} has a different position", pos),
+            Self::TooManyKings(pos) => write!(f, "Too many kings (found another one at {})", pos),
+            Self::MissingKing(color) => write!(f, "{} has no king", color),
+            Self::PawnOnBackRank(pos) => write!(f, "Pawn on the back rank at {}", pos),
+            Self::BadEnPassant(pos) => write!(f, "Bad en passant square {}", pos),
+            Self::OpponentInCheck(pos) => {
+                write!(f, "The king at {} is in check, but it's not its turn", pos)
+            }
+        }
+    }
+}
 
 /// ## BoardBuilder
 ///
 /// The board builder is the struct which provides a helper to build custom chess games
 pub struct BoardBuilder {
     board: Board,
+    en_passant: Option<Position>,
 }
 
 impl From<Board> for BoardBuilder {
     fn from(board: Board) -> Self {
-        Self { board }
+        Self {
+            board,
+            en_passant: board.get_en_passant(),
+        }
     }
 }
 
@@ -22,7 +60,10 @@ impl Default for BoardBuilder {
         let mut board = Board::empty();
         board.white_castling_rights.disable_all();
         board.black_castling_rights.disable_all();
-        Self { board }
+        Self {
+            board,
+            en_passant: None,
+        }
     }
 }
 
@@ -30,46 +71,91 @@ impl BoardBuilder {
     /// ### row
     ///
     /// Fill piece row with clones of piece
-    pub fn row(mut self, piece: Piece) -> Self {
-        let mut pos = piece.get_pos();
-        while pos.get_col() > 0 {
-            pos = pos.next_left()
-        }
-
-        for _ in 0..8 {
-            *self.board.get_square(pos) = Square::from(piece.move_to(pos));
-            pos = pos.next_right();
-        }
-
-        self
+    pub fn row(self, piece: Piece) -> Self {
+        self.fill_rank(piece.get_pos().get_row(), piece.kind(), piece.get_color())
     }
 
     /// ### column
     ///
     /// Fill piece column with clones of piece
-    pub fn column(mut self, piece: Piece) -> Self {
-        let mut pos = piece.get_pos();
-        while pos.get_row() > 0 {
-            pos = pos.next_below()
+    pub fn column(self, piece: Piece) -> Self {
+        self.fill_file(piece.get_pos().get_col(), piece.kind(), piece.get_color())
+    }
+
+    /// ### fill_rank
+    ///
+    /// Fill the rank at index `rank` (0 is the first rank, 7 the eighth) with pieces of the provided kind and color.
+    /// Nothing is done if the index is out of the board
+    pub fn fill_rank(mut self, rank: i32, kind: PieceKind, color: Color) -> Self {
+        for col in 0..8 {
+            let pos: Position = Position::new(rank, col);
+            if pos.is_on_board() {
+                self.board.put_piece(pos, Piece::new(kind, color, pos));
+            }
         }
+        self
+    }
 
-        for _ in 0..8 {
-            *self.board.get_square(pos) = Square::from(piece.move_to(pos));
-            pos = pos.next_above();
+    /// ### fill_file
+    ///
+    /// Fill the file at index `file` (0 is the a-file, 7 the h-file) with pieces of the provided kind and color.
+    /// Nothing is done if the index is out of the board
+    pub fn fill_file(mut self, file: i32, kind: PieceKind, color: Color) -> Self {
+        for row in 0..8 {
+            let pos: Position = Position::new(row, file);
+            if pos.is_on_board() {
+                self.board.put_piece(pos, Piece::new(kind, color, pos));
+            }
         }
+        self
+    }
 
+    /// ### clear_square
+    ///
+    /// Remove the piece at `pos`, if any
+    pub fn clear_square(mut self, pos: Position) -> Self {
+        if pos.is_on_board() {
+            self.board.take_piece(pos);
+        }
         self
     }
 
+    /// ### clear_rank
+    ///
+    /// Remove all the pieces on the rank at index `rank` (0 is the first rank, 7 the eighth)
+    pub fn clear_rank(self, rank: i32) -> Self {
+        (0..8).fold(self, |builder, col| {
+            builder.clear_square(Position::new(rank, col))
+        })
+    }
+
+    /// ### clear_all
+    ///
+    /// Remove all the pieces from the board
+    pub fn clear_all(self) -> Self {
+        (0..8).fold(self, |builder, rank| builder.clear_rank(rank))
+    }
+
     /// ### piece
     ///
     /// Put `piece` in the board
     pub fn piece(mut self, piece: Piece) -> Self {
-        let pos = piece.get_pos();
-        *self.board.get_square(pos) = Square::from(piece);
+        self.board.add_piece(piece);
         self
     }
 
+    /// ### symmetric
+    ///
+    /// Put `piece` in the board together with its mirror for the opponent on the opposite side of the board
+    /// (e.g. a white knight on b1 also puts a black knight on b8)
+    pub fn symmetric(self, piece: Piece) -> Self {
+        let pos: Position = piece.get_pos();
+        let mirror: Piece = piece
+            .with_color(!piece.get_color())
+            .move_to(Position::new(7 - pos.get_row(), pos.get_col()));
+        self.piece(piece).piece(mirror)
+    }
+
     /// ### enable_castling
     ///
     /// Enable castling rights for both king and queen for both players
@@ -140,11 +226,100 @@ impl BoardBuilder {
         self
     }
 
+    /// ### en_passant
+    ///
+    /// Set the square behind the pawn which has just moved two squares, where it can be captured en passant.
+    /// The square is validated when the board is built: it must be on the sixth rank if white is moving
+    /// or on the third rank if black is moving, with the pawn of the opponent in front of it and nothing behind it,
+    /// otherwise it is discarded (or rejected by `try_build`)
+    pub fn en_passant(mut self, pos: Position) -> Self {
+        self.en_passant = Some(pos);
+        self
+    }
+
     /// ### build
     ///
-    /// Get board with selected options
+    /// Get board with selected options.
+    /// The position is not validated, so it can be used to set up positions for tests and variants;
+    /// use `try_build` to validate it
     pub fn build(self) -> Board {
-        self.board
+        let board: Board = self.board;
+        let en_passant: Option<Position> = self
+            .en_passant
+            .filter(|pos| Self::is_consistent_en_passant(&board, *pos));
+        board.with_en_passant(en_passant)
+    }
+
+    /// ### try_build
+    ///
+    /// Get board with selected options, making sure the position makes sense:
+    ///
+    /// 1. every piece knows the square it stands on
+    /// 2. each player has at most a king (exactly one, unless the variant rules allow the player to have no king)
+    /// 3. there are no pawns on the first and on the last rank (unless the variant rules allow pawns to start there)
+    /// 4. the en passant square, if set, is consistent with the player moving and with the pawns on the board
+    /// 5. the player who is not moving is not in check
+    pub fn try_build(self) -> Result<Board, PositionError> {
+        if let Some(pos) = self.en_passant {
+            if !Self::is_consistent_en_passant(&self.board, pos) {
+                return Err(PositionError::BadEnPassant(pos));
+            }
+        }
+        let board: Board = self.build();
+        for row in 0..8 {
+            for col in 0..8 {
+                let pos: Position = Position::new(row, col);
+                let piece: Piece = match board.squares[Board::square_index(pos)].get_piece() {
+                    Some(piece) => piece,
+                    None => continue,
+                };
+                let color: Color = piece.get_color();
+                if piece.get_pos() != pos {
+                    return Err(PositionError::PieceMismatch(pos));
+                }
+                if piece.is_pawn()
+                    && (row == 0 || row == 7)
+                    && !board.rules.pawn_double_step_ranks(color).contains(&row)
+                {
+                    return Err(PositionError::PawnOnBackRank(pos));
+                }
+            }
+        }
+        for color in Color::both().iter() {
+            let mut kings = board
+                .pieces()
+                .filter(|x| x.is_king() && x.get_color() == *color)
+                .map(|x| x.get_pos());
+            match (kings.next(), kings.next()) {
+                (_, Some(pos)) => return Err(PositionError::TooManyKings(pos)),
+                (None, _) if board.rules.requires_king(*color) => {
+                    return Err(PositionError::MissingKing(*color))
+                }
+                _ => {}
+            }
+        }
+        let waiting: Color = !board.get_turn();
+        match board.get_king_pos(waiting) {
+            Some(king) if board.is_in_check(waiting) => Err(PositionError::OpponentInCheck(king)),
+            _ => Ok(board),
+        }
+    }
+
+    /// ### is_consistent_en_passant
+    ///
+    /// Returns whether `pos` can be the en passant square of `board`, given the player moving
+    fn is_consistent_en_passant(board: &Board, pos: Position) -> bool {
+        let pusher: Color = !board.get_turn();
+        let rank: i32 = match pusher {
+            WHITE => 2,
+            BLACK => 5,
+        };
+        pos.is_on_board()
+            && pos.get_row() == rank
+            && board.has_no_piece(pos)
+            && board.has_no_piece(pos.pawn_back(pusher))
+            && board.get_piece(pos.pawn_up(pusher))
+                == Some(Piece::Pawn(pusher, pos.pawn_up(pusher)))
     }
 }
 
@@ -153,7 +328,7 @@ mod test {
 
     use super::*;
     use crate::position::*;
-    use crate::{BLACK, WHITE};
+    use crate::{Move, BLACK, WHITE};
 
     use pretty_assertions::assert_eq;
 
@@ -231,6 +406,50 @@ mod test {
         assert_eq!(board.get_piece(A8).unwrap(), Piece::Queen(WHITE, A8));
     }
 
+    #[test]
+    fn fill_rank_and_file() {
+        let board: Board = BoardBuilder::default()
+            .fill_rank(6, PieceKind::Pawn, BLACK)
+            .fill_file(4, PieceKind::Rook, WHITE)
+            .fill_rank(8, PieceKind::Queen, WHITE)
+            .build();
+        assert_eq!(board.get_piece(A7).unwrap(), Piece::Pawn(BLACK, A7));
+        assert_eq!(board.get_piece(H7).unwrap(), Piece::Pawn(BLACK, H7));
+        assert_eq!(board.get_piece(E1).unwrap(), Piece::Rook(WHITE, E1));
+        assert_eq!(board.get_piece(E7).unwrap(), Piece::Rook(WHITE, E7));
+        assert_eq!(board.get_piece(E8).unwrap(), Piece::Rook(WHITE, E8));
+        assert_eq!(board.pieces().count(), 15);
+        assert_eq!(board.material.material(WHITE), 8 * 5);
+    }
+
+    #[test]
+    fn clear() {
+        // Default position without queens
+        let board: Board = BoardBuilder::from(Board::default())
+            .clear_square(D1)
+            .clear_square(D8)
+            .build();
+        assert_eq!(board.get_piece(D1), None);
+        assert_eq!(board.get_piece(D8), None);
+        assert_eq!(board.pieces().count(), 30);
+        assert_eq!(
+            board.material.material(WHITE),
+            Board::default().material.material(WHITE) - 9
+        );
+        // The king can step on the queen square too
+        assert_eq!(board.get_legal_moves(WHITE).len(), 21);
+        let board: Board = BoardBuilder::from(Board::default())
+            .clear_rank(1)
+            .clear_rank(6)
+            .build();
+        assert_eq!(board.pieces().filter(|x| x.is_pawn()).count(), 0);
+        assert_eq!(board.pieces().count(), 16);
+        let board: Board = BoardBuilder::from(Board::default()).clear_all().build();
+        assert_eq!(board.pieces().count(), 0);
+        assert_eq!(board.material.material(WHITE), 0);
+        assert_eq!(board.material.material(BLACK), 0);
+    }
+
     #[test]
     fn piece() {
         let board: Board = BoardBuilder::default()
@@ -241,6 +460,182 @@ mod test {
         assert_eq!(board.get_piece(H8).unwrap(), Piece::Rook(BLACK, H8));
     }
 
+    #[test]
+    fn symmetric() {
+        let board: Board = BoardBuilder::default()
+            .symmetric(Piece::King(WHITE, E1))
+            .symmetric(Piece::Knight(WHITE, B1))
+            .symmetric(Piece::Bishop(BLACK, C8))
+            .fill_rank(1, PieceKind::Pawn, WHITE)
+            .fill_rank(6, PieceKind::Pawn, BLACK)
+            .build();
+        assert_eq!(board.get_piece(E1).unwrap(), Piece::King(WHITE, E1));
+        assert_eq!(board.get_piece(E8).unwrap(), Piece::King(BLACK, E8));
+        assert_eq!(board.get_piece(B1).unwrap(), Piece::Knight(WHITE, B1));
+        assert_eq!(board.get_piece(B8).unwrap(), Piece::Knight(BLACK, B8));
+        assert_eq!(board.get_piece(C8).unwrap(), Piece::Bishop(BLACK, C8));
+        assert_eq!(board.get_piece(C1).unwrap(), Piece::Bishop(WHITE, C1));
+        assert_eq!(board.pieces().count(), 22);
+        assert_eq!(
+            board.get_legal_moves(WHITE).len(),
+            board.change_turn().get_legal_moves(BLACK).len()
+        );
+        assert_eq!(board.get_material_advantage(WHITE), 0);
+    }
+
+    #[test]
+    fn en_passant() {
+        let builder = || {
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .piece(Piece::Pawn(WHITE, E5))
+                .piece(Piece::King(BLACK, E8))
+                .piece(Piece::Pawn(BLACK, D5))
+        };
+        let board: Board = builder().en_passant(D6).build();
+        assert_eq!(board.get_en_passant(), Some(D6));
+        assert!(board.get_legal_moves(WHITE).contains(&Move::Piece(E5, D6)));
+        // Not consistent with the player moving
+        let board: Board = builder().en_passant(D6).player_moving(BLACK).build();
+        assert_eq!(board.get_en_passant(), None);
+        let board: Board = builder().en_passant(D3).build();
+        assert_eq!(board.get_en_passant(), None);
+        assert!(!board.get_legal_moves(WHITE).contains(&Move::Piece(E5, D6)));
+        // Kept when building from a board
+        let board: Board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").unwrap();
+        assert_eq!(BoardBuilder::from(board).build().get_en_passant(), Some(D6));
+        // Discarded once the pawns are removed
+        let board: Board = BoardBuilder::from(board).clear_all().build();
+        assert_eq!(board.get_en_passant(), None);
+    }
+
+    #[test]
+    fn try_build() {
+        assert_eq!(
+            BoardBuilder::from(Board::default()).try_build(),
+            Ok(Board::default())
+        );
+        assert_eq!(
+            BoardBuilder::from(Board::horde()).try_build(),
+            Ok(Board::horde())
+        );
+        let board: Board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").unwrap();
+        assert_eq!(BoardBuilder::from(board).try_build(), Ok(board));
+    }
+
+    #[test]
+    fn try_build_piece_mismatch() {
+        let mut builder: BoardBuilder = BoardBuilder::from(Board::default());
+        builder.board.squares[Board::square_index(E4)].set_piece(Piece::Queen(WHITE, D1));
+        assert_eq!(builder.try_build(), Err(PositionError::PieceMismatch(E4)));
+    }
+
+    #[test]
+    fn try_build_too_many_kings() {
+        let kings = || {
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .piece(Piece::King(BLACK, E8))
+        };
+        assert_eq!(
+            kings().piece(Piece::King(WHITE, H1)).try_build(),
+            Err(PositionError::TooManyKings(H1))
+        );
+        assert_eq!(
+            kings().piece(Piece::King(BLACK, H8)).try_build(),
+            Err(PositionError::TooManyKings(H8))
+        );
+    }
+
+    #[test]
+    fn try_build_missing_king() {
+        assert_eq!(
+            BoardBuilder::default()
+                .piece(Piece::King(BLACK, E8))
+                .try_build(),
+            Err(PositionError::MissingKing(WHITE))
+        );
+        assert_eq!(
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .try_build(),
+            Err(PositionError::MissingKing(BLACK))
+        );
+        // White has no king in Horde
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Pawn(WHITE, E2))
+            .build()
+            .with_horde_rules(true);
+        assert_eq!(BoardBuilder::from(board).try_build(), Ok(board));
+    }
+
+    #[test]
+    fn try_build_pawn_on_back_rank() {
+        let kings = || {
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .piece(Piece::King(BLACK, E8))
+        };
+        assert_eq!(
+            kings().piece(Piece::Pawn(WHITE, A1)).try_build(),
+            Err(PositionError::PawnOnBackRank(A1))
+        );
+        assert_eq!(
+            kings().piece(Piece::Pawn(BLACK, H8)).try_build(),
+            Err(PositionError::PawnOnBackRank(H8))
+        );
+        assert_eq!(
+            kings().piece(Piece::Pawn(WHITE, H8)).try_build(),
+            Err(PositionError::PawnOnBackRank(H8))
+        );
+    }
+
+    #[test]
+    fn try_build_bad_en_passant() {
+        let builder = || {
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .piece(Piece::Pawn(WHITE, E5))
+                .piece(Piece::King(BLACK, E8))
+                .piece(Piece::Pawn(BLACK, D5))
+        };
+        assert!(builder().en_passant(D6).try_build().is_ok());
+        // Wrong rank
+        assert_eq!(
+            builder().en_passant(D3).try_build(),
+            Err(PositionError::BadEnPassant(D3))
+        );
+        // No pawn which has just moved two squares
+        assert_eq!(
+            builder().en_passant(C6).try_build(),
+            Err(PositionError::BadEnPassant(C6))
+        );
+        // The pawn couldn't have moved from its starting square
+        assert_eq!(
+            builder()
+                .piece(Piece::Bishop(BLACK, D7))
+                .en_passant(D6)
+                .try_build(),
+            Err(PositionError::BadEnPassant(D6))
+        );
+    }
+
+    #[test]
+    fn try_build_opponent_in_check() {
+        let builder = || {
+            BoardBuilder::default()
+                .piece(Piece::King(WHITE, E1))
+                .piece(Piece::Rook(WHITE, E4))
+                .piece(Piece::King(BLACK, E8))
+        };
+        assert_eq!(
+            builder().try_build(),
+            Err(PositionError::OpponentInCheck(E8))
+        );
+        assert!(builder().player_moving(BLACK).try_build().is_ok());
+    }
+
     #[test]
     fn player_moving() {
         let board: Board = BoardBuilder::default().player_moving(BLACK).build();
@@ -1,26 +1,39 @@
 //! # CastlingRights
 //!
-//! This module exposes the castling rights type
+//! This module exposes the castling rights type.
+//! Besides the availability, the castling rights keep the files of the rooks involved in castling,
+//! which differ from the a and h files in Chess960 positions
 
 /// ### CastlingRights
 ///
 /// Defines the castling rights for the game
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CastlingRights {
     kingside: bool,
     queenside: bool,
+    kingside_rook_file: u8,
+    queenside_rook_file: u8,
 }
 
 impl Default for CastlingRights {
     fn default() -> Self {
-        Self {
-            kingside: true,
-            queenside: true,
-        }
+        Self::new(true, true)
     }
 }
 
 impl CastlingRights {
+    /// ### new
+    ///
+    /// Instantiate new castling rights. The rooks are on the h and a files
+    pub const fn new(kingside: bool, queenside: bool) -> Self {
+        Self {
+            kingside,
+            queenside,
+            kingside_rook_file: 7,
+            queenside_rook_file: 0,
+        }
+    }
+
     /// ### can_kingside_castle
     ///
     /// returns whether kingside castle is enabled
@@ -35,6 +48,41 @@ impl CastlingRights {
         self.queenside
     }
 
+    /// ### kingside_rook_file
+    ///
+    /// returns the file (0-7) of the rook used for kingside castling
+    pub fn kingside_rook_file(&self) -> u8 {
+        self.kingside_rook_file
+    }
+
+    /// ### queenside_rook_file
+    ///
+    /// returns the file (0-7) of the rook used for queenside castling
+    pub fn queenside_rook_file(&self) -> u8 {
+        self.queenside_rook_file
+    }
+
+    /// ### has_standard_rook_files
+    ///
+    /// returns whether the castling rooks are on the h and a files, as in standard chess
+    pub fn has_standard_rook_files(&self) -> bool {
+        self.kingside_rook_file == 7 && self.queenside_rook_file == 0
+    }
+
+    /// ### set_kingside_rook_file
+    ///
+    /// set the file (0-7) of the rook used for kingside castling
+    pub fn set_kingside_rook_file(&mut self, file: u8) {
+        self.kingside_rook_file = file
+    }
+
+    /// ### set_queenside_rook_file
+    ///
+    /// set the file (0-7) of the rook used for queenside castling
+    pub fn set_queenside_rook_file(&mut self, file: u8) {
+        self.queenside_rook_file = file
+    }
+
     /// ### disable_kingside
     ///
     /// disable kingside castling rights
@@ -86,6 +134,14 @@ mod test {
 
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn castling_rights_new() {
+        let rights: CastlingRights = CastlingRights::new(true, false);
+        assert_eq!(rights.can_kingside_castle(), true);
+        assert_eq!(rights.can_queenside_castle(), false);
+        assert_eq!(CastlingRights::new(true, true), CastlingRights::default());
+    }
+
     #[test]
     fn castling_rights() {
         let mut rights: CastlingRights = CastlingRights::default();
@@ -110,4 +166,18 @@ mod test {
         assert_eq!(rights.can_kingside_castle(), true);
         assert_eq!(rights.can_queenside_castle(), true);
     }
+
+    #[test]
+    fn castling_rights_rook_files() {
+        let mut rights: CastlingRights = CastlingRights::default();
+        assert_eq!(rights.kingside_rook_file(), 7);
+        assert_eq!(rights.queenside_rook_file(), 0);
+        assert_eq!(rights.has_standard_rook_files(), true);
+        rights.set_kingside_rook_file(6);
+        rights.set_queenside_rook_file(1);
+        assert_eq!(rights.kingside_rook_file(), 6);
+        assert_eq!(rights.queenside_rook_file(), 1);
+        assert_eq!(rights.has_standard_rook_files(), false);
+        assert_ne!(rights, CastlingRights::default());
+    }
 }
@@ -0,0 +1,447 @@
+//! # Crazyhouse
+//!
+//! This module exposes the Crazyhouse rules for `Board`: captured pieces change color and go to the reserve
+//! of the capturer, who can drop them on an empty square instead of moving a piece.
+//! <https://en.wikipedia.org/wiki/Crazyhouse>
+
+use super::{Board, Color, Move, Piece, PieceKind, Position, Rules, VariantRules, BLACK, WHITE};
+
+use alloc::vec::Vec;
+
+/// The kinds of pieces which can be held in a reserve, sorted by value
+const RESERVE_KINDS: [PieceKind; 5] = [
+    PieceKind::Queen,
+    PieceKind::Rook,
+    PieceKind::Bishop,
+    PieceKind::Knight,
+    PieceKind::Pawn,
+];
+
+/// ## Reserve
+///
+/// The pieces captured by a player in Crazyhouse, which can be dropped on the board
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Reserve {
+    /// amount of pieces for each kind, in the order of `RESERVE_KINDS`
+    pieces: [u8; 5],
+}
+
+impl Reserve {
+    /// ### count
+    ///
+    /// Get the amount of pieces of the provided kind in the reserve
+    pub fn count(&self, kind: PieceKind) -> u8 {
+        match Self::index(kind) {
+            Some(index) => self.pieces[index],
+            None => 0,
+        }
+    }
+
+    /// ### is_empty
+    ///
+    /// Returns whether there are no pieces in the reserve
+    pub fn is_empty(&self) -> bool {
+        self.pieces.iter().all(|x| *x == 0)
+    }
+
+    /// ### kinds
+    ///
+    /// Get the kinds of pieces available in the reserve, from the most valuable
+    pub fn kinds(&self) -> Vec<PieceKind> {
+        RESERVE_KINDS
+            .iter()
+            .copied()
+            .filter(|x| self.count(*x) > 0)
+            .collect()
+    }
+
+    /// ### with_piece
+    ///
+    /// Get a new reserve with a piece of the provided kind added. Kings can't be held in a reserve
+    pub fn with_piece(mut self, kind: PieceKind) -> Self {
+        if let Some(index) = Self::index(kind) {
+            self.pieces[index] = self.pieces[index].saturating_add(1);
+        }
+        self
+    }
+
+    /// ### without_piece
+    ///
+    /// Get a new reserve with a piece of the provided kind removed, if any
+    pub fn without_piece(mut self, kind: PieceKind) -> Self {
+        if let Some(index) = Self::index(kind) {
+            self.pieces[index] = self.pieces[index].saturating_sub(1);
+        }
+        self
+    }
+
+    /// ### material
+    ///
+    /// Get the material value of the pieces in the reserve
+    pub fn material(&self) -> i32 {
+        RESERVE_KINDS
+            .iter()
+            .map(|x| {
+                Piece::new(*x, WHITE, Position::new(0, 0)).get_material_value()
+                    * self.count(*x) as i32
+            })
+            .sum()
+    }
+
+    /// ### index
+    ///
+    /// Get the index of `kind` in the reserve
+    fn index(kind: PieceKind) -> Option<usize> {
+        RESERVE_KINDS.iter().position(|x| *x == kind)
+    }
+}
+
+impl Board {
+    /// ### crazyhouse
+    ///
+    /// Create the default board for the Crazyhouse variant, with the Crazyhouse rules enabled
+    pub fn crazyhouse() -> Self {
+        Board::default().with_crazyhouse_rules(true)
+    }
+
+    /// ### with_crazyhouse_rules
+    ///
+    /// Get a new board with the Crazyhouse rules enabled or disabled:
+    ///
+    /// - captured pieces go to the reserve of the capturer; promoted pieces go back to the reserve as pawns
+    /// - the player to move can drop a piece of the reserve on any empty square (`Move::Drop`),
+    ///   except pawns, which can't be dropped on the first and on the last rank
+    /// - the game can't be drawn by insufficient material
+    pub fn with_crazyhouse_rules(&self, enabled: bool) -> Self {
+        self.with_variant_flag(Rules::Crazyhouse, enabled)
+    }
+
+    /// ### is_crazyhouse
+    ///
+    /// Returns whether the Crazyhouse rules are enabled
+    pub fn is_crazyhouse(&self) -> bool {
+        self.rules == Rules::Crazyhouse
+    }
+
+    /// ### get_reserve
+    ///
+    /// Get the reserve of the provided player
+    pub fn get_reserve(&self, color: Color) -> Reserve {
+        match color {
+            WHITE => self.white_reserve,
+            BLACK => self.black_reserve,
+        }
+    }
+
+    /// ### with_reserve
+    ///
+    /// Get a new board with the provided reserve for `color`
+    pub fn with_reserve(&self, color: Color, reserve: Reserve) -> Self {
+        let mut result = *self;
+        match color {
+            WHITE => result.white_reserve = reserve,
+            BLACK => result.black_reserve = reserve,
+        }
+        result
+    }
+
+    /// ### is_promoted
+    ///
+    /// Returns whether the piece at `pos` is a promoted pawn. Promoted pieces are tracked only with the Crazyhouse rules enabled
+    pub fn is_promoted(&self, pos: Position) -> bool {
+        pos.is_on_board() && self.promoted & Self::square_bit(pos) != 0
+    }
+
+    /// ### with_promoted
+    ///
+    /// Get a new board where the piece at `pos` is marked as a promoted pawn or not
+    pub fn with_promoted(&self, pos: Position, promoted: bool) -> Self {
+        let mut result = *self;
+        if pos.is_on_board() {
+            match promoted {
+                true => result.promoted |= Self::square_bit(pos),
+                false => result.promoted &= !Self::square_bit(pos),
+            }
+        }
+        result
+    }
+
+    // -- crate
+
+    /// ### is_legal_drop
+    ///
+    /// Returns whether `color` can drop a piece of the provided kind on `pos`
+    pub(crate) fn is_legal_drop(&self, kind: PieceKind, pos: Position, color: Color) -> bool {
+        self.rules.allow_drops()
+            && pos.is_on_board()
+            && self.get_reserve(color).count(kind) > 0
+            && self.has_no_piece(pos)
+            && !(kind == PieceKind::Pawn && (pos.get_row() == 0 || pos.get_row() == 7))
+            && !self.drop_piece(kind, pos, color).is_in_check(color)
+    }
+
+    /// ### get_legal_drops
+    ///
+    /// Get the legal drops for `color`
+    pub(crate) fn get_legal_drops(&self, color: Color) -> Vec<Move> {
+        self.legal_drops(color).collect()
+    }
+
+    /// ### has_legal_drop
+    ///
+    /// Returns whether `color` has at least a legal drop, without generating all of them
+    pub(crate) fn has_legal_drop(&self, color: Color) -> bool {
+        self.legal_drops(color).next().is_some()
+    }
+
+    /// ### drop_piece
+    ///
+    /// Drop a piece of the reserve of `color` on `pos`, without checking whether the drop is legal
+    pub(crate) fn drop_piece(&self, kind: PieceKind, pos: Position, color: Color) -> Self {
+        let mut result = *self;
+        result.en_passant = None;
+        result.taken_piece = None;
+        if pos.is_off_board() {
+            return result;
+        }
+        result.add_piece(Piece::new(kind, color, pos));
+        result.with_reserve(color, self.get_reserve(color).without_piece(kind))
+    }
+
+    /// ### capture_to_reserve
+    ///
+    /// Put the piece captured at `pos` in the reserve of the capturer.
+    /// Promoted pieces go back to the reserve as pawns
+    pub(crate) fn capture_to_reserve(&mut self, piece: Piece, pos: Position) {
+        let kind: PieceKind = match self.is_promoted(pos) {
+            true => PieceKind::Pawn,
+            false => piece.kind(),
+        };
+        let capturer: Color = !piece.get_color();
+        *self = self
+            .with_promoted(pos, false)
+            .with_reserve(capturer, self.get_reserve(capturer).with_piece(kind));
+        // A rook dropped on its starting square can't castle
+        let castling_rights = match piece.get_color() {
+            WHITE => &mut self.white_castling_rights,
+            BLACK => &mut self.black_castling_rights,
+        };
+        if pos.is_queenside_rook(piece.get_color()) {
+            castling_rights.disable_queenside();
+        } else if pos.is_kingside_rook(piece.get_color()) {
+            castling_rights.disable_kingside();
+        }
+    }
+
+    /// ### reserve_value
+    ///
+    /// Get the value of the reserve of `color`, in the same scale of `get_player_value`
+    pub(crate) fn reserve_value(&self, color: Color) -> f64 {
+        (self.get_reserve(color).material() * 10) as f64
+    }
+
+    /// ### legal_drops
+    ///
+    /// Iterate over the legal drops for `color`
+    fn legal_drops(&self, color: Color) -> impl Iterator<Item = Move> + '_ {
+        let kinds: Vec<PieceKind> = match self.rules.allow_drops() {
+            true => self.get_reserve(color).kinds(),
+            false => Vec::new(),
+        };
+        kinds.into_iter().flat_map(move |kind| {
+            (0..64)
+                .map(|square| Position::new(square / 8, square % 8))
+                .filter(move |pos| self.is_legal_drop(kind, *pos, color))
+                .map(move |pos| Move::Drop(kind, pos))
+        })
+    }
+
+    /// ### square_bit
+    ///
+    /// Get the bit of `pos` in the promoted pieces mask
+    fn square_bit(pos: Position) -> u64 {
+        1 << (pos.get_row() * 8 + pos.get_col())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{MoveResult, Promotion};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn reserve() {
+        let reserve: Reserve = Reserve::default();
+        assert_eq!(reserve.is_empty(), true);
+        assert_eq!(reserve.kinds(), Vec::new());
+        let reserve: Reserve = reserve
+            .with_piece(PieceKind::Pawn)
+            .with_piece(PieceKind::Pawn)
+            .with_piece(PieceKind::Knight)
+            .with_piece(PieceKind::King);
+        assert_eq!(reserve.is_empty(), false);
+        assert_eq!(reserve.count(PieceKind::Pawn), 2);
+        assert_eq!(reserve.count(PieceKind::Knight), 1);
+        assert_eq!(reserve.count(PieceKind::King), 0);
+        assert_eq!(reserve.kinds(), vec![PieceKind::Knight, PieceKind::Pawn]);
+        assert_eq!(reserve.material(), 5);
+        let reserve: Reserve = reserve
+            .without_piece(PieceKind::Knight)
+            .without_piece(PieceKind::Knight);
+        assert_eq!(reserve.count(PieceKind::Knight), 0);
+        assert_eq!(reserve.kinds(), vec![PieceKind::Pawn]);
+    }
+
+    #[test]
+    fn reserve_bookkeeping() {
+        assert_eq!(Board::crazyhouse().is_crazyhouse(), true);
+        assert_eq!(Board::default().is_crazyhouse(), false);
+        // 1. e4 d5 2. exd5 Qxd5
+        let board: Board = Board::crazyhouse()
+            .play_uci_moves(&["e2e4", "d7d5", "e4d5", "d8d5"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.get_reserve(WHITE).count(PieceKind::Pawn), 1);
+        assert_eq!(board.get_reserve(BLACK).count(PieceKind::Pawn), 1);
+        // Without Crazyhouse rules, captured pieces are lost
+        let board: Board = Board::default()
+            .play_uci_moves(&["e2e4", "d7d5", "e4d5", "d8d5"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.get_reserve(WHITE).is_empty(), true);
+        // En passant
+        let board: Board = Board::crazyhouse()
+            .play_uci_moves(&["e2e4", "a7a6", "e4e5", "d7d5", "e5d6"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.get_reserve(WHITE).count(PieceKind::Pawn), 1);
+        // Promoted pieces go back to the reserve as pawns
+        let board: Board = Board::from_fen("1n2k3/P7/8/8/8/8/8/4K3 w - -")
+            .ok()
+            .unwrap()
+            .with_crazyhouse_rules(true);
+        let board: Board = match board.play_move(Move::Piece(A7, B8)) {
+            MoveResult::Promote(board, _) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        let board: Board = match board.promote(Promotion::Queen) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        assert_eq!(board.get_reserve(WHITE).count(PieceKind::Knight), 1);
+        assert_eq!(board.is_promoted(B8), true);
+        let board: Board = board
+            .play_uci_moves(&["e8d7", "b8c7", "d7c7"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.is_promoted(C7), false);
+        assert_eq!(board.get_reserve(BLACK).count(PieceKind::Pawn), 1);
+        assert_eq!(board.get_reserve(BLACK).count(PieceKind::Queen), 0);
+    }
+
+    #[test]
+    fn drop_legality() {
+        let board: Board = Board::from_fen("4k3/8/8/8/8/8/8/4K2r w - -")
+            .ok()
+            .unwrap()
+            .with_crazyhouse_rules(true)
+            .with_reserve(
+                WHITE,
+                Reserve::default()
+                    .with_piece(PieceKind::Pawn)
+                    .with_piece(PieceKind::Knight),
+            );
+        // In check: only drops blocking the check are legal
+        let drops: Vec<Move> = board
+            .get_legal_moves(WHITE)
+            .into_iter()
+            .filter(|x| x.is_drop())
+            .collect();
+        assert_eq!(
+            drops,
+            vec![
+                Move::Drop(PieceKind::Knight, F1),
+                Move::Drop(PieceKind::Knight, G1)
+            ]
+        );
+        // Pawns can't be dropped on the first rank
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, F1), WHITE),
+            false
+        );
+        // Not in reserve
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Queen, F1), WHITE),
+            false
+        );
+        // Occupied square
+        let board: Board = board.remove_piece(H1);
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, E8), WHITE),
+            false
+        );
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, E2), WHITE),
+            true
+        );
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, E7), WHITE),
+            true
+        );
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, E1), WHITE),
+            false
+        );
+        // Pawns can be dropped on 6 ranks, the knight on any empty square
+        assert_eq!(board.get_legal_drops(WHITE).len(), 48 + 62);
+        // Without Crazyhouse rules, drops are illegal
+        let board: Board = board.with_crazyhouse_rules(false);
+        assert_eq!(
+            board.is_legal_move(Move::Drop(PieceKind::Pawn, E2), WHITE),
+            false
+        );
+        assert_eq!(board.get_legal_drops(WHITE), Vec::new());
+        // Dropped pieces leave the reserve
+        let board: Board = board.with_crazyhouse_rules(true);
+        let board: Board = match board.play_move(Move::Drop(PieceKind::Knight, C3)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        assert_eq!(board.get_piece(C3), Some(Piece::Knight(WHITE, C3)));
+        assert_eq!(board.get_reserve(WHITE).count(PieceKind::Knight), 0);
+        assert_eq!(board.get_reserve(WHITE).count(PieceKind::Pawn), 1);
+    }
+
+    #[test]
+    fn drop_mate() {
+        // Back rank mate by dropping a rook
+        let board: Board = Board::from_fen("7k/6pp/8/8/8/8/8/4K3 w - -")
+            .ok()
+            .unwrap()
+            .with_crazyhouse_rules(true)
+            .with_reserve(WHITE, Reserve::default().with_piece(PieceKind::Rook));
+        assert_eq!(
+            board.play_move(Move::Drop(PieceKind::Rook, A8)),
+            MoveResult::Victory(WHITE)
+        );
+        // Drops are considered by the search
+        assert!(board
+            .rate_legal_moves(0)
+            .iter()
+            .any(|(m, _)| *m == Move::Drop(PieceKind::Rook, A8)));
+        // The check can be blocked by a drop, so it's not a mate when the defender has a reserve
+        let board: Board =
+            board.with_reserve(BLACK, Reserve::default().with_piece(PieceKind::Knight));
+        let board: Board = match board.play_move(Move::Drop(PieceKind::Rook, A8)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        assert!(board
+            .get_legal_moves(BLACK)
+            .contains(&Move::Drop(PieceKind::Knight, G8)));
+    }
+}
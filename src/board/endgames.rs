@@ -0,0 +1,427 @@
+//! # Endgames
+//!
+//! This module exposes the constructors for the positions commonly used to train endgames.
+//! The positions are described from White's point of view; the ones which take the attacker as parameter
+//! are mirrored vertically when the attacker is Black.
+//! The endgame studies (e.g. `kq_vs_k`) instead place the pieces on the provided squares.
+
+use super::{Board, BoardBuilder, Color, Piece, Position, BLACK, WHITE};
+
+impl Board {
+    /// ### endgame_kq_vs_k
+    ///
+    /// Create a king and queen versus king endgame, with `attacker` to move.
+    /// The defending king stands in the center of the board and must be driven to the edge.
+    ///
+    /// ```text
+    /// 8 . . . . . . . .
+    /// 7 . . . . . . . .
+    /// 6 . . . . . . . .
+    /// 5 . . . . k . . .
+    /// 4 . . . . . . . .
+    /// 3 . . . . . . . .
+    /// 2 . . . . . . . .
+    /// 1 . . . Q K . . .
+    ///   a b c d e f g h
+    /// ```
+    ///
+    /// To make the defender move first, use `BoardBuilder::from(board).player_moving(color)`
+    pub fn endgame_kq_vs_k(attacker: Color) -> Self {
+        BoardBuilder::default()
+            .piece(Piece::King(attacker, relative(attacker, 0, 4)))
+            .piece(Piece::Queen(attacker, relative(attacker, 0, 3)))
+            .piece(Piece::King(!attacker, relative(attacker, 4, 4)))
+            .player_moving(attacker)
+            .build()
+    }
+
+    /// ### endgame_kr_vs_k
+    ///
+    /// Create a king and rook versus king endgame, with `attacker` to move.
+    /// The defending king stands in the center of the board and must be driven to the edge.
+    ///
+    /// ```text
+    /// 8 . . . . . . . .
+    /// 7 . . . . . . . .
+    /// 6 . . . . . . . .
+    /// 5 . . . . k . . .
+    /// 4 . . . . . . . .
+    /// 3 . . . . . . . .
+    /// 2 . . . . . . . .
+    /// 1 R . . . K . . .
+    ///   a b c d e f g h
+    /// ```
+    ///
+    /// To make the defender move first, use `BoardBuilder::from(board).player_moving(color)`
+    pub fn endgame_kr_vs_k(attacker: Color) -> Self {
+        BoardBuilder::default()
+            .piece(Piece::King(attacker, relative(attacker, 0, 4)))
+            .piece(Piece::Rook(attacker, relative(attacker, 0, 0)))
+            .piece(Piece::King(!attacker, relative(attacker, 4, 4)))
+            .player_moving(attacker)
+            .build()
+    }
+
+    /// ### endgame_kp_vs_k
+    ///
+    /// Create a king and pawn versus king endgame, with `attacker` to move.
+    /// The pawn stands on the fifth rank of the provided file (0-7), with its king in front of it on the sixth rank
+    /// and the defending king on the last rank: the attacker wins whoever moves first, unless the pawn is a rook pawn
+    /// (file 0 or 7), which is a draw.
+    ///
+    /// ```text
+    /// 8 . . . . k . . .
+    /// 7 . . . . . . . .
+    /// 6 . . . . K . . .
+    /// 5 . . . . P . . .
+    /// 4 . . . . . . . .
+    /// 3 . . . . . . . .
+    /// 2 . . . . . . . .
+    /// 1 . . . . . . . .
+    ///   a b c d e f g h
+    /// ```
+    ///
+    /// Panics if `pawn_file` is not in range 0-7.
+    /// To make the defender move first, use `BoardBuilder::from(board).player_moving(color)`
+    pub fn endgame_kp_vs_k(attacker: Color, pawn_file: i32) -> Self {
+        assert!(
+            (0..8).contains(&pawn_file),
+            "Pawn file must be in range 0-7"
+        );
+        BoardBuilder::default()
+            .piece(Piece::King(attacker, relative(attacker, 5, pawn_file)))
+            .piece(Piece::Pawn(attacker, relative(attacker, 4, pawn_file)))
+            .piece(Piece::King(!attacker, relative(attacker, 7, pawn_file)))
+            .player_moving(attacker)
+            .build()
+    }
+
+    /// ### endgame_lucena
+    ///
+    /// Create the Lucena position, with White to move.
+    /// White wins cutting off the black king with the rook and "building a bridge" on the fourth rank
+    /// to shelter the king from the checks of the black rook.
+    /// <https://en.wikipedia.org/wiki/Lucena_position>
+    ///
+    /// ```text
+    /// 8 . . K . . . . .
+    /// 7 . . P . k . . .
+    /// 6 . . . . . . . .
+    /// 5 . . . . . . . .
+    /// 4 . . . . . . . .
+    /// 3 . . . . . . . .
+    /// 2 . r . . . . . .
+    /// 1 . . . R . . . .
+    ///   a b c d e f g h
+    /// ```
+    pub fn endgame_lucena() -> Self {
+        BoardBuilder::default()
+            .piece(Piece::King(WHITE, Position::new(7, 2)))
+            .piece(Piece::Pawn(WHITE, Position::new(6, 2)))
+            .piece(Piece::Rook(WHITE, Position::new(0, 3)))
+            .piece(Piece::King(BLACK, Position::new(6, 4)))
+            .piece(Piece::Rook(BLACK, Position::new(1, 1)))
+            .player_moving(WHITE)
+            .build()
+    }
+
+    /// ### endgame_philidor
+    ///
+    /// Create the Philidor position, with Black to move.
+    /// Black draws keeping the rook on the sixth rank, so the white king can't advance, until the pawn moves forward;
+    /// then the rook goes back to check the white king from behind.
+    /// <https://en.wikipedia.org/wiki/Philidor_position>
+    ///
+    /// ```text
+    /// 8 . . . . k . . .
+    /// 7 R . . . . . . .
+    /// 6 . r . . . . . .
+    /// 5 . . . . K . . .
+    /// 4 . . . . P . . .
+    /// 3 . . . . . . . .
+    /// 2 . . . . . . . .
+    /// 1 . . . . . . . .
+    ///   a b c d e f g h
+    /// ```
+    pub fn endgame_philidor() -> Self {
+        BoardBuilder::default()
+            .piece(Piece::King(WHITE, Position::new(4, 4)))
+            .piece(Piece::Pawn(WHITE, Position::new(3, 4)))
+            .piece(Piece::Rook(WHITE, Position::new(6, 0)))
+            .piece(Piece::King(BLACK, Position::new(7, 4)))
+            .piece(Piece::Rook(BLACK, Position::new(5, 1)))
+            .player_moving(BLACK)
+            .build()
+    }
+
+    /// ### kq_vs_k
+    ///
+    /// Create a king and queen versus king study, with the pieces on the provided squares and `attacker` to move.
+    /// Castling is disabled.
+    /// Returns `None` if the position is illegal: squares off board or overlapping, adjacent kings
+    /// or defending king in check
+    pub fn kq_vs_k(
+        attacker: Color,
+        king: Position,
+        queen: Position,
+        defender_king: Position,
+    ) -> Option<Self> {
+        endgame_study(attacker, king, Piece::Queen(attacker, queen), defender_king)
+    }
+
+    /// ### kr_vs_k
+    ///
+    /// Create a king and rook versus king study, with the pieces on the provided squares and `attacker` to move.
+    /// Castling is disabled.
+    /// Returns `None` if the position is illegal: squares off board or overlapping, adjacent kings
+    /// or defending king in check
+    pub fn kr_vs_k(
+        attacker: Color,
+        king: Position,
+        rook: Position,
+        defender_king: Position,
+    ) -> Option<Self> {
+        endgame_study(attacker, king, Piece::Rook(attacker, rook), defender_king)
+    }
+
+    /// ### kp_vs_k
+    ///
+    /// Create a king and pawn versus king study, with the pieces on the provided squares and `attacker` to move.
+    /// Returns `None` if the position is illegal: squares off board or overlapping, adjacent kings,
+    /// defending king in check or pawn on the first or on the last rank
+    pub fn kp_vs_k(
+        attacker: Color,
+        king: Position,
+        pawn: Position,
+        defender_king: Position,
+    ) -> Option<Self> {
+        if pawn.get_row() == 0 || pawn.get_row() == 7 {
+            return None;
+        }
+        endgame_study(attacker, king, Piece::Pawn(attacker, pawn), defender_king)
+    }
+}
+
+/// ### endgame_study
+///
+/// Create a position with the attacker king and piece against the lone defender king, with `attacker` to move.
+/// Returns `None` if the position is illegal
+fn endgame_study(
+    attacker: Color,
+    king: Position,
+    piece: Piece,
+    defender_king: Position,
+) -> Option<Board> {
+    let squares: [Position; 3] = [king, piece.get_pos(), defender_king];
+    if squares.iter().any(|x| x.is_off_board())
+        || king == piece.get_pos()
+        || piece.get_pos() == defender_king
+        || king.distance(defender_king) < 2
+    {
+        return None;
+    }
+    let board: Board = BoardBuilder::default()
+        .piece(Piece::King(attacker, king))
+        .piece(piece)
+        .piece(Piece::King(!attacker, defender_king))
+        .player_moving(attacker)
+        .build();
+    match board.is_in_check(!attacker) {
+        true => None,
+        false => Some(board),
+    }
+}
+
+/// ### relative
+///
+/// Get the position at `row` and `col` from the point of view of `color`
+fn relative(color: Color, row: i32, col: i32) -> Position {
+    match color {
+        WHITE => Position::new(row, col),
+        BLACK => Position::new(7 - row, col),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{GameBuilder, Move};
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use pretty_assertions::assert_eq;
+
+    /// Check the position is valid for a game and neither side is stalemated
+    fn assert_playable(board: Board) {
+        assert!(GameBuilder::default().board(board).try_build().is_ok());
+        let king: Position = board.get_king_pos(!board.get_turn()).unwrap();
+        assert_eq!(board.is_threatened(king, !board.get_turn()), false);
+        for color in [WHITE, BLACK].iter() {
+            let board: Board = BoardBuilder::from(board).player_moving(*color).build();
+            assert_eq!(board.is_stalemate(), false);
+            assert_eq!(board.is_checkmate(), false);
+        }
+    }
+
+    #[test]
+    fn endgame_kq_vs_k() {
+        let board: Board = Board::endgame_kq_vs_k(WHITE);
+        assert_playable(board);
+        assert_eq!(board.get_turn(), WHITE);
+        assert_eq!(board.get_piece(D1), Some(Piece::Queen(WHITE, D1)));
+        assert_eq!(board.get_piece(E5), Some(Piece::King(BLACK, E5)));
+        // The defending king is far from the attacking one
+        assert_eq!(
+            board
+                .get_king_pos(WHITE)
+                .unwrap()
+                .distance(board.get_king_pos(BLACK).unwrap()),
+            4
+        );
+        let board: Board = Board::endgame_kq_vs_k(BLACK);
+        assert_playable(board);
+        assert_eq!(board.get_turn(), BLACK);
+        assert_eq!(board.get_piece(D8), Some(Piece::Queen(BLACK, D8)));
+        assert_eq!(board.get_piece(E4), Some(Piece::King(WHITE, E4)));
+        // Near the edge, the queen mates within the horizon
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, F6))
+            .piece(Piece::Queen(WHITE, G1))
+            .piece(Piece::King(BLACK, H8))
+            .build();
+        assert_eq!(board.find_mate(2), Some(vec![Move::Piece(G1, G7)]));
+    }
+
+    #[test]
+    fn endgame_kr_vs_k() {
+        let board: Board = Board::endgame_kr_vs_k(WHITE);
+        assert_playable(board);
+        assert_eq!(board.get_piece(A1), Some(Piece::Rook(WHITE, A1)));
+        assert_eq!(
+            board.get_castling_rights(WHITE).can_queenside_castle(),
+            false
+        );
+        assert_playable(Board::endgame_kr_vs_k(BLACK));
+        // With the king on the edge, the rook mates within the horizon
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, F6))
+            .piece(Piece::Rook(WHITE, A2))
+            .piece(Piece::King(BLACK, H8))
+            .build();
+        assert_eq!(board.find_mate(2).map(|x| x.len()), Some(3));
+    }
+
+    #[test]
+    fn endgame_kp_vs_k() {
+        let board: Board = Board::endgame_kp_vs_k(WHITE, 3);
+        assert_playable(board);
+        assert_eq!(board.get_piece(D5), Some(Piece::Pawn(WHITE, D5)));
+        assert_eq!(board.get_piece(D6), Some(Piece::King(WHITE, D6)));
+        assert_eq!(board.get_piece(D8), Some(Piece::King(BLACK, D8)));
+        let board: Board = Board::endgame_kp_vs_k(BLACK, 0);
+        assert_playable(board);
+        assert_eq!(board.get_piece(A4), Some(Piece::Pawn(BLACK, A4)));
+        assert_eq!(board.get_piece(A3), Some(Piece::King(BLACK, A3)));
+        assert_eq!(board.get_piece(A1), Some(Piece::King(WHITE, A1)));
+        // The defending king can't reach the pawn
+        for file in 0..8 {
+            let board: Board = Board::endgame_kp_vs_k(WHITE, file);
+            let pawn: Position = Position::new(4, file);
+            assert_eq!(board.is_threatened(pawn, WHITE), false);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn endgame_kp_vs_k_bad_file() {
+        Board::endgame_kp_vs_k(WHITE, 8);
+    }
+
+    #[test]
+    fn kq_vs_k() {
+        let board: Board = Board::kq_vs_k(WHITE, F6, G1, H8).unwrap();
+        assert_playable(board);
+        assert_eq!(board.get_turn(), WHITE);
+        assert_eq!(board.get_piece(F6), Some(Piece::King(WHITE, F6)));
+        assert_eq!(board.get_piece(G1), Some(Piece::Queen(WHITE, G1)));
+        assert_eq!(board.get_piece(H8), Some(Piece::King(BLACK, H8)));
+        assert_eq!(board.get_player_pieces(WHITE).len(), 2);
+        assert_eq!(board.get_player_pieces(BLACK).len(), 1);
+        let board: Board = Board::kq_vs_k(BLACK, C3, D4, A1).unwrap();
+        assert_eq!(board.get_turn(), BLACK);
+        assert_eq!(board.get_piece(D4), Some(Piece::Queen(BLACK, D4)));
+        // Illegal positions
+        assert_eq!(Board::kq_vs_k(WHITE, G7, G1, H8), None);
+        assert_eq!(Board::kq_vs_k(WHITE, F6, H1, H8), None);
+        assert_eq!(Board::kq_vs_k(WHITE, F6, F6, H8), None);
+        assert_eq!(Board::kq_vs_k(WHITE, F6, G1, Position::new(8, 0)), None);
+    }
+
+    #[test]
+    fn kr_vs_k() {
+        let board: Board = Board::kr_vs_k(WHITE, E1, A1, E5).unwrap();
+        assert_playable(board);
+        assert_eq!(board.get_piece(A1), Some(Piece::Rook(WHITE, A1)));
+        assert_eq!(
+            board.get_castling_rights(WHITE).can_queenside_castle(),
+            false
+        );
+        assert_eq!(
+            board.get_castling_rights(BLACK).can_kingside_castle(),
+            false
+        );
+        assert!(!board
+            .get_legal_moves(WHITE)
+            .contains(&Move::QueenSideCastle));
+        assert_eq!(Board::kr_vs_k(WHITE, E1, A5, E5), None);
+        assert_eq!(Board::kr_vs_k(WHITE, E4, A1, E5), None);
+    }
+
+    #[test]
+    fn kp_vs_k() {
+        let board: Board = Board::kp_vs_k(WHITE, D6, D5, D8).unwrap();
+        assert_playable(board);
+        assert_eq!(board, Board::endgame_kp_vs_k(WHITE, 3));
+        let board: Board = Board::kp_vs_k(BLACK, E3, E4, E1).unwrap();
+        assert_eq!(board, Board::endgame_kp_vs_k(BLACK, 4));
+        assert_eq!(Board::kp_vs_k(WHITE, D6, D8, A1), None);
+        assert_eq!(Board::kp_vs_k(WHITE, D6, D1, A1), None);
+        // Pawn giving check
+        assert_eq!(Board::kp_vs_k(WHITE, A1, D5, E6), None);
+    }
+
+    #[test]
+    fn endgame_lucena() {
+        let board: Board = Board::endgame_lucena();
+        assert_playable(board);
+        assert_eq!(board.get_turn(), WHITE);
+        // The rook drives the black king away, then builds the bridge on the fourth rank
+        let legal_moves: Vec<Move> = board.get_legal_moves(WHITE);
+        assert!(legal_moves.contains(&Move::Piece(D1, E1)));
+        assert!(legal_moves.contains(&Move::Piece(D1, D4)));
+        assert!(board
+            .make_unchecked(Move::Piece(D1, E1))
+            .change_turn()
+            .is_check());
+        // The white king is locked in front of the pawn by the black king
+        assert!(!legal_moves.contains(&Move::Piece(C8, D7)));
+        assert!(!legal_moves.contains(&Move::Piece(C8, B7)));
+    }
+
+    #[test]
+    fn endgame_philidor() {
+        let board: Board = Board::endgame_philidor();
+        assert_playable(board);
+        assert_eq!(board.get_turn(), BLACK);
+        // The rook on the sixth rank keeps the white king away
+        assert!(board.is_threatened(E6, WHITE));
+        assert!(board.is_threatened(D6, WHITE));
+        assert!(board.is_threatened(F6, WHITE));
+        // Neither player has a quick mate
+        assert_eq!(board.find_mate(2), None);
+        let board: Board = BoardBuilder::from(board).player_moving(WHITE).build();
+        assert_eq!(board.find_mate(2), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! # Explain
+//!
+//! This module exposes the reasons why a move is illegal, which can be reported to players learning the game.
+
+use super::{Board, Color, Move, Piece, Position};
+
+/// ## IllegalReason
+///
+/// Describes why a move can't be played on the board
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IllegalReason {
+    /// The piece belongs to the opponent
+    WrongTurn,
+    /// There's no piece on the source square
+    NoPieceAtSource,
+    /// The destination square is occupied by a piece of the same player
+    CapturesOwnPiece,
+    /// There are pieces between the source and the destination square, or the pawn can't advance
+    /// because the square in front of it is occupied
+    Blocked,
+    /// The move would leave the king of the player in check
+    LeavesKingInCheck,
+    /// The piece can't move this way (this is also reported for the moves forbidden by the variant rules)
+    IllegalPattern,
+    /// The player can't castle on that side
+    CastlingUnavailable,
+}
+
+impl core::fmt::Display for IllegalReason {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::WrongTurn => write!(f, "It's not the turn of this piece"),
+            Self::NoPieceAtSource => write!(f, "There's no piece to move"),
+            Self::CapturesOwnPiece => write!(f, "A piece can't capture a piece of the same color"),
+            Self::Blocked => write!(f, "The path of the piece is blocked"),
+            Self::LeavesKingInCheck => write!(f, "The move would leave the king in check"),
+            Self::IllegalPattern => write!(f, "The piece can't move this way"),
+            Self::CastlingUnavailable => write!(f, "Castling is not available"),
+        }
+    }
+}
+
+impl Board {
+    /// ### explain_illegal
+    ///
+    /// Get the reason why the provided move can't be played by the player to move.
+    /// Returns `None` if the move is legal
+    pub fn explain_illegal(&self, m: Move) -> Option<IllegalReason> {
+        let color: Color = self.get_turn();
+        if self.is_legal_move(m, color) && self.is_allowed_by_rules(m, color) {
+            return None;
+        }
+        let (from, to): (Position, Position) = match m {
+            Move::KingSideCastle | Move::QueenSideCastle => {
+                return Some(IllegalReason::CastlingUnavailable)
+            }
+            Move::Piece(from, to) => (from, to),
+            Move::Drop(_, _) | Move::Resign => return Some(IllegalReason::IllegalPattern),
+        };
+        let piece: Piece = match self.get_piece(from) {
+            Some(piece) => piece,
+            None => return Some(IllegalReason::NoPieceAtSource),
+        };
+        Some(if piece.get_color() != color {
+            IllegalReason::WrongTurn
+        } else if from == to || !self.follows_pattern(piece, to) {
+            IllegalReason::IllegalPattern
+        } else if self.has_ally_piece(to, color) {
+            IllegalReason::CapturesOwnPiece
+        } else if !piece.is_legal_move(to, self) {
+            IllegalReason::Blocked
+        } else if self.apply_move(m).is_in_check(color) {
+            IllegalReason::LeavesKingInCheck
+        } else {
+            IllegalReason::IllegalPattern
+        })
+    }
+
+    /// ### follows_pattern
+    ///
+    /// Returns whether `to` can be reached by the piece according to its movement pattern, ignoring the pieces
+    /// in between. Pawns can move diagonally only to capture
+    fn follows_pattern(&self, piece: Piece, to: Position) -> bool {
+        let from: Position = piece.get_pos();
+        match piece {
+            Piece::King(_, _) => from.is_adjacent_to(to),
+            Piece::Queen(_, _) => from.is_orthogonal_to(to) || from.is_diagonal_to(to),
+            Piece::Rook(_, _) => from.is_orthogonal_to(to),
+            Piece::Bishop(_, _) => from.is_diagonal_to(to),
+            Piece::Knight(_, _) => from.is_knight_move(to),
+            Piece::Pawn(color, _) => {
+                let up: Position = from.pawn_up(color);
+                to == up
+                    || (to == up.pawn_up(color) && self.is_double_push_square(from, color))
+                    || ((to == up.next_left() || to == up.next_right())
+                        && (self.has_enemy_piece(to, color) || self.get_en_passant() == Some(to)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{BoardBuilder, PieceKind, Rules};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn explain_illegal() {
+        let board: Board = Board::default();
+        assert_eq!(board.explain_illegal(Move::Piece(E2, E4)), None);
+        assert_eq!(board.explain_illegal(Move::Resign), None);
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E7, E5)),
+            Some(IllegalReason::WrongTurn)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E4, E5)),
+            Some(IllegalReason::NoPieceAtSource)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::Piece(A1, A2)),
+            Some(IllegalReason::CapturesOwnPiece)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::Piece(A1, A5)),
+            Some(IllegalReason::Blocked)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::Piece(G1, G3)),
+            Some(IllegalReason::IllegalPattern)
+        );
+        // Pawns move diagonally only to capture
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E2, D3)),
+            Some(IllegalReason::IllegalPattern)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::KingSideCastle),
+            Some(IllegalReason::CastlingUnavailable)
+        );
+        // Drops are not allowed in standard chess
+        assert_eq!(
+            board.explain_illegal(Move::Drop(PieceKind::Knight, E4)),
+            Some(IllegalReason::IllegalPattern)
+        );
+    }
+
+    #[test]
+    fn explain_illegal_pawn_blocked() {
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E7, E5))
+            .apply_move(Move::Piece(E5, E4))
+            .apply_move(Move::Piece(E4, E3));
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E2, E3)),
+            Some(IllegalReason::Blocked)
+        );
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E2, E4)),
+            Some(IllegalReason::Blocked)
+        );
+        assert_eq!(board.explain_illegal(Move::Piece(D2, E3)), None);
+    }
+
+    #[test]
+    fn explain_illegal_check() {
+        // The knight is pinned by the bishop
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::Knight(Color::White, D2))
+            .piece(Piece::Bishop(Color::Black, B4))
+            .piece(Piece::King(Color::Black, E8))
+            .piece(Piece::Rook(Color::Black, F8))
+            .build();
+        assert_eq!(
+            board.explain_illegal(Move::Piece(D2, F3)),
+            Some(IllegalReason::LeavesKingInCheck)
+        );
+        // The king can't step into check
+        assert_eq!(
+            board.explain_illegal(Move::Piece(E1, F1)),
+            Some(IllegalReason::LeavesKingInCheck)
+        );
+        assert_eq!(board.explain_illegal(Move::Piece(E1, E2)), None);
+        // The piece to drop is not in the reserve
+        let board: Board = board.with_rules(Rules::Crazyhouse);
+        assert_eq!(
+            board.explain_illegal(Move::Drop(PieceKind::Knight, E4)),
+            Some(IllegalReason::IllegalPattern)
+        );
+    }
+}
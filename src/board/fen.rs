@@ -0,0 +1,596 @@
+//! # FEN
+//!
+//! This module exposes the FEN (Forsyth–Edwards notation) import and export for `Board`.
+//! <https://en.wikipedia.org/wiki/Forsyth%E2%80%93Edwards_Notation>
+
+use super::{Board, BoardBuilder, CastlingRights, Color, Piece, PieceKind, Position, Reserve};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// ## FenError
+///
+/// Describes an error while parsing a FEN string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FenError {
+    /// FEN must have six fields (the last two are optional)
+    BadFieldCount,
+    /// Piece placement is invalid
+    BadPiecePlacement,
+    /// Active color is not `w` or `b`
+    BadActiveColor,
+    /// Castling availability is invalid
+    BadCastling,
+    /// En passant target square is invalid
+    BadEnPassant,
+    /// Halfmove clock or fullmove number is not a number
+    BadMoveNumber,
+    /// Three-check counters are invalid
+    BadChecks,
+    /// Crazyhouse reserve is invalid
+    BadReserve,
+}
+
+impl core::fmt::Display for FenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadFieldCount => write!(f, "Bad field count"),
+            Self::BadPiecePlacement => write!(f, "Bad piece placement"),
+            Self::BadActiveColor => write!(f, "Bad active color"),
+            Self::BadCastling => write!(f, "Bad castling availability"),
+            Self::BadEnPassant => write!(f, "Bad en passant target square"),
+            Self::BadMoveNumber => write!(f, "Bad move number"),
+            Self::BadChecks => write!(f, "Bad three-check counters"),
+            Self::BadReserve => write!(f, "Bad crazyhouse reserve"),
+        }
+    }
+}
+
+/// ## FenCastling
+///
+/// Describes how the castling availability is written in FEN
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FenCastling {
+    /// Use the classic letters when the kings are on the e file and the castling rooks on the a and h files,
+    /// the rook files otherwise
+    Auto,
+    /// Classic letters (`KQkq`)
+    Classic,
+    /// Shredder-FEN: the files of the castling rooks (e.g. `HAha`)
+    Shredder,
+}
+
+impl Board {
+    /// ### to_fen
+    ///
+    /// Get the FEN representation of the board.
+    /// The castling availability is written with the classic letters, unless the position requires the rook files
+    /// (see `FenCastling::Auto`).
+    /// Since the board doesn't track the move counters, the halfmove clock is always `0` and the fullmove number is always `1`.
+    /// If the Three-check rules are enabled, the checks delivered by white and black are appended as `+W+B` (e.g. `+2+1`).
+    /// If the Crazyhouse rules are enabled, promoted pieces are followed by `~` and the reserves are appended to the
+    /// piece placement in brackets (e.g. `[Pn]`)
+    pub fn to_fen(&self) -> String {
+        self.to_fen_with_castling(FenCastling::Auto)
+    }
+
+    /// ### to_fen_with_castling
+    ///
+    /// Get the FEN representation of the board, writing the castling availability in the provided dialect
+    pub fn to_fen_with_castling(&self, dialect: FenCastling) -> String {
+        let mut fen: String = String::new();
+        // Piece placement
+        for row in (0..8).rev() {
+            let mut empty: u8 = 0;
+            for col in 0..8 {
+                match self.get_piece(Position::new(row, col)) {
+                    Some(piece) => {
+                        if empty > 0 {
+                            fen.push((b'0' + empty) as char);
+                            empty = 0;
+                        }
+                        fen.push(piece.to_ascii_char());
+                        if self.is_promoted(Position::new(row, col)) {
+                            fen.push('~');
+                        }
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                fen.push((b'0' + empty) as char);
+            }
+            if row > 0 {
+                fen.push('/');
+            }
+        }
+        // Reserves
+        if self.is_crazyhouse() {
+            fen.push('[');
+            for color in Color::both().iter() {
+                let reserve: Reserve = self.get_reserve(*color);
+                for kind in reserve.kinds() {
+                    let ch: char = Piece::new(kind, *color, Position::new(0, 0)).to_ascii_char();
+                    (0..reserve.count(kind)).for_each(|_| fen.push(ch));
+                }
+            }
+            fen.push(']');
+        }
+        // Active color
+        fen.push_str(match self.get_turn() {
+            Color::White => " w ",
+            Color::Black => " b ",
+        });
+        // Castling
+        let shredder: bool = match dialect {
+            FenCastling::Auto => !self.has_classic_castling(),
+            FenCastling::Classic => false,
+            FenCastling::Shredder => true,
+        };
+        let castling_len: usize = fen.len();
+        for color in Color::both().iter().copied() {
+            let rights: CastlingRights = self.get_castling_rights(color);
+            let (kingside, queenside): (char, char) = match shredder {
+                true => (
+                    (b'A' + rights.kingside_rook_file()) as char,
+                    (b'A' + rights.queenside_rook_file()) as char,
+                ),
+                false => ('K', 'Q'),
+            };
+            let case = |ch: char| match color {
+                Color::White => ch,
+                Color::Black => ch.to_ascii_lowercase(),
+            };
+            if rights.can_kingside_castle() {
+                fen.push(case(kingside));
+            }
+            if rights.can_queenside_castle() {
+                fen.push(case(queenside));
+            }
+        }
+        if fen.len() == castling_len {
+            fen.push('-');
+        }
+        // En passant
+        match self.get_en_passant() {
+            Some(pos) => fen.push_str(&format!(" {}", pos)),
+            None => fen.push_str(" -"),
+        }
+        // Move counters
+        fen.push_str(" 0 1");
+        // Three-check counters
+        if self.is_three_check() {
+            fen.push_str(&format!(
+                " +{}+{}",
+                self.get_checks(Color::White),
+                self.get_checks(Color::Black)
+            ));
+        }
+        fen
+    }
+
+    /// ### from_fen
+    ///
+    /// Parse a board from a FEN string.
+    /// Halfmove clock and fullmove number are optional and, if provided, they're validated, but ignored.
+    /// A pawn of the player to move standing on the last rank is reported as a pending promotion.
+    /// The castling availability can be written with the classic letters (`KQkq`), which refer to the outermost rook
+    /// on each side of the king (X-FEN), or with the files of the rooks (Shredder-FEN, e.g. `HAha`).
+    /// The FEN may end with the Three-check counters (`+W+B`); in this case the Three-check rules are enabled.
+    /// If the piece placement is followed by the reserves in brackets (e.g. `[Pn]`), the Crazyhouse rules are enabled.
+    pub fn from_fen(fen: &str) -> Result<Board, FenError> {
+        let mut fields: Vec<&str> = fen.split_whitespace().collect();
+        let checks: Option<(u8, u8)> = match fields.last() {
+            Some(last) if last.starts_with('+') => {
+                let checks = parse_checks(last).ok_or(FenError::BadChecks)?;
+                fields.pop();
+                Some(checks)
+            }
+            _ => None,
+        };
+        if fields.len() != 4 && fields.len() != 6 {
+            return Err(FenError::BadFieldCount);
+        }
+        // Reserves
+        let (placement, reserves): (&str, Option<&str>) = match fields[0].find('[') {
+            Some(index) => (
+                &fields[0][..index],
+                Some(
+                    fields[0][index + 1..]
+                        .strip_suffix(']')
+                        .ok_or(FenError::BadReserve)?,
+                ),
+            ),
+            None => (fields[0], None),
+        };
+        // Piece placement
+        let mut builder: BoardBuilder = BoardBuilder::default();
+        let mut promoted: Vec<Position> = Vec::new();
+        let rows: Vec<&str> = placement.split('/').collect();
+        if rows.len() != 8 {
+            return Err(FenError::BadPiecePlacement);
+        }
+        for (i, pieces) in rows.iter().enumerate() {
+            let row: i32 = 7 - i as i32;
+            let mut col: i32 = 0;
+            for ch in pieces.chars() {
+                if let Some(empty) = ch.to_digit(10) {
+                    if empty == 0 || empty > 8 {
+                        return Err(FenError::BadPiecePlacement);
+                    }
+                    col += empty as i32;
+                } else if ch == '~' {
+                    // The previous piece is a promoted pawn
+                    let pos: Position = Position::new(row, col - 1);
+                    if reserves.is_none() || col == 0 || promoted.last() == Some(&pos) {
+                        return Err(FenError::BadPiecePlacement);
+                    }
+                    promoted.push(pos);
+                } else {
+                    let pos: Position = Position::new(row, col);
+                    if pos.is_off_board() {
+                        return Err(FenError::BadPiecePlacement);
+                    }
+                    let piece: Piece =
+                        Piece::from_ascii_char(ch, pos).ok_or(FenError::BadPiecePlacement)?;
+                    builder = builder.piece(piece);
+                    col += 1;
+                }
+            }
+            if col != 8 {
+                return Err(FenError::BadPiecePlacement);
+            }
+        }
+        // Active color
+        builder = builder.player_moving(match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            _ => return Err(FenError::BadActiveColor),
+        });
+        let mut board: Board = builder.build();
+        // Castling
+        if fields[2] != "-" {
+            for ch in fields[2].chars() {
+                board = board.parse_castling(ch).ok_or(FenError::BadCastling)?;
+            }
+        }
+        // En passant
+        if fields[3] != "-" {
+            let pos: Position =
+                Position::from_str(fields[3]).map_err(|_| FenError::BadEnPassant)?;
+            if pos.get_row() != 2 && pos.get_row() != 5 {
+                return Err(FenError::BadEnPassant);
+            }
+            board = board.with_en_passant(Some(pos));
+        }
+        // Move counters
+        if fields.len() == 6 && fields[4..].iter().any(|x| x.parse::<u16>().is_err()) {
+            return Err(FenError::BadMoveNumber);
+        }
+        if let Some(reserves) = reserves {
+            board = board.with_crazyhouse_rules(true);
+            for ch in reserves.chars() {
+                let kind: PieceKind = match PieceKind::from_ascii_char(ch) {
+                    Some(PieceKind::King) | None => return Err(FenError::BadReserve),
+                    Some(kind) => kind,
+                };
+                let color: Color = match ch.is_ascii_uppercase() {
+                    true => Color::White,
+                    false => Color::Black,
+                };
+                board = board.with_reserve(color, board.get_reserve(color).with_piece(kind));
+            }
+            for pos in promoted {
+                if board.get_piece(pos).is_none() {
+                    return Err(FenError::BadPiecePlacement);
+                }
+                board = board.with_promoted(pos, true);
+            }
+        }
+        if let Some((white, black)) = checks {
+            board = board
+                .with_three_check_rules(true)
+                .with_checks(Color::White, white)
+                .with_checks(Color::Black, black);
+        }
+        Ok(board.check_available_pawn_promotion())
+    }
+}
+
+impl Board {
+    /// ### has_classic_castling
+    ///
+    /// Returns whether the castling availability can be written with the classic letters, that is
+    /// for each player who can castle the king is on the e file and the rooks on the a and h files
+    fn has_classic_castling(&self) -> bool {
+        Color::both().iter().copied().all(|color| {
+            let rights: CastlingRights = self.get_castling_rights(color);
+            !(rights.can_kingside_castle() || rights.can_queenside_castle())
+                || (rights.has_standard_rook_files()
+                    && self.get_king_pos(color) == Some(Position::king_pos(color)))
+        })
+    }
+
+    /// ### parse_castling
+    ///
+    /// Enable the castling right described by a character of the FEN castling availability.
+    /// Returns `None` if the character is invalid
+    fn parse_castling(&self, ch: char) -> Option<Self> {
+        let color: Color = match ch.is_ascii_uppercase() {
+            true => Color::White,
+            false => Color::Black,
+        };
+        let rank: i32 = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        let king: i32 = self
+            .get_king_pos(color)
+            .map(|pos| pos.get_col())
+            .unwrap_or(4);
+        let is_rook = |col: &i32| {
+            self.get_piece(Position::new(rank, *col))
+                .map(|piece| piece.is_rook() && piece.get_color() == color)
+                .unwrap_or(false)
+        };
+        let mut rights: CastlingRights = self.get_castling_rights(color);
+        match ch.to_ascii_lowercase() {
+            // X-FEN: the outermost rook on the side of the king
+            'k' => {
+                let file: i32 = ((king + 1)..8).rev().find(is_rook).unwrap_or(7);
+                rights.enable_kingside();
+                rights.set_kingside_rook_file(file as u8);
+            }
+            'q' => {
+                let file: i32 = (0..king).find(is_rook).unwrap_or(0);
+                rights.enable_queenside();
+                rights.set_queenside_rook_file(file as u8);
+            }
+            // Shredder-FEN: the file of the rook
+            file @ 'a'..='h' => {
+                let file: i32 = (file as u8 - b'a') as i32;
+                match file.cmp(&king) {
+                    core::cmp::Ordering::Greater => {
+                        rights.enable_kingside();
+                        rights.set_kingside_rook_file(file as u8);
+                    }
+                    core::cmp::Ordering::Less => {
+                        rights.enable_queenside();
+                        rights.set_queenside_rook_file(file as u8);
+                    }
+                    core::cmp::Ordering::Equal => return None,
+                }
+            }
+            _ => return None,
+        }
+        Some(self.with_castling_rights(color, rights))
+    }
+}
+
+/// ### parse_checks
+///
+/// Parse the Three-check counters (`+W+B`), which can't exceed 3
+fn parse_checks(token: &str) -> Option<(u8, u8)> {
+    let counters: Vec<u8> = token
+        .strip_prefix('+')?
+        .split('+')
+        .map(|x| x.parse::<u8>().ok().filter(|x| *x <= 3))
+        .collect::<Option<Vec<u8>>>()?;
+    match counters.as_slice() {
+        [white, black] => Some((*white, *black)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::Move;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    #[test]
+    fn to_fen() {
+        assert_eq!(Board::default().to_fen().as_str(), START_FEN);
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .change_turn();
+        assert_eq!(
+            board.to_fen().as_str(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::King(Color::Black, E8))
+            .enable_kingside_castle(Color::Black)
+            .build();
+        assert_eq!(board.to_fen().as_str(), "4k3/8/8/8/8/8/8/4K3 w k - 0 1");
+        let board: Board = BoardBuilder::default().build();
+        assert_eq!(board.to_fen().as_str(), "8/8/8/8/8/8/8/8 w - - 0 1");
+    }
+
+    #[test]
+    fn from_fen() {
+        assert_eq!(Board::from_fen(START_FEN).ok().unwrap(), Board::default());
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .change_turn();
+        assert_eq!(
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3")
+                .ok()
+                .unwrap(),
+            board
+        );
+        // Round trip
+        let fen: &str = "r1bq1rk1/pp2bppp/2n1pn2/3p4/2PP4/2N2N2/PP2BPPP/R2QKB1R b KQ - 3 8";
+        let board: Board = Board::from_fen(fen).ok().unwrap();
+        assert_eq!(board.get_turn(), Color::Black);
+        assert_eq!(board.get_piece(C6), Some(Piece::Knight(Color::Black, C6)));
+        assert_eq!(
+            board.to_fen().as_str(),
+            "r1bq1rk1/pp2bppp/2n1pn2/3p4/2PP4/2N2N2/PP2BPPP/R2QKB1R b KQ - 0 1"
+        );
+    }
+
+    #[test]
+    fn shredder_fen() {
+        // Chess960 position with the kingside rook on g1
+        let fen: &str = "rnbqkbrn/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRN w GAga - 0 1";
+        let board: Board = Board::from_fen(fen).ok().unwrap();
+        let rights: CastlingRights = board.get_castling_rights(Color::White);
+        assert_eq!(rights.can_kingside_castle(), true);
+        assert_eq!(rights.can_queenside_castle(), true);
+        assert_eq!(rights.kingside_rook_file(), 6);
+        assert_eq!(rights.queenside_rook_file(), 0);
+        assert_eq!(board.get_castling_rights(Color::Black), rights);
+        assert_eq!(board.to_fen().as_str(), fen);
+        assert_eq!(
+            board.to_fen_with_castling(FenCastling::Shredder).as_str(),
+            fen
+        );
+        assert_eq!(
+            board.to_fen_with_castling(FenCastling::Classic).as_str(),
+            "rnbqkbrn/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRN w KQkq - 0 1"
+        );
+        // X-FEN: the classic letters refer to the outermost rook
+        assert_eq!(
+            Board::from_fen("rnbqkbrn/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRN w KQkq - 0 1")
+                .ok()
+                .unwrap(),
+            board
+        );
+        let board: Board =
+            Board::from_fen("rnbqkbrn/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRN w Ga - 0 1")
+                .ok()
+                .unwrap();
+        assert_eq!(
+            board
+                .get_castling_rights(Color::White)
+                .can_queenside_castle(),
+            false
+        );
+        assert_eq!(
+            board
+                .get_castling_rights(Color::Black)
+                .can_kingside_castle(),
+            false
+        );
+        assert_eq!(board.to_fen().as_str().ends_with(" w Ga - 0 1"), true);
+        // Standard positions use the classic letters, unless asked otherwise
+        assert_eq!(
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1")
+                .ok()
+                .unwrap(),
+            Board::default()
+        );
+        assert_eq!(
+            Board::default()
+                .to_fen_with_castling(FenCastling::Shredder)
+                .as_str(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1"
+        );
+        // The rook file can't be the king file
+        assert_eq!(
+            Board::from_fen("rnbqkbrn/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBRN w E - 0 1"),
+            Err(FenError::BadCastling)
+        );
+    }
+
+    #[test]
+    fn crazyhouse_fen() {
+        let board: Board = Board::crazyhouse();
+        assert_eq!(
+            board.to_fen().as_str(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1"
+        );
+        let fen: &str = "rQ~2kbnr/p1pppppp/8/8/8/8/1PPPPPPP/RNBQKBNR[NPPpb] b KQk - 0 1";
+        let board: Board = Board::from_fen(fen).ok().unwrap();
+        assert_eq!(board.is_crazyhouse(), true);
+        assert_eq!(board.is_promoted(B8), true);
+        assert_eq!(board.is_promoted(A8), false);
+        assert_eq!(board.get_reserve(Color::White).count(PieceKind::Pawn), 2);
+        assert_eq!(board.get_reserve(Color::White).count(PieceKind::Knight), 1);
+        assert_eq!(board.get_reserve(Color::Black).count(PieceKind::Pawn), 1);
+        assert_eq!(board.get_reserve(Color::Black).count(PieceKind::Bishop), 1);
+        assert_eq!(
+            board.to_fen().as_str(),
+            "rQ~2kbnr/p1pppppp/8/8/8/8/1PPPPPPP/RNBQKBNR[NPPbp] b KQk - 0 1"
+        );
+        for fen in ["8/8/8/8/8/8/8/8[K] w - -", "8/8/8/8/8/8/8/8[Q w - -"] {
+            assert_eq!(Board::from_fen(fen), Err(FenError::BadReserve));
+        }
+        for fen in ["Q~7/8/8/8/8/8/8/8 w - -", "1~7/8/8/8/8/8/8/8[] w - -"] {
+            assert_eq!(Board::from_fen(fen), Err(FenError::BadPiecePlacement));
+        }
+    }
+
+    #[test]
+    fn three_check_fen() {
+        let board: Board = Board::three_check().with_checks(Color::White, 2);
+        assert_eq!(board.to_fen(), alloc::format!("{} +2+0", START_FEN));
+        let board: Board = Board::from_fen(board.to_fen().as_str()).ok().unwrap();
+        assert_eq!(board.is_three_check(), true);
+        assert_eq!(board.get_checks(Color::White), 2);
+        assert_eq!(board.get_checks(Color::Black), 0);
+        let board: Board = Board::from_fen("8/8/8/8/8/8/8/8 w - - +1+3").ok().unwrap();
+        assert_eq!(board.is_three_check(), true);
+        assert_eq!(board.get_checks(Color::Black), 3);
+        for fen in [
+            "8/8/8/8/8/8/8/8 w - - +1",
+            "8/8/8/8/8/8/8/8 w - - +1+4",
+            "8/8/8/8/8/8/8/8 w - - +a+0",
+        ] {
+            assert_eq!(Board::from_fen(fen), Err(FenError::BadChecks));
+        }
+    }
+
+    #[test]
+    fn from_fen_errors() {
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 w -"),
+            Err(FenError::BadFieldCount)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8 w - -"),
+            Err(FenError::BadPiecePlacement)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/7 w - -"),
+            Err(FenError::BadPiecePlacement)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/9 w - -"),
+            Err(FenError::BadPiecePlacement)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/7x w - -"),
+            Err(FenError::BadPiecePlacement)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 x - -"),
+            Err(FenError::BadActiveColor)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 w KX -"),
+            Err(FenError::BadCastling)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 w - e4"),
+            Err(FenError::BadEnPassant)
+        );
+        assert_eq!(
+            Board::from_fen("8/8/8/8/8/8/8/8 w - - a 1"),
+            Err(FenError::BadMoveNumber)
+        );
+        assert_eq!(
+            FenError::BadPiecePlacement.to_string().as_str(),
+            "Bad piece placement"
+        );
+    }
+}
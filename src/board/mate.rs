@@ -0,0 +1,130 @@
+//! # Mate
+//!
+//! This module exposes the forced mate search for `Board`
+
+use super::{Board, Move, Promotion};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl Board {
+    /// ### find_mate
+    ///
+    /// Search for a forced checkmate for the player to move within `moves` full moves.
+    /// If a forced mate exists, returns the shortest mating line, made up of both the player's moves and
+    /// the opponent's best (longest) defence; so a mate in `n` is returned as `2n - 1` half-moves.
+    /// Returns `None` if no forced mate exists within `moves`.
+    ///
+    /// Pawns reaching the last rank are always promoted to queen.
+    pub fn find_mate(&self, moves: usize) -> Option<Vec<Move>> {
+        if self.promotion.is_some() {
+            return None;
+        }
+        (1..=moves).find_map(|n| self.mate_in(n))
+    }
+
+    /// ### mate_in
+    ///
+    /// Search for a forced mate in exactly `n` moves or less for the player to move.
+    /// This is an alpha-beta search where the only scores are "mate" and "no mate":
+    /// the search of the defences of an attacking move is cut off as soon as one of them escapes the mate.
+    fn mate_in(&self, n: usize) -> Option<Vec<Move>> {
+        for m in self.get_legal_moves(self.get_turn()) {
+            let next: Board = self.apply_mate_search_move(m);
+            if next.is_checkmate() {
+                return Some(vec![m]);
+            }
+            if n == 1 || next.is_stalemate() {
+                continue;
+            }
+            if let Some(defence) = next.longest_defence(n - 1) {
+                let mut line: Vec<Move> = vec![m];
+                line.extend(defence);
+                return Some(line);
+            }
+        }
+        None
+    }
+
+    /// ### longest_defence
+    ///
+    /// Given a board where the defending player is to move, returns the longest line among the defences,
+    /// if all of them lead to a mate within `n` moves; returns `None` if any defence escapes the mate.
+    fn longest_defence(&self, n: usize) -> Option<Vec<Move>> {
+        let mut longest: Option<Vec<Move>> = None;
+        for reply in self.get_legal_moves(self.get_turn()) {
+            let next: Board = self.apply_mate_search_move(reply);
+            let mate: Vec<Move> = (1..=n).find_map(|k| next.mate_in(k))?;
+            if longest
+                .as_ref()
+                .map(|x| mate.len() >= x.len())
+                .unwrap_or(true)
+            {
+                let mut line: Vec<Move> = vec![reply];
+                line.extend(mate);
+                longest = Some(line);
+            }
+        }
+        longest
+    }
+
+    /// ### apply_mate_search_move
+    ///
+    /// Apply move, promoting pawns to queen, and change turn
+    fn apply_mate_search_move(&self, m: Move) -> Self {
+        let next: Board = self.apply_move(m).check_available_pawn_promotion();
+        match next.promotion {
+            Some(_) => next.promote_pawn(Promotion::Queen),
+            None => next,
+        }
+        .change_turn()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn find_mate_in_one() {
+        // Back rank mate
+        let board: Board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - -")
+            .ok()
+            .unwrap();
+        assert_eq!(board.find_mate(1), Some(vec![Move::Piece(A1, A8)]));
+        assert_eq!(board.find_mate(3), Some(vec![Move::Piece(A1, A8)]));
+        // Promotion to queen
+        let board: Board = Board::from_fen("k7/2P5/1K6/8/8/8/8/8 w - -").ok().unwrap();
+        assert_eq!(board.find_mate(1), Some(vec![Move::Piece(C7, C8)]));
+    }
+
+    #[test]
+    fn find_mate_in_two() {
+        // Rook roller
+        let board: Board = Board::from_fen("7k/8/8/8/8/8/R7/1R4K1 w - -").ok().unwrap();
+        assert_eq!(board.find_mate(1), None);
+        let line: Vec<Move> = board.find_mate(2).unwrap();
+        assert_eq!(line.len(), 3);
+        // Play the line
+        let mut board: Board = board;
+        for m in line.iter() {
+            board = board.apply_mate_search_move(*m);
+        }
+        assert!(board.is_checkmate());
+    }
+
+    #[test]
+    fn find_no_mate() {
+        assert_eq!(Board::default().find_mate(2), None);
+        // Lone king can't mate
+        let board: Board = Board::from_fen("7k/8/8/8/8/8/8/K7 w - -").ok().unwrap();
+        assert_eq!(board.find_mate(2), None);
+        // Stalemate is not a mate
+        let board: Board = Board::from_fen("7k/8/6K1/8/8/8/8/5Q2 w - -").ok().unwrap();
+        assert_eq!(board.find_mate(1), Some(vec![Move::Piece(F1, F8)]));
+    }
+}
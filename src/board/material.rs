@@ -0,0 +1,107 @@
+//! # Material
+//!
+//! This module exposes the material type, which keeps track of the value of the pieces on the board
+
+use crate::{Color, Piece};
+
+/// ### Material
+///
+/// Running totals of the material and of the weighted value of the pieces of both players.
+/// Totals are updated every time a piece is added or removed from the board, so they don't have to
+/// be computed from scratch.
+/// Weighted values are stored as half points, since position weights are all multiples of 0.5;
+/// in this way totals are always exact and can be compared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Material {
+    white: i32,
+    black: i32,
+    white_weighted: i32,
+    black_weighted: i32,
+}
+
+impl Material {
+    /// ### add
+    ///
+    /// Add piece value to totals
+    pub fn add(&mut self, piece: Piece) {
+        let (material, weighted) = self.totals(piece.get_color());
+        *material += piece.get_material_value();
+        *weighted += Self::half_points(piece);
+    }
+
+    /// ### remove
+    ///
+    /// Remove piece value from totals
+    pub fn remove(&mut self, piece: Piece) {
+        let (material, weighted) = self.totals(piece.get_color());
+        *material -= piece.get_material_value();
+        *weighted -= Self::half_points(piece);
+    }
+
+    /// ### material
+    ///
+    /// Get material value for player
+    pub fn material(&self, color: Color) -> i32 {
+        match color {
+            Color::White => self.white,
+            Color::Black => self.black,
+        }
+    }
+
+    /// ### weighted
+    ///
+    /// Get weighted value for player
+    pub fn weighted(&self, color: Color) -> f64 {
+        match color {
+            Color::White => self.white_weighted as f64 / 2.0,
+            Color::Black => self.black_weighted as f64 / 2.0,
+        }
+    }
+
+    /// ### totals
+    ///
+    /// Get mutable references to material and weighted totals for player
+    fn totals(&mut self, color: Color) -> (&mut i32, &mut i32) {
+        match color {
+            Color::White => (&mut self.white, &mut self.white_weighted),
+            Color::Black => (&mut self.black, &mut self.black_weighted),
+        }
+    }
+
+    /// ### half_points
+    ///
+    /// Get weighted value of piece as half points
+    fn half_points(piece: Piece) -> i32 {
+        (piece.get_weighted_value() * 2.0) as i32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn material() {
+        let mut material: Material = Material::default();
+        assert_eq!(material.material(Color::White), 0);
+        assert_eq!(material.weighted(Color::White), 0.0);
+        material.add(Piece::Knight(Color::White, B1));
+        material.add(Piece::Pawn(Color::Black, E7));
+        assert_eq!(material.material(Color::White), 3);
+        assert_eq!(
+            material.weighted(Color::White),
+            Piece::Knight(Color::White, B1).get_weighted_value()
+        );
+        assert_eq!(material.material(Color::Black), 1);
+        assert_eq!(
+            material.weighted(Color::Black),
+            Piece::Pawn(Color::Black, E7).get_weighted_value()
+        );
+        material.remove(Piece::Knight(Color::White, B1));
+        assert_eq!(material.material(Color::White), 0);
+        assert_eq!(material.weighted(Color::White), 0.0);
+    }
+}
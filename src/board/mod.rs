@@ -8,13 +8,19 @@
 //!
 //! - default chess
 //! - horde variant
+//! - three-check variant
 //! - Dunsany's chess
+//! - no-castling chess
+//! - odds games (`with_odds`)
+//! - endgame training positions (e.g. `endgame_lucena`)
 //!
 
-use super::{Color, Move, Piece, Position, Square, BLACK, WHITE};
+use super::tables::{KING_ATTACKS, KNIGHT_ATTACKS};
+use super::{Color, Direction, Move, Piece, PieceKind, Position, Square, BLACK, WHITE};
 use crate::position::{
     A1, A2, A3, A4, A7, A8, B1, B5, B8, C1, C5, C8, D1, D8, E1, E8, F1, F5, F8, G1, G5, G8, H1, H8,
 };
+use crate::MoveParseError;
 
 use alloc::{
     string::{String, ToString},
@@ -25,19 +31,41 @@ use core::cmp::Ordering;
 // Modules
 mod builder;
 mod castling_rights;
+mod crazyhouse;
+mod endgames;
+mod explain;
+mod fen;
+mod mate;
+mod material;
+mod odds;
+mod render;
+mod repetition;
+mod rules;
 mod types;
+mod uci;
 // Use
-use castling_rights::CastlingRights;
+use material::Material;
 // Export
-pub use builder::BoardBuilder;
-pub use types::{MoveResult, Promotion, RatedMove};
+pub use builder::{BoardBuilder, PositionError};
+pub use castling_rights::CastlingRights;
+pub use crazyhouse::Reserve;
+pub use explain::IllegalReason;
+pub use fen::{FenCastling, FenError};
+pub use odds::Odds;
+pub use render::{BoardDisplay, RenderError, RenderOptions};
+pub use repetition::RepetitionTracker;
+pub use rules::{CrazyhouseRules, HordeRules, Rules, StandardRules, ThreeCheckRules, VariantRules};
+pub use types::{GameStatus, MoveResult, PositionKey, Promotion, RatedMove};
+pub use uci::UciError;
 
 // -- Board
 
 /// ## Board
 ///
-/// Contains the Chess game itself
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// Contains the Chess game itself.
+/// Equality and hashing take the whole state of the board into account, including the piece taken on the last turn
+/// and the pending promotion, so two boards with the same position may differ: use `position_key` to compare positions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Board {
     /// the 64 squares of the chess board
     squares: [Square; 64],
@@ -53,6 +81,22 @@ pub struct Board {
     black_castling_rights: CastlingRights,
     /// describes which player has to move the next turn
     turn: Color,
+    /// running totals of the material on the board
+    material: Material,
+    /// rules of the variant in use
+    rules: Rules,
+    /// checks delivered by white player
+    white_checks: u8,
+    /// checks delivered by black player
+    black_checks: u8,
+    /// pieces captured by white player, which can be dropped
+    white_reserve: Reserve,
+    /// pieces captured by black player, which can be dropped
+    black_reserve: Reserve,
+    /// mask of the squares occupied by promoted pawns (tracked only in Crazyhouse)
+    promoted: u64,
+    /// moves the player to move can still play before the turn passes to the opponent (two-move odds)
+    odds_moves: u8,
 }
 
 impl Default for Board {
@@ -96,14 +140,22 @@ impl Board {
             white_castling_rights: CastlingRights::default(),
             black_castling_rights: CastlingRights::default(),
             turn: WHITE,
+            material: Material::default(),
+            rules: Rules::Standard,
+            white_checks: 0,
+            black_checks: 0,
+            white_reserve: Reserve::default(),
+            black_reserve: Reserve::default(),
+            promoted: 0,
+            odds_moves: 0,
         }
     }
 
     /// ### horde
     ///
-    /// Create the default board for the Horde variant
+    /// Create the default board for the Horde variant, with the Horde rules enabled
     pub fn horde() -> Self {
-        BoardBuilder::from(Board::default())
+        BoardBuilder::from(Board::default().with_horde_rules(true))
             .row(Piece::Pawn(WHITE, A1))
             .row(Piece::Pawn(WHITE, A2))
             .row(Piece::Pawn(WHITE, A3))
@@ -115,6 +167,23 @@ impl Board {
             .build()
     }
 
+    /// ### three_check
+    ///
+    /// Create the default board for the Three-check variant, with the Three-check rules enabled
+    pub fn three_check() -> Self {
+        Board::default().with_three_check_rules(true)
+    }
+
+    /// ### no_castling
+    ///
+    /// Create the default board without castling rights, for no-castling chess.
+    /// Since castling rights can be restored (e.g. importing a FEN), use `Options::allow_castling` to forbid castling in a `Game`
+    pub fn no_castling() -> Self {
+        BoardBuilder::from(Board::default())
+            .disable_castling()
+            .build()
+    }
+
     /// ### dunsany
     ///
     /// Create the default board for the dunsany's chess
@@ -146,6 +215,16 @@ impl Board {
         self.en_passant
     }
 
+    /// ### get_castling_rights
+    ///
+    /// Get the castling rights of the provided player
+    pub fn get_castling_rights(&self, color: Color) -> CastlingRights {
+        match color {
+            WHITE => self.white_castling_rights,
+            BLACK => self.black_castling_rights,
+        }
+    }
+
     /// ### get_promoting_pawn
     ///
     /// Get the promoting pawn
@@ -160,24 +239,38 @@ impl Board {
         self.taken_piece
     }
 
+    /// ### position_key
+    ///
+    /// Get the key which identifies the current position (pieces placement, turn, castling rights and en passant).
+    /// The key can be used in hash maps and sets
+    pub fn position_key(&self) -> PositionKey {
+        PositionKey::from(self)
+    }
+
     /// ### get_material_advantage
     ///
     /// Get the value of the material advantage of a certain player
     #[inline]
     pub fn get_material_advantage(&self, color: Color) -> i32 {
-        self.squares
-            .iter()
-            .map(|square| match square.get_piece() {
-                Some(piece) => {
-                    if piece.get_color() == color {
-                        piece.get_material_value()
-                    } else {
-                        -piece.get_material_value()
-                    }
-                }
-                None => 0,
-            })
-            .sum()
+        self.material.material(color) - self.material.material(!color)
+    }
+
+    /// ### material_signature
+    ///
+    /// Get the material signature of the board, which lists the pieces of White, then `v`, then the pieces of Black,
+    /// each side starting from the king and then by descending value (e.g. `KRPvKR`).
+    /// Letters are always uppercase, as in the common notation for endgames
+    pub fn material_signature(&self) -> String {
+        let side = |color: Color| -> String {
+            let mut kinds: Vec<PieceKind> = self
+                .pieces()
+                .filter(|piece| piece.get_color() == color)
+                .map(|piece| piece.kind())
+                .collect();
+            kinds.sort();
+            kinds.iter().map(|kind| kind.to_ascii_char()).collect()
+        };
+        format!("{}v{}", side(WHITE), side(BLACK))
     }
 
     /// ### get_piece
@@ -188,7 +281,39 @@ impl Board {
         if pos.is_off_board() {
             return None;
         }
-        self.squares[((7 - pos.get_row()) * 8 + pos.get_col()) as usize].get_piece()
+        self.squares[Self::square_index(pos)].get_piece()
+    }
+
+    /// ### iter
+    ///
+    /// Iterate over the 64 squares of the board, getting the position and the piece placed on it.
+    /// Squares are visited rank by rank, from the first to the eighth, and from the `a` to the `h` file on each rank
+    /// (`a1`, `b1`, ..., `h1`, `a2`, ..., `h8`)
+    pub fn iter(&self) -> impl Iterator<Item = (Position, Option<Piece>)> + '_ {
+        (0..64).map(move |i| {
+            let pos: Position = Position::new(i / 8, i % 8);
+            (pos, self.squares[Self::square_index(pos)].get_piece())
+        })
+    }
+
+    /// ### pieces
+    ///
+    /// Iterate over the pieces on the board, in the same order as `iter`
+    pub fn pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        self.iter().filter_map(|(_, piece)| piece)
+    }
+
+    /// ### piece_grid
+    ///
+    /// Get the board as a grid of optional pieces.
+    /// The grid is indexed as `grid[rank][file]`, where the first row is the **eighth** rank (as the board
+    /// is seen from the white player) and the first column is the `a` file; so `grid[0][0]` is `a8` and `grid[7][7]` is `h1`
+    pub fn piece_grid(&self) -> [[Option<Piece>; 8]; 8] {
+        let mut grid: [[Option<Piece>; 8]; 8] = [[None; 8]; 8];
+        for (i, square) in self.squares.iter().enumerate() {
+            grid[i / 8][i % 8] = square.get_piece();
+        }
+        grid
     }
 
     /// ### get_player_pieces
@@ -203,6 +328,17 @@ impl Board {
             .collect()
     }
 
+    /// ### bishops_on_color
+    ///
+    /// Count the bishops of the provided player placed on light squares (if `light` is true) or
+    /// on dark squares (if `light` is false)
+    pub fn bishops_on_color(&self, color: Color, light: bool) -> usize {
+        self.get_player_pieces(color)
+            .iter()
+            .filter(|x| x.is_bishop() && x.get_pos().is_light_square() == light)
+            .count()
+    }
+
     /// ### get_king_pos
     ///
     /// If there is a king on the board, return the position that it sits on.
@@ -218,6 +354,17 @@ impl Board {
         king_pos
     }
 
+    /// ### all_kings
+    ///
+    /// Get the position of the kings of both players, white first.
+    /// Players without a king on the board are skipped
+    pub fn all_kings(&self) -> Vec<(Color, Position)> {
+        Color::both()
+            .iter()
+            .filter_map(|color| self.get_king_pos(*color).map(|pos| (*color, pos)))
+            .collect()
+    }
+
     /// ### get_legal_moves
     ///
     /// Returns the list of available moves for player with color `color`
@@ -231,10 +378,25 @@ impl Board {
                 }
             }
         }
+        result.extend(self.get_legal_drops(color));
+        self.rules.filter_legal_moves(self, color, &mut result);
 
         result
     }
 
+    /// ### has_any_legal_move
+    ///
+    /// Returns whether player with color `color` has at least a legal move.
+    /// This is the same as `!get_legal_moves(color).is_empty()`, but it stops at the first legal move found
+    pub fn has_any_legal_move(&self, color: Color) -> bool {
+        self.squares
+            .iter()
+            .filter_map(|square| square.get_piece())
+            .filter(|piece| piece.get_color() == color)
+            .any(|piece| piece.has_legal_move(self))
+            || self.has_legal_drop(color)
+    }
+
     /// ### get_piece_legal_moves
     ///
     /// Get legal moves for piece at `pos` position
@@ -253,19 +415,216 @@ impl Board {
     /// and material value.
     #[inline]
     pub fn get_player_value(&self, color: Color) -> f64 {
-        self.squares
-            .iter()
-            .map(|square| match square.get_piece() {
-                Some(piece) => {
-                    if piece.get_color() == color {
-                        piece.get_weighted_value()
-                    } else {
-                        -piece.get_weighted_value()
-                    }
-                }
-                None => 0.0,
+        self.material.weighted(color) - self.material.weighted(!color)
+    }
+
+    /// ### evaluate_centipawns
+    ///
+    /// Evaluate the board for a given color in centipawns, as most chess engines do.
+    /// Pieces are valued with `Piece::value_centipawns`, plus the weight of their position scaled by 10,
+    /// so that a weight of `0.5` is worth 5 centipawns
+    pub fn evaluate_centipawns(&self, color: Color) -> i32 {
+        let value = |color: Color| -> i32 {
+            self.get_player_pieces(color)
+                .iter()
+                .map(|piece| piece.value_centipawns() + (piece.position_weight() * 10.0) as i32)
+                .sum()
+        };
+        value(color) - value(!color)
+    }
+
+    /// ### king_distance_to_edge
+    ///
+    /// Get the distance between the king of the provided player and the nearest edge of the board.
+    /// Returns `None` if the player has no king on the board
+    pub fn king_distance_to_edge(&self, color: Color) -> Option<i32> {
+        self.get_king_pos(color).map(|x| x.distance_to_edge())
+    }
+
+    /// ### is_passed_pawn
+    ///
+    /// Returns whether there is a passed pawn at `pos`, that is a pawn which can't be stopped by enemy pawns
+    /// because there are none in front of it, on its file or on the adjacent files.
+    /// Returns `false` if there is no pawn at `pos`
+    pub fn is_passed_pawn(&self, pos: Position) -> bool {
+        let color: Color = match self.get_piece(pos) {
+            Some(Piece::Pawn(color, _)) => color,
+            _ => return false,
+        };
+        !self.pieces().any(|piece| {
+            let enemy_pos: Position = piece.get_pos();
+            let ahead: bool = match color {
+                WHITE => enemy_pos.get_row() > pos.get_row(),
+                BLACK => enemy_pos.get_row() < pos.get_row(),
+            };
+            piece.is_pawn()
+                && piece.get_color() != color
+                && ahead
+                && (enemy_pos.get_col() - pos.get_col()).abs() <= 1
+        })
+    }
+
+    /// ### passed_pawns
+    ///
+    /// Get the positions of the passed pawns of the provided player (see `is_passed_pawn`)
+    pub fn passed_pawns(&self, color: Color) -> Vec<Position> {
+        self.pieces()
+            .filter(|piece| piece.is_pawn() && piece.get_color() == color)
+            .map(|piece| piece.get_pos())
+            .filter(|pos| self.is_passed_pawn(*pos))
+            .collect()
+    }
+
+    /// ### positional_value
+    ///
+    /// Get the positional value of the board for a given color.
+    /// This is the contribution of the piece positions to `get_player_value`, without the material value;
+    /// so `get_player_value` is `positional_value` plus 10 times `get_material_advantage`
+    #[inline]
+    pub fn positional_value(&self, color: Color) -> f64 {
+        self.get_player_value(color) - (self.get_material_advantage(color) * 10) as f64
+    }
+
+    /// ### mobility
+    ///
+    /// Get the mobility for the provided player, which is the amount of legal moves
+    /// the player could make, regardless of whose turn it is.
+    pub fn mobility(&self, color: Color) -> usize {
+        self.with_turn(color).get_legal_moves(color).len()
+    }
+
+    /// ### get_attackers
+    ///
+    /// Get the pieces of `by_color` attacking `pos`.
+    /// Pinned pieces are reported too, since they still attack the square
+    pub fn get_attackers(&self, pos: Position, by_color: Color) -> Vec<Piece> {
+        self.get_player_pieces(by_color)
+            .into_iter()
+            .filter(|x| x.is_legal_attack(pos, self))
+            .collect()
+    }
+
+    /// ### get_piece_attacks
+    ///
+    /// Get the squares attacked by the piece at `pos`, in the same order as `iter`.
+    /// Unlike the legal moves, the squares are reported even if the piece is pinned, and a pawn attacks its diagonals
+    /// even if they are empty; the squares occupied by the pieces of the same player are not attacked.
+    /// Returns an empty vector if there is no piece at `pos`
+    pub fn get_piece_attacks(&self, pos: Position) -> Vec<Position> {
+        match self.get_piece(pos) {
+            Some(piece) => self
+                .iter()
+                .map(|(target, _)| target)
+                .filter(|target| *target != pos && piece.is_legal_attack(*target, self))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// ### attacked_squares
+    ///
+    /// Get the squares attacked by at least one piece of `color` (see `get_piece_attacks`), in the same order as `iter`
+    pub fn attacked_squares(&self, color: Color) -> Vec<Position> {
+        let pieces: Vec<Piece> = self.get_player_pieces(color);
+        self.iter()
+            .map(|(target, _)| target)
+            .filter(|target| {
+                pieces
+                    .iter()
+                    .any(|piece| piece.get_pos() != *target && piece.is_legal_attack(*target, self))
             })
-            .sum()
+            .collect()
+    }
+
+    /// ### control_map
+    ///
+    /// Get, for each square, the amount of white pieces attacking it minus the amount of black pieces attacking it
+    /// (see `get_attackers`); so positive values are squares controlled by white and negative values by black.
+    /// Squares are indexed as `row * 8 + col`, in the same order as `iter` (`a1`, `b1`, ..., `h8`)
+    pub fn control_map(&self) -> [i8; 64] {
+        let mut map: [i8; 64] = [0; 64];
+        for (i, (pos, _)) in self.iter().enumerate() {
+            map[i] = self.get_attackers(pos, WHITE).len() as i8
+                - self.get_attackers(pos, BLACK).len() as i8;
+        }
+        map
+    }
+
+    /// ### smallest_attacker
+    ///
+    /// Get the least valuable piece of `by_color` attacking `pos`,
+    /// which is the piece to capture with first in an exchange
+    pub fn smallest_attacker(&self, pos: Position, by_color: Color) -> Option<Piece> {
+        self.get_attackers(pos, by_color)
+            .into_iter()
+            .min_by_key(|x| x.get_material_value())
+    }
+
+    /// ### see_ge
+    ///
+    /// Returns whether the static exchange evaluation (SEE) of a move is greater or equal than `threshold`.
+    /// The exchange on the destination square is played out with the least valuable attacker first,
+    /// stopping as soon as the outcome relative to the threshold is known, so it's cheaper than computing the exact value.
+    /// Pieces behind the attackers (x-rays) are taken into account, while promotions are not.
+    /// Castling and resignation are evaluated as `0`.
+    pub fn see_ge(&self, m: Move, threshold: i32) -> bool {
+        let (from, to): (Position, Position) = match m {
+            Move::Piece(from, to) => (from, to),
+            _ => return threshold <= 0,
+        };
+        let piece: Piece = match self.get_piece(from) {
+            Some(piece) => piece,
+            None => return threshold <= 0,
+        };
+        let en_passant: bool =
+            piece.is_pawn() && self.has_no_piece(to) && self.en_passant == Some(to);
+        let captured: i32 = match self.get_piece(to) {
+            Some(captured) => captured.get_material_value(),
+            None if en_passant => 1,
+            None => 0,
+        };
+        // Even if the piece is taken for free, threshold is not reached
+        let mut swap: i32 = captured - threshold;
+        if swap < 0 {
+            return false;
+        }
+        // Even if the piece is lost, threshold is reached
+        swap = piece.get_material_value() - swap;
+        if swap <= 0 {
+            return true;
+        }
+        // Play out the exchange
+        let mut board: Board = *self;
+        if en_passant {
+            board.take_piece(to.pawn_back(piece.get_color()));
+        }
+        board.take_piece(from);
+        board.put_piece(to, piece);
+        let mut color: Color = piece.get_color();
+        let mut result: bool = true;
+        loop {
+            color = !color;
+            // Get least valuable attacker
+            let attacker: Piece = match board.smallest_attacker(to, color) {
+                Some(attacker) => attacker,
+                None => break,
+            };
+            result = !result;
+            let mut next: Board = board;
+            next.take_piece(attacker.get_pos());
+            next.put_piece(to, attacker);
+            // King can capture only if the square is not defended
+            if attacker.is_king() {
+                let defended: bool = !next.get_attackers(to, !color).is_empty();
+                return if defended { !result } else { result };
+            }
+            swap = attacker.get_material_value() - swap;
+            if swap < result as i32 {
+                break;
+            }
+            board = next;
+        }
+        result
     }
 
     /// ### get_rating
@@ -360,39 +719,48 @@ impl Board {
         self.get_piece(pos).is_none()
     }
 
+    /// ### path_clear
+    ///
+    /// Returns whether the squares between `from` and `to` (both excluded) are empty, if the two squares are on the
+    /// same rank, file or diagonal. Returns false if the squares are not aligned
+    pub fn path_clear(&self, from: Position, to: Position) -> bool {
+        if !from.is_orthogonal_to(to) && !from.is_diagonal_to(to) {
+            return false;
+        }
+        let row_step: i32 = (to.get_row() - from.get_row()).signum();
+        let col_step: i32 = (to.get_col() - from.get_col()).signum();
+        let distance: i32 = from.chebyshev_distance(to);
+        (1..distance).all(|i| {
+            self.has_no_piece(Position::new(
+                from.get_row() + row_step * i,
+                from.get_col() + col_step * i,
+            ))
+        })
+    }
+
     /// ### is_threatened
     ///
     /// Is a square threatened by an enemy piece?
     pub fn is_threatened(&self, pos: Position, ally_color: Color) -> bool {
-        for (i, square) in self.squares.iter().enumerate() {
-            let row = 7 - i / 8;
-            let col = i % 8;
-            let square_pos = Position::new(row as i32, col as i32);
-            if !square_pos.is_orthogonal_to(pos)
-                && !square_pos.is_diagonal_to(pos)
-                && !square_pos.is_knight_move(pos)
-            {
-                continue;
-            }
-
-            if let Some(piece) = square.get_piece() {
-                if piece.get_color() == ally_color {
-                    continue;
-                }
-
-                if piece.is_legal_attack(pos, self) {
-                    return true;
-                }
-            }
-        }
-
-        false
+        self.pieces()
+            .filter(|piece| piece.get_color() != ally_color)
+            .filter(|piece| {
+                let square_pos: Position = piece.get_pos();
+                square_pos.is_orthogonal_to(pos)
+                    || square_pos.is_diagonal_to(pos)
+                    || square_pos.is_knight_move(pos)
+            })
+            .any(|piece| piece.is_legal_attack(pos, self))
     }
 
     /// ### can_kingside_castle
     ///
     /// Can a given player castle kingside?
+    /// The king can't castle out of check, nor pass through or land on a square attacked by the opponent (f and g files)
     pub fn can_kingside_castle(&self, color: Color) -> bool {
+        if !self.rules.allow_castling() {
+            return false;
+        }
         let right_of_king = Position::king_pos(color).next_right();
         match color {
             WHITE => {
@@ -421,7 +789,12 @@ impl Board {
     /// ### can_queenside_castle
     ///
     /// Can a given player castle queenside?
+    /// The king can't castle out of check, nor pass through or land on a square attacked by the opponent (d and c files).
+    /// The b-file square must be empty, but it can be attacked, since the king doesn't cross it
     pub fn can_queenside_castle(&self, color: Color) -> bool {
+        if !self.rules.allow_castling() {
+            return false;
+        }
         match color {
             WHITE => {
                 self.has_no_piece(Position::new(0, 1))
@@ -432,6 +805,7 @@ impl Board {
                     && self.white_castling_rights.can_queenside_castle()
                     && !self.is_in_check(color)
                     && !self.is_threatened(Position::queen_pos(color), color)
+                    && !self.is_threatened(Position::queen_pos(color).next_left(), color)
             }
             BLACK => {
                 self.has_no_piece(Position::new(7, 1))
@@ -442,6 +816,7 @@ impl Board {
                     && self.black_castling_rights.can_queenside_castle()
                     && !self.is_in_check(color)
                     && !self.is_threatened(Position::queen_pos(color), color)
+                    && !self.is_threatened(Position::queen_pos(color).next_left(), color)
             }
         }
     }
@@ -473,6 +848,7 @@ impl Board {
                 }
                 _ => false,
             },
+            Move::Drop(kind, pos) => self.is_legal_drop(kind, pos, player_color),
             Move::Resign => true,
         }
     }
@@ -486,26 +862,31 @@ impl Board {
     /// 1. The player only has a king
     /// 2. The player only has a king and a knight
     /// 3. The player only has a king and two knights
-    /// 4. The player only has a king and a bishop
-    /// 5. The player only has a king and two bishops
+    /// 4. The player only has a king and bishops, all standing on squares of the same color
     ///
+    /// A king and two bishops on squares of opposite colors can checkmate, so they're sufficient material
     pub fn has_sufficient_material(&self, color: Color) -> bool {
+        // e.g. captured pieces can be dropped in Crazyhouse
+        if !self.rules.insufficient_material_draws() {
+            return true;
+        }
         // Get and sort player pieces
         let mut pieces = self.get_player_pieces(color);
         pieces.sort();
+        let bishops_on_same_color = || {
+            pieces[1..].iter().all(|x| {
+                x.is_bishop() && x.get_pos().square_color() == pieces[1].get_pos().square_color()
+            })
+        };
 
         !(pieces.is_empty()
             || (pieces.len() == 1 && pieces[0].is_king())
             || (pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_knight())
-            || (pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_bishop())
             || (pieces.len() == 3
                 && pieces[0].is_king()
                 && pieces[1].is_knight()
                 && pieces[2].is_knight())
-            || (pieces.len() == 3
-                && pieces[0].is_king()
-                && pieces[1].is_bishop()
-                && pieces[2].is_bishop()))
+            || (pieces[0].is_king() && bishops_on_same_color()))
     }
 
     /// ### has_insufficient_material
@@ -517,21 +898,44 @@ impl Board {
     /// 1. The player only has a king
     /// 2. The player only has a king and a knight
     /// 3. The player only has a king and two knights
-    /// 4. The player only has a king and a bishop
-    /// 5. The player only has a king and two bishops
+    /// 4. The player only has a king and bishops, all standing on squares of the same color
     ///
     #[inline]
     pub fn has_insufficient_material(&self, color: Color) -> bool {
         !self.has_sufficient_material(color)
     }
 
+    /// ### is_dead_position
+    ///
+    /// Returns whether neither player can checkmate because of the material on the board, so the game is drawn.
+    /// Both players must have insufficient material and, if both of them have minor pieces, the position is dead
+    /// only if all the minor pieces are bishops standing on squares of the same color
+    /// (e.g. a king and a bishop can be checkmated by a king and a bishop on squares of the opposite color,
+    /// or by a king and a knight, with the help of the loser)
+    pub fn is_dead_position(&self) -> bool {
+        if self.has_sufficient_material(WHITE) || self.has_sufficient_material(BLACK) {
+            return false;
+        }
+        let minor_pieces: Vec<Piece> = self.pieces().filter(|x| !x.is_king()).collect();
+        let both_sides: bool = Color::both()
+            .iter()
+            .all(|color| minor_pieces.iter().any(|x| x.get_color() == *color));
+        !both_sides
+            || minor_pieces.iter().all(|x| {
+                x.is_bishop()
+                    && x.get_pos().square_color() == minor_pieces[0].get_pos().square_color()
+            })
+    }
+
     /// ### is_stalemate
     ///
     /// Is the current player in stalemate?
     pub fn is_stalemate(&self) -> bool {
-        (self.get_legal_moves(self.get_turn()).is_empty() && !self.is_in_check(self.get_turn()))
-            || (self.has_insufficient_material(self.turn)
-                && self.has_insufficient_material(!self.turn))
+        if self.rules.extra_victory(self, None).is_some() {
+            return false;
+        }
+        (!self.is_in_check(self.get_turn()) && !self.has_any_legal_move(self.get_turn()))
+            || self.is_dead_position()
     }
 
     /// ### is_check
@@ -541,11 +945,219 @@ impl Board {
         self.is_in_check(self.get_turn())
     }
 
+    /// ### checkers
+    ///
+    /// Get the pieces giving check to the current player
+    pub fn checkers(&self) -> Vec<Piece> {
+        match self.get_king_pos(self.get_turn()) {
+            Some(king) => self.get_attackers(king, !self.get_turn()),
+            None => Vec::new(),
+        }
+    }
+
+    /// ### move_gives_check
+    ///
+    /// Returns whether the provided move, played by the current player, puts the opponent's king in check.
+    /// The result is the same as applying the move and looking for the check, but only the squares changed by the move
+    /// are examined, without copying the board; so this is cheaper during a search.
+    /// As when the move is applied, a pawn reaching the last rank is not promoted yet
+    pub fn move_gives_check(&self, m: Move) -> bool {
+        let color: Color = self.get_turn();
+        let king: Position = match self.get_king_pos(!color) {
+            Some(king) => king,
+            None => return false,
+        };
+        // The squares changed by the move, with the piece placed on each of them, in the order they are changed
+        let mut changes: [(Position, Option<Piece>); 4] = [(king, None); 4];
+        let mut len: usize = 0;
+        let mut change = |pos: Position, piece: Option<Piece>| {
+            changes[len] = (pos, piece);
+            len += 1;
+        };
+        match m {
+            Move::Piece(from, to) => {
+                if let (Some(piece), true) = (self.get_piece(from), to.is_on_board()) {
+                    change(from, None);
+                    change(to, Some(piece.move_to(to)));
+                    if let (Some(en_passant), Piece::Pawn(pawn_color, _)) = (self.en_passant, piece)
+                    {
+                        let up: Position = from.pawn_up(pawn_color);
+                        if en_passant == to
+                            && (en_passant == up.next_left() || en_passant == up.next_right())
+                        {
+                            change(en_passant.pawn_back(pawn_color), None);
+                        }
+                    }
+                }
+            }
+            Move::KingSideCastle | Move::QueenSideCastle => {
+                let king_pos: Position = Position::king_pos(color);
+                if self.get_piece(king_pos) != Some(Piece::King(color, king_pos)) {
+                    return self.apply_move(m).is_in_check(!color);
+                }
+                let (rook_pos, king_to, rook_to): (Position, Position, Position) = match m {
+                    Move::KingSideCastle => (
+                        Position::new(king_pos.get_row(), 7),
+                        king_pos.next_right().next_right(),
+                        king_pos.next_right(),
+                    ),
+                    _ => (
+                        Position::new(king_pos.get_row(), 0),
+                        king_pos.next_left().next_left(),
+                        king_pos.next_left(),
+                    ),
+                };
+                change(king_pos, None);
+                change(king_to, Some(Piece::King(color, king_to)));
+                if let Some(rook) = self.get_piece(rook_pos) {
+                    change(rook_pos, None);
+                    change(rook_to, Some(rook.move_to(rook_to)));
+                }
+            }
+            Move::Drop(kind, pos) => {
+                if pos.is_on_board() {
+                    change(pos, Some(Piece::new(kind, color, pos)));
+                }
+            }
+            Move::Resign => {}
+        }
+        let changes: &[(Position, Option<Piece>)] = &changes[..len];
+        let piece_at = |pos: Position| -> Option<Piece> {
+            match changes.iter().rev().find(|(changed, _)| *changed == pos) {
+                Some((_, piece)) => *piece,
+                None => self.get_piece(pos),
+            }
+        };
+        // The king may have been captured
+        if piece_at(king) != Some(Piece::King(!color, king)) {
+            return false;
+        }
+        let is_attacker = |pos: Position, kind: PieceKind| -> bool {
+            piece_at(pos) == Some(Piece::new(kind, color, pos))
+        };
+        let pawns: [Option<Position>; 2] = match king.try_pawn_back(color) {
+            Some(back) => [back.try_next_left(), back.try_next_right()],
+            None => [None, None],
+        };
+        let slider = |directions: &[Direction], kind: PieceKind| -> bool {
+            directions.iter().any(|direction| {
+                king.ray(*direction)
+                    .find_map(piece_at)
+                    .map(|piece| {
+                        piece.get_color() == color
+                            && (piece.kind() == kind || piece.kind() == PieceKind::Queen)
+                    })
+                    .unwrap_or(false)
+            })
+        };
+        KNIGHT_ATTACKS
+            .get(king)
+            .iter()
+            .any(|pos| is_attacker(*pos, PieceKind::Knight))
+            || KING_ATTACKS
+                .get(king)
+                .iter()
+                .any(|pos| is_attacker(*pos, PieceKind::King))
+            || pawns
+                .iter()
+                .flatten()
+                .any(|pos| is_attacker(*pos, PieceKind::Pawn))
+            || slider(&Direction::ORTHOGONALS, PieceKind::Rook)
+            || slider(&Direction::DIAGONALS, PieceKind::Bishop)
+    }
+
     /// ### is_checkmate
     ///
     /// Is the current player in checkmate?
     pub fn is_checkmate(&self) -> bool {
-        self.is_in_check(self.get_turn()) && self.get_legal_moves(self.get_turn()).is_empty()
+        self.is_in_check(self.get_turn()) && !self.has_any_legal_move(self.get_turn())
+    }
+
+    /// ### game_status
+    ///
+    /// Get the most specific status of the game for the player to move, in this order:
+    /// victory granted by the variant rules, checkmate, stalemate, insufficient material, check and in progress.
+    /// Since the board doesn't keep the history of the game, `GameStatus::FiftyMove` and `GameStatus::Repetition`
+    /// are never returned; use `Game::status()` to take them into account
+    pub fn game_status(&self) -> GameStatus {
+        let turn: Color = self.get_turn();
+        if let Some(winner) = self.rules.extra_victory(self, None) {
+            return GameStatus::VariantVictory(winner);
+        }
+        let in_check: bool = self.is_in_check(turn);
+        let can_move: bool = self.has_any_legal_move(turn);
+        if in_check && !can_move {
+            GameStatus::Checkmate(!turn)
+        } else if !can_move {
+            GameStatus::Stalemate
+        } else if self.is_dead_position() {
+            GameStatus::InsufficientMaterial
+        } else if in_check {
+            GameStatus::Check
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    // -- notation
+
+    /// ### to_san
+    ///
+    /// Get the SAN (standard algebraic notation) representation of the provided move (e.g. `Nbd7`, `exd5`, `e8=Q#`).
+    /// The move must be legal for the player moving in the current position.
+    /// If the move promotes a pawn, the promotion should be provided too.
+    /// Since resign is not a move in SAN, an empty string is returned for `Move::Resign`.
+    pub fn to_san(&self, m: Move, promotion: Option<Promotion>) -> String {
+        if m == Move::Resign {
+            return String::new();
+        }
+        let mut san: String = self.move_to_san(m, promotion);
+        // Check or checkmate suffix
+        let next_turn: Board = self.replay_move(m, promotion);
+        if next_turn.is_checkmate() {
+            san.push('#');
+        } else if next_turn.is_check() {
+            san.push('+');
+        }
+        san
+    }
+
+    /// ### parse_san
+    ///
+    /// Parse a move in SAN (e.g. `Nbd7`, `exd5`, `e8=Q#`) played by the current player, getting the move and
+    /// the eventual promotion.
+    /// The check and checkmate suffixes and the annotations (`!`, `?`) are optional and castles can be written with zeros (`0-0`).
+    /// Returns `MoveParseError::IllegalMove` if the move is not legal in the current position or it's ambiguous
+    pub fn parse_san(&self, san: &str) -> Result<(Move, Option<Promotion>), MoveParseError> {
+        let san: String = san
+            .trim()
+            .trim_end_matches(&['+', '#', '!', '?'][..])
+            .replace('0', "O");
+        if san.is_empty() || san.contains(char::is_whitespace) {
+            return Err(MoveParseError::BadFormat);
+        }
+        for m in self.get_legal_moves(self.get_turn()) {
+            let promotions: &[Option<Promotion>] = match self
+                .apply_move(m)
+                .check_available_pawn_promotion()
+                .promotion
+            {
+                Some(_) => &[
+                    Some(Promotion::Queen),
+                    Some(Promotion::Rook),
+                    Some(Promotion::Bishop),
+                    Some(Promotion::Knight),
+                ],
+                None => &[None],
+            };
+            if let Some(promotion) = promotions
+                .iter()
+                .find(|promotion| self.move_to_san(m, **promotion) == san)
+            {
+                return Ok((m, *promotion));
+            }
+        }
+        Err(MoveParseError::IllegalMove)
     }
 
     // -- evaluation
@@ -634,12 +1246,8 @@ impl Board {
     /// Remove all of the pieces for a given player
     pub fn remove_all(&self, color: Color) -> Self {
         let mut result = *self;
-        for square in &mut result.squares {
-            if let Some(piece) = square.get_piece() {
-                if piece.get_color() == color {
-                    *square = Square::empty()
-                }
-            }
+        for piece in self.get_player_pieces(color) {
+            result.take_piece(piece.get_pos());
         }
 
         result
@@ -651,7 +1259,7 @@ impl Board {
     /// Does nothing if square is empty
     pub fn remove_piece(&self, position: Position) -> Self {
         let mut result = *self;
-        *result.get_square(position) = Square::empty();
+        result.take_piece(position);
         result
     }
 
@@ -660,59 +1268,216 @@ impl Board {
     /// Convert all of a given players pieces to queens
     pub fn queen_all(&self, color: Color) -> Self {
         let mut result = *self;
-        for square in &mut result.squares {
-            if let Some(piece) = square.get_piece() {
-                if !piece.is_king() && piece.get_color() == color {
-                    *square = Square::from(Piece::Queen(color, piece.get_pos()))
-                }
+        for piece in self.get_player_pieces(color) {
+            if !piece.is_king() {
+                result.add_piece(Piece::Queen(color, piece.get_pos()));
             }
         }
 
         result
     }
 
-    /// ### set_turn
+    /// ### swap_colors
     ///
-    /// Make the game a certain player's turn
-    #[inline]
-    pub fn set_turn(&self, color: Color) -> Self {
+    /// Get a new board where every piece changes color, keeping its position.
+    /// Castling rights, check counters and reserves are swapped between the players and the turn is flipped;
+    /// the en passant square is cleared, since it can't be captured by the new player to move.
+    ///
+    /// The position is **not** checked, so the returned board may be illegal (e.g. pawns on their last rank)
+    pub fn swap_colors(&self) -> Self {
         let mut result = *self;
-        result.turn = color;
-        result
+        for piece in self
+            .get_player_pieces(WHITE)
+            .into_iter()
+            .chain(self.get_player_pieces(BLACK))
+        {
+            result.add_piece(piece.with_color(!piece.get_color()));
+        }
+        result.white_castling_rights = self.black_castling_rights;
+        result.black_castling_rights = self.white_castling_rights;
+        result.white_checks = self.black_checks;
+        result.black_checks = self.white_checks;
+        result.white_reserve = self.black_reserve;
+        result.black_reserve = self.white_reserve;
+        result.en_passant = None;
+        result.change_turn()
     }
 
-    /// ### change_turn
+    /// ### with_horde_rules
     ///
-    /// Change the current turn to the next player.
-    #[inline]
-    pub fn change_turn(mut self) -> Self {
-        self.turn = !self.turn;
-        self
+    /// Get a new board with the Horde rules enabled or disabled:
+    ///
+    /// - white pawns on the first rank can advance two squares (no en passant is possible after this move)
+    /// - black wins when white has no pieces left, instead of stalemate
+    ///
+    /// The rules are not encoded in FEN, so they must be enabled again on boards imported with `from_fen`
+    pub fn with_horde_rules(&self, enabled: bool) -> Self {
+        self.with_variant_flag(Rules::Horde, enabled)
     }
 
-    /// ### play_move
+    /// ### is_horde
     ///
-    /// Play a move and confirm it is legal.
-    /// Panics if a promotion must be performed first
-    pub fn play_move(&self, m: Move) -> MoveResult {
-        let current_color = self.get_turn();
-        // Panic if promotion is available
-        if let Some(pos) = self.promotion {
-            panic!(
-                "A promotion at '{}' must be performed before moving a piece",
-                pos
-            );
+    /// Returns whether the Horde rules are enabled
+    pub fn is_horde(&self) -> bool {
+        self.rules == Rules::Horde
+    }
+
+    /// ### is_horde_wiped_out
+    ///
+    /// Returns whether the Horde rules are enabled and white has no pieces left, which means black has won
+    pub fn is_horde_wiped_out(&self) -> bool {
+        self.is_horde() && self.get_player_pieces(WHITE).is_empty()
+    }
+
+    /// ### with_three_check_rules
+    ///
+    /// Get a new board with the Three-check rules enabled or disabled.
+    /// When enabled, the checks delivered by each player are counted and the player who delivers the third check wins.
+    /// A move giving a double check counts as a single check
+    pub fn with_three_check_rules(&self, enabled: bool) -> Self {
+        self.with_variant_flag(Rules::ThreeCheck, enabled)
+    }
+
+    /// ### is_three_check
+    ///
+    /// Returns whether the Three-check rules are enabled
+    pub fn is_three_check(&self) -> bool {
+        self.rules == Rules::ThreeCheck
+    }
+
+    /// ### get_checks
+    ///
+    /// Get the amount of checks delivered by `color`. Checks are counted only with the Three-check rules enabled
+    pub fn get_checks(&self, color: Color) -> u8 {
+        match color {
+            WHITE => self.white_checks,
+            BLACK => self.black_checks,
         }
-        // Make move
-        if m == Move::Resign {
-            MoveResult::Victory(!current_color)
-        } else if self.is_legal_move(m, current_color) {
-            // Apply move and change turn
-            let next_turn: Board = self.apply_move(m);
-            // If is checkmate, return victory
-            if next_turn.change_turn().is_checkmate() {
-                MoveResult::Victory(current_color)
-            } else if next_turn.change_turn().is_stalemate() {
+    }
+
+    /// ### with_checks
+    ///
+    /// Get a new board with the provided amount of checks delivered by `color`
+    pub fn with_checks(&self, color: Color, checks: u8) -> Self {
+        let mut result = *self;
+        match color {
+            WHITE => result.white_checks = checks,
+            BLACK => result.black_checks = checks,
+        }
+        result
+    }
+
+    /// ### has_three_checks
+    ///
+    /// Returns whether the Three-check rules are enabled and `color` has delivered three checks, which means it has won
+    pub fn has_three_checks(&self, color: Color) -> bool {
+        self.is_three_check() && self.get_checks(color) >= 3
+    }
+
+    /// ### count_check
+    ///
+    /// If the Three-check rules are enabled and `color` has just given check, increment its checks
+    fn count_check(&self, color: Color) -> Self {
+        match self.rules.count_checks() && self.is_in_check(!color) {
+            true => self.with_checks(color, self.get_checks(color).saturating_add(1)),
+            false => *self,
+        }
+    }
+
+    /// ### is_double_push_square
+    ///
+    /// Returns whether a pawn of `color` on `pos` can advance two squares
+    pub(crate) fn is_double_push_square(&self, pos: Position, color: Color) -> bool {
+        self.rules
+            .pawn_double_step_ranks(color)
+            .contains(&pos.get_row())
+    }
+
+    /// ### with_en_passant
+    ///
+    /// Get a new board with the provided en passant square, which is the square behind a pawn which has just
+    /// moved two squares. The square is not validated, so it's up to the caller to provide a consistent position
+    pub fn with_en_passant(&self, en_passant: Option<Position>) -> Self {
+        let mut result = *self;
+        result.en_passant = en_passant;
+        result
+    }
+
+    /// ### with_castling_rights
+    ///
+    /// Get a new board with the provided castling rights for `color`.
+    /// Castling still requires the king and the rook on their starting squares
+    pub fn with_castling_rights(&self, color: Color, rights: CastlingRights) -> Self {
+        let mut result = *self;
+        match color {
+            WHITE => result.white_castling_rights = rights,
+            BLACK => result.black_castling_rights = rights,
+        }
+        result
+    }
+
+    /// ### with_turn
+    ///
+    /// Get a new board where it's the provided player's turn
+    #[inline]
+    pub fn with_turn(&self, color: Color) -> Self {
+        let mut result = *self;
+        result.set_turn_mut(color);
+        result
+    }
+
+    /// ### set_turn_mut
+    ///
+    /// Make it the provided player's turn, changing the board in place
+    #[inline]
+    pub fn set_turn_mut(&mut self, color: Color) {
+        self.turn = color;
+    }
+
+    /// ### set_turn
+    ///
+    /// Make the game a certain player's turn
+    #[deprecated(since = "0.2.0", note = "use `with_turn` or `set_turn_mut` instead")]
+    #[inline]
+    pub fn set_turn(&self, color: Color) -> Self {
+        self.with_turn(color)
+    }
+
+    /// ### change_turn
+    ///
+    /// Change the current turn to the next player.
+    #[inline]
+    pub fn change_turn(mut self) -> Self {
+        self.turn = !self.turn;
+        self
+    }
+
+    /// ### play_move
+    ///
+    /// Play a move and confirm it is legal.
+    /// Panics if a promotion must be performed first
+    pub fn play_move(&self, m: Move) -> MoveResult {
+        let current_color = self.get_turn();
+        // Panic if promotion is available
+        if let Some(pos) = self.promotion {
+            panic!(
+                "A promotion at '{}' must be performed before moving a piece",
+                pos
+            );
+        }
+        // Make move
+        if m == Move::Resign {
+            MoveResult::Victory(!current_color)
+        } else if self.is_legal_move(m, current_color) && self.is_allowed_by_rules(m, current_color)
+        {
+            // Apply move and change turn
+            let next_turn: Board = self.apply_move(m).count_check(current_color);
+            // If is checkmate, return victory
+            if next_turn.change_turn().is_checkmate() {
+                MoveResult::Victory(current_color)
+            } else if let Some(winner) = self.rules.extra_victory(&next_turn, Some(m)) {
+                MoveResult::Victory(winner)
+            } else if next_turn.change_turn().is_stalemate() {
                 // Check stalemate
                 MoveResult::Stalemate
             } else {
@@ -721,7 +1486,7 @@ impl Board {
                 // If there's a promotion available, return `Promote`; otherwise return `Continuing` changing player's turn
                 match next_turn.promotion {
                     Some(pos_promotion) => MoveResult::Promote(next_turn, pos_promotion),
-                    None => MoveResult::Continuing(next_turn.change_turn()),
+                    None => MoveResult::Continuing(next_turn.end_turn()),
                 }
             }
         } else {
@@ -737,34 +1502,160 @@ impl Board {
     /// In this case the result will be `Continuing`, `Stalemate` or `Victory` in case the promotion
     /// causes a checkmate.
     pub fn promote(&self, promotion: Promotion) -> MoveResult {
+        if self.promotion.is_none() {
+            panic!("There's no promotion available");
+        }
+        let color: Color = self.get_turn();
+        // Promote pawn, change turn and return
+        let result = self.promote_pawn(promotion).end_turn();
+        if result.is_checkmate() {
+            MoveResult::Victory(color)
+        } else if let Some(winner) = self.rules.extra_victory(&result, None) {
+            MoveResult::Victory(winner)
+        } else if result.is_stalemate() {
+            // Check stalemate
+            MoveResult::Stalemate
+        } else {
+            MoveResult::Continuing(result)
+        }
+    }
+
+    /// ### make_unchecked
+    ///
+    /// Apply a move to the board, without checking whether the move is legal and **without** changing turn.
+    /// Pawns reaching the last rank are not promoted and the game result is not evaluated.
+    ///
+    /// This function doesn't enforce the rules of chess, so the returned board may be in a state which can't be
+    /// reached in a real game; it is meant for engine authors who need to explore hypothetical continuations.
+    /// Use `play_move` to play a move according to the rules.
+    ///
+    /// ```rust
+    /// use harmon::*;
+    ///
+    /// let board = Board::default();
+    /// // Explore 1. e4 e5 2. Qh5
+    /// let line = [Move::Piece(E2, E4), Move::Piece(E7, E5), Move::Piece(D1, H5)];
+    /// let mut position = board;
+    /// for m in line.iter() {
+    ///     position = position.make_unchecked(*m).change_turn();
+    /// }
+    /// assert_eq!(position.get_turn(), Color::Black);
+    /// assert!(position.get_piece(H5).is_some());
+    /// // The original board is left untouched
+    /// assert!(board.get_piece(H5).is_none());
+    /// ```
+    pub fn make_unchecked(&self, m: Move) -> Self {
+        self.apply_move(m)
+    }
+
+    // -- crate
+
+    /// ### apply_move
+    ///
+    /// Apply a move to the board and return a new Board with the move applied
+    pub(crate) fn apply_move(&self, m: Move) -> Self {
+        match m {
+            Move::KingSideCastle => self.apply_kingside_castle(),
+            Move::QueenSideCastle => self.apply_queenside_castle(),
+            Move::Piece(from, to) => self.apply_piece_move(from, to),
+            Move::Drop(kind, pos) => self.drop_piece(kind, pos, self.turn),
+            Move::Resign => *self, // Resign does nothing
+        }
+    }
+
+    /// ### promote_pawn
+    ///
+    /// Replace the promoting pawn with the provided promotion, without changing turn.
+    /// The check given by the promoted piece is counted, unless the pawn move already gave check.
+    /// If there's no pawn to promote, the board is returned unchanged
+    pub(crate) fn promote_pawn(&self, promotion: Promotion) -> Self {
         let mut result = *self;
-        match result.promotion.take() {
-            Some(pos) => {
-                let color: Color = result.get_turn();
-                let promotion: Piece = match promotion {
-                    Promotion::Bishop => Piece::Bishop(color, pos),
-                    Promotion::Knight => Piece::Knight(color, pos),
-                    Promotion::Queen => Piece::Queen(color, pos),
-                    Promotion::Rook => Piece::Rook(color, pos),
-                };
-                result.add_piece(promotion);
-                // Change turn and return
-                let result = result.change_turn();
-                if result.is_checkmate() {
-                    MoveResult::Victory(color)
-                } else if result.is_stalemate() {
-                    // Check stalemate
-                    MoveResult::Stalemate
-                } else {
-                    MoveResult::Continuing(result)
-                }
+        if let Some(pos) = result.promotion.take() {
+            let color: Color = result.get_turn();
+            result.add_piece(Piece::from_promotion(promotion, color, pos));
+            if result.is_crazyhouse() {
+                result = result.with_promoted(pos, true);
+            }
+            if !self.is_in_check(!color) {
+                result = result.count_check(color);
             }
-            None => panic!("There's no promotion available"),
         }
+        result
+    }
+
+    /// ### same_position
+    ///
+    /// Returns whether the two boards describe the same position for repetition purposes, which means same pieces
+    /// placement, same player to move, same castling rights and same en passant square (the latter only if the
+    /// en passant capture is actually possible, as stated by the FIDE laws).
+    /// Unlike `==`, what happened on the last turn (the taken piece and the pending promotion) is not compared
+    pub fn same_position(&self, other: &Board) -> bool {
+        self.position_key() == other.position_key()
+    }
+
+    /// ### capturable_en_passant
+    ///
+    /// Get the en passant square, only if the player to move can legally capture en passant
+    fn capturable_en_passant(&self) -> Option<Position> {
+        let color: Color = self.get_turn();
+        self.en_passant.filter(|ep| {
+            let row: i32 = match color {
+                WHITE => ep.get_row() - 1,
+                BLACK => ep.get_row() + 1,
+            };
+            [ep.get_col() - 1, ep.get_col() + 1]
+                .iter()
+                .map(|col| Position::new(row, *col))
+                .any(|from| {
+                    matches!(self.get_piece(from), Some(Piece::Pawn(c, _)) if c == color)
+                        && self.is_legal_move(Move::Piece(from, *ep), color)
+                })
+        })
+    }
+
+    /// ### replay_move
+    ///
+    /// Apply a move to the board, performing the provided promotion if any and changing turn.
+    /// This is meant to replay moves already validated, so legality is not checked
+    pub(crate) fn replay_move(&self, m: Move, promotion: Option<Promotion>) -> Self {
+        if m == Move::Resign {
+            return *self;
+        }
+        let color: Color = self.get_turn();
+        let result: Board = self.apply_move(m).count_check(color);
+        match promotion {
+            Some(promotion) => result
+                .check_available_pawn_promotion()
+                .promote_pawn(promotion),
+            None => result,
+        }
+        .end_turn()
     }
 
     // -- private
 
+    /// ### is_allowed_by_rules
+    ///
+    /// Returns whether the move is not forbidden by the variant rules
+    fn is_allowed_by_rules(&self, m: Move, color: Color) -> bool {
+        let mut moves: Vec<Move> = vec![m];
+        self.rules.filter_legal_moves(self, color, &mut moves);
+        moves.contains(&m)
+    }
+
+    /// ### end_turn
+    ///
+    /// Pass the turn to the next player, unless the player to move still has moves granted by the odds
+    fn end_turn(mut self) -> Self {
+        match self.odds_moves {
+            0 => self.change_turn(),
+            _ => {
+                self.odds_moves -= 1;
+                self
+            }
+        }
+    }
+
     /// ### is_in_check
     ///
     /// Get whether or not the king of a given color is in check.
@@ -777,13 +1668,41 @@ impl Board {
         }
     }
 
-    /// ### get_square
+    /// ### square_index
     ///
-    /// Get a mutable reference to the square with the provided position.
+    /// Get the index of the square at `pos` in `squares`, where squares are stored from `a8` to `h1`
+    #[inline]
+    const fn square_index(pos: Position) -> usize {
+        ((7 - pos.get_row()) * 8 + pos.get_col()) as usize
+    }
+
+    /// ### put_piece
+    ///
+    /// Put `piece` at `pos`, updating the material totals, and return the piece it replaced.
+    /// The piece is moved to `pos`, so its position always agrees with its square.
     /// Panics if position is off_board
     #[inline]
-    fn get_square(&mut self, pos: Position) -> &mut Square {
-        &mut self.squares[((7 - pos.get_row()) * 8 + pos.get_col()) as usize]
+    fn put_piece(&mut self, pos: Position, piece: Piece) -> Option<Piece> {
+        let piece: Piece = piece.move_to(pos);
+        let replaced: Option<Piece> = self.squares[Self::square_index(pos)].replace(piece);
+        if let Some(replaced) = replaced {
+            self.material.remove(replaced);
+        }
+        self.material.add(piece);
+        replaced
+    }
+
+    /// ### take_piece
+    ///
+    /// Take the piece at `pos` out of the board, updating the material totals.
+    /// Panics if position is off_board
+    #[inline]
+    fn take_piece(&mut self, pos: Position) -> Option<Piece> {
+        let taken: Option<Piece> = self.squares[Self::square_index(pos)].take();
+        if let Some(taken) = taken {
+            self.material.remove(taken);
+        }
+        taken
     }
 
     /// ### add_piece
@@ -791,8 +1710,7 @@ impl Board {
     /// Add piece to board
     #[inline]
     fn add_piece(&mut self, piece: Piece) {
-        let pos = piece.get_pos();
-        *self.get_square(pos) = Square::from(piece);
+        self.put_piece(piece.get_pos(), piece);
     }
 
     /// ### move_piece
@@ -808,10 +1726,7 @@ impl Board {
             return result;
         }
 
-        let from_square = result.get_square(from);
-        if let Some(piece) = from_square.get_piece() {
-            *from_square = Square::empty();
-
+        if let Some(piece) = result.take_piece(from) {
             // Check en passant
             if piece.is_starting_pawn() && (from.get_row() - to.get_row()).abs() == 2 {
                 result.en_passant = Some(to.pawn_back(piece.get_color()))
@@ -820,9 +1735,16 @@ impl Board {
             // Check if there is an enemy piece at `to`
             if result.has_enemy_piece(to, result.get_turn()) {
                 result.taken_piece = result.get_piece(to);
+                if let (true, Some(taken)) = (result.is_crazyhouse(), result.taken_piece) {
+                    result.capture_to_reserve(taken, to);
+                }
+            }
+            // Promoted pieces are tracked while they move
+            if result.is_promoted(from) {
+                result = result.with_promoted(from, false).with_promoted(to, true);
             }
 
-            result.add_piece(piece.move_to(to));
+            result.put_piece(to, piece);
 
             let castling_rights = match piece.get_color() {
                 WHITE => &mut result.white_castling_rights,
@@ -841,18 +1763,6 @@ impl Board {
         result
     }
 
-    /// ### apply_move
-    ///
-    /// Apply a move to the board and return a new Board with the move applied
-    fn apply_move(&self, m: Move) -> Self {
-        match m {
-            Move::KingSideCastle => self.apply_kingside_castle(),
-            Move::QueenSideCastle => self.apply_queenside_castle(),
-            Move::Piece(from, to) => self.apply_piece_move(from, to),
-            Move::Resign => *self, // Resign does nothing
-        }
-    }
-
     /// ### apply_kingside_castle
     ///
     /// Apply kingside castle to board
@@ -900,32 +1810,140 @@ impl Board {
                 || en_passant == from.pawn_up(player_color).next_right())
                 && en_passant == to
             {
-                result.squares[((7 - en_passant.pawn_back(player_color).get_row()) * 8
-                    + en_passant.get_col()) as usize] = Square::empty();
+                result.taken_piece = self.get_piece(en_passant.pawn_back(player_color));
+                result.take_piece(en_passant.pawn_back(player_color));
+                if let (true, Some(taken)) = (result.is_crazyhouse(), result.taken_piece) {
+                    result.capture_to_reserve(taken, en_passant.pawn_back(player_color));
+                }
             }
         }
 
         result
     }
 
+    /// ### move_to_san
+    ///
+    /// Get SAN representation for a move, without check suffix
+    fn move_to_san(&self, m: Move, promotion: Option<Promotion>) -> String {
+        match m {
+            Move::KingSideCastle => String::from("O-O"),
+            Move::QueenSideCastle => String::from("O-O-O"),
+            Move::Resign => String::new(),
+            Move::Piece(from, to) => self.piece_move_to_san(from, to, promotion),
+            Move::Drop(kind, to) => format!("{}@{}", kind.to_ascii_char(), to),
+        }
+    }
+
+    /// ### piece_move_to_san
+    ///
+    /// Get SAN representation for a piece move, without check suffix
+    fn piece_move_to_san(
+        &self,
+        from: Position,
+        to: Position,
+        promotion: Option<Promotion>,
+    ) -> String {
+        let mut san: String = String::new();
+        let file = |pos: Position| (b'a' + pos.get_col() as u8) as char;
+        let rank = |pos: Position| (b'1' + pos.get_row() as u8) as char;
+        match self.get_piece(from) {
+            Some(Piece::Pawn(_, _)) => {
+                // Pawn captures are specified by the starting file
+                if from.get_col() != to.get_col() {
+                    san.push(file(from));
+                    san.push('x');
+                }
+                san.push_str(&to.to_string());
+                if let Some(promotion) = promotion {
+                    san.push('=');
+                    san.push(PieceKind::from(promotion).to_ascii_char());
+                }
+            }
+            Some(piece) => {
+                san.push(piece.with_color(WHITE).to_ascii_char());
+                // Get pieces of the same kind which could move to the same square
+                let ambiguous: Vec<Position> = self
+                    .get_legal_moves(piece.get_color())
+                    .into_iter()
+                    .filter_map(|x| match x {
+                        Move::Piece(other, dest) if dest == to && other != from => Some(other),
+                        _ => None,
+                    })
+                    .filter(|x| self.get_piece(*x).map(|x| x.get_name()) == Some(piece.get_name()))
+                    .collect();
+                if !ambiguous.is_empty() {
+                    if ambiguous.iter().all(|x| x.get_col() != from.get_col()) {
+                        san.push(file(from));
+                    } else if ambiguous.iter().all(|x| x.get_row() != from.get_row()) {
+                        san.push(rank(from));
+                    } else {
+                        san.push(file(from));
+                        san.push(rank(from));
+                    }
+                }
+                if self.has_piece(to) {
+                    san.push('x');
+                }
+                san.push_str(&to.to_string());
+            }
+            None => {
+                san.push_str(&from.to_string());
+                san.push_str(&to.to_string());
+            }
+        }
+        san
+    }
+
     /// ### check_available_pawn_promotion
     ///
     /// Check whether there is a pawn promotion available
     fn check_available_pawn_promotion(mut self) -> Self {
-        let mut promoting_pawn: Option<Position> = None;
         // Search for a pawn which can be promoted
-        for square in self.squares.iter() {
-            if let Some(piece) = square.get_piece() {
-                if piece.is_promoting_pawn() && piece.get_color() == self.get_turn() {
-                    promoting_pawn = Some(piece.get_pos());
-                }
-            }
-        }
+        let turn: Color = self.get_turn();
+        let promoting_pawn: Option<Position> = self
+            .pieces()
+            .find(|piece| piece.is_promoting_pawn() && piece.get_color() == turn)
+            .map(|piece| piece.get_pos());
         // Set promotion
         self.promotion = promoting_pawn;
         self
     }
 
+    /// ### evaluate
+    ///
+    /// Static evaluation of the board for the provided player, used on the leaves of the search.
+    /// This is the player value plus the mop-up term for both the players
+    fn evaluate(&self, color: Color) -> f64 {
+        self.get_player_value(color) + self.mop_up(color) - self.mop_up(!color)
+            + self.reserve_value(color)
+            - self.reserve_value(!color)
+    }
+
+    /// ### mop_up
+    ///
+    /// Mop-up evaluation of the board for the provided player.
+    /// This term is only applied in the endgame, when the opponent has only the king left
+    /// and the player has at least a rook or a queen: it rewards pushing the opponent's king towards the edge
+    /// of the board and bringing the player's king closer to it, which is required to deliver checkmate.
+    /// Otherwise `0.0` is returned
+    fn mop_up(&self, color: Color) -> f64 {
+        let (king, enemy_king) = match (self.get_king_pos(color), self.get_king_pos(!color)) {
+            (Some(king), Some(enemy_king)) => (king, enemy_king),
+            _ => return 0.0,
+        };
+        let lone_king: bool = self.get_player_pieces(!color).len() == 1;
+        let mating_material: bool = self
+            .get_player_pieces(color)
+            .iter()
+            .any(|x| x.is_queen() || x.is_rook());
+        if !lone_king || !mating_material {
+            return 0.0;
+        }
+        let edge: f64 = (3 - enemy_king.distance_to_edge()) as f64;
+        let closeness: f64 = (7 - king.chebyshev_distance(enemy_king)) as f64;
+        edge * 10.0 + closeness * 6.0
+    }
+
     /// ### minimax
     ///
     /// Perform minimax on a certain position, and get the minimum or maximum value
@@ -944,7 +1962,7 @@ impl Board {
         getting_move_for: Color,
     ) -> f64 {
         if depth == 0 {
-            return self.get_player_value(getting_move_for);
+            return self.evaluate(getting_move_for);
         }
 
         let legal_moves = self.get_legal_moves(self.get_turn());
@@ -1005,98 +2023,14 @@ impl Board {
 
 // -- board fmt
 
+// Board display is rendered using unicode characters and ANSI colors (see `Board::display` to configure the rendering).
+// Use the alternate flag (`{:#}`) to render the board using plain ASCII characters only.
 impl core::fmt::Display for Board {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
-        // Make progress bar
-        let (white_score, black_score): (f64, f64) = self.get_rating(2);
-        let (your_color, their_color) = match self.turn {
-            WHITE => ("▓", "░"),
-            BLACK => ("░", "▓"),
-        };
-        let (your_score, their_score): (f64, f64) = match self.turn {
-            WHITE => (white_score, black_score),
-            BLACK => (black_score, white_score),
-        };
-
-        let white = match self.turn {
-            WHITE => your_color.repeat((your_score * 0.16_f64) as usize),
-            BLACK => their_color.repeat((their_score * 0.16_f64) as usize),
-        };
-
-        let black = match self.turn {
-            BLACK => your_color.repeat((your_score * 0.16_f64) as usize),
-            WHITE => their_color.repeat((their_score * 0.16_f64) as usize),
-        };
-        let rating_bar = white + &black;
-        // Prepare labels
-        let abc = if self.turn == WHITE {
-            "abcdefgh"
-        } else {
-            "hgfedcba"
-        };
-        // Write board
-        write!(f, "   {}\n  ╔════════╗", abc)?;
-        let mut square_color = !self.turn;
-        let height = 8;
-        let width = 8;
-
-        for row in 0..height {
-            writeln!(f)?;
-
-            let print_row = match self.turn {
-                WHITE => height - row - 1,
-                BLACK => row,
-            };
-            write!(f, "{} ║", print_row + 1)?;
-
-            for col in 0..width {
-                let print_col = match self.turn {
-                    BLACK => width - col - 1,
-                    WHITE => col,
-                };
-
-                let pos = Position::new(print_row, print_col);
-
-                let s = if let Some(piece) = self.get_piece(pos) {
-                    piece.to_string()
-                } else {
-                    String::from(match square_color {
-                        WHITE => "░",
-                        BLACK => "▓",
-                    })
-                };
-                if Some(pos) == self.en_passant {
-                    write!(f, "\x1b[34m{}\x1b[m\x1b[0m", s)?;
-                } else if self.is_threatened(pos, self.turn) {
-                    write!(f, "\x1b[31m{}\x1b[m\x1b[0m", s)?;
-                } else if self.is_threatened(pos, !self.turn) {
-                    write!(f, "\x1b[32m{}\x1b[m\x1b[0m", s)?;
-                } else {
-                    write!(f, "{}", s)?;
-                }
-
-                square_color = !square_color;
-            }
-            write!(f, "║")?;
-
-            if row == 2 {
-                let white_adv = self.get_material_advantage(WHITE);
-                let black_adv = self.get_material_advantage(BLACK);
-
-                match white_adv.cmp(&black_adv) {
-                    Ordering::Equal => write!(f, " Both sides have equal material")?,
-                    Ordering::Greater => write!(f, " White +{} points", white_adv)?,
-                    Ordering::Less => write!(f, " Black +{} points", black_adv)?,
-                }
-            } else if row == 3 {
-                write!(f, " {} to move", self.turn)?;
-            } else if row == 4 {
-                write!(f, " [{}]", rating_bar)?;
-            }
-            square_color = !square_color;
+        match f.alternate() {
+            true => write!(f, "{}", self.display().ascii(true).ansi(false)),
+            false => write!(f, "{}", self.display()),
         }
-
-        write!(f, "\n  ╚════════╝\n   {}\n", abc)
     }
 }
 
@@ -1170,6 +2104,141 @@ mod test {
         assert_eq!(board.turn, WHITE);
     }
 
+    #[test]
+    fn horde_rules() {
+        assert_eq!(Board::horde().is_horde(), true);
+        assert_eq!(Board::default().is_horde(), false);
+        // First rank pawns can advance two squares
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, A1))
+            .piece(Piece::Pawn(WHITE, H2))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert!(!board.get_legal_moves(WHITE).contains(&Move::Piece(A1, A3)));
+        let board: Board = board.with_horde_rules(true);
+        let moves: Vec<Move> = board.get_legal_moves(WHITE);
+        assert!(moves.contains(&Move::Piece(A1, A3)));
+        assert!(moves.contains(&Move::Piece(A1, A2)));
+        assert!(moves.contains(&Move::Piece(H2, H4)));
+        assert_eq!(board.apply_move(Move::Piece(A1, A3)).get_en_passant(), None);
+        // Black can't advance two squares from the eighth rank
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::Pawn(BLACK, A8))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Pawn(WHITE, H2))
+            .player_moving(BLACK)
+            .build()
+            .with_horde_rules(true);
+        assert!(!board.get_legal_moves(BLACK).contains(&Move::Piece(A8, A6)));
+    }
+
+    #[test]
+    fn horde_wiped_out() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Rook(BLACK, A8))
+            .piece(Piece::Pawn(WHITE, A4))
+            .player_moving(BLACK)
+            .build();
+        // Without Horde rules, it's a stalemate
+        assert_eq!(board.play_move(Move::Piece(A8, A4)), MoveResult::Stalemate);
+        let board: Board = board.with_horde_rules(true);
+        assert_eq!(board.is_horde_wiped_out(), false);
+        assert_eq!(
+            board.play_move(Move::Piece(A8, A4)),
+            MoveResult::Victory(BLACK)
+        );
+        let board: Board = board.apply_move(Move::Piece(A8, A4)).change_turn();
+        assert_eq!(board.is_horde_wiped_out(), true);
+        assert_eq!(board.is_stalemate(), false);
+    }
+
+    #[test]
+    fn swap_colors() {
+        let board: Board = Board::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w Q - 0 1")
+            .ok()
+            .unwrap()
+            .with_checks(WHITE, 2);
+        let swapped: Board = board.swap_colors();
+        assert_eq!(swapped.get_turn(), BLACK);
+        assert_eq!(swapped.get_piece(A1), Some(Piece::Rook(BLACK, A1)));
+        assert_eq!(swapped.get_piece(G1), Some(Piece::King(BLACK, G1)));
+        assert_eq!(swapped.get_piece(F2), Some(Piece::Pawn(BLACK, F2)));
+        assert_eq!(swapped.get_piece(G8), Some(Piece::King(WHITE, G8)));
+        assert_eq!(swapped.get_piece(H7), Some(Piece::Pawn(WHITE, H7)));
+        assert_eq!(swapped.get_piece(E4), None);
+        assert_eq!(swapped.get_player_pieces(WHITE).len(), 4);
+        assert_eq!(swapped.get_player_pieces(BLACK).len(), 5);
+        assert_eq!(swapped.get_material_advantage(BLACK), 5);
+        assert_eq!(
+            swapped.get_castling_rights(BLACK).can_queenside_castle(),
+            true
+        );
+        assert_eq!(
+            swapped.get_castling_rights(WHITE).can_queenside_castle(),
+            false
+        );
+        assert_eq!(swapped.get_checks(BLACK), 2);
+        assert_eq!(swapped.get_checks(WHITE), 0);
+        // Swapping twice gives back the same board
+        assert_eq!(swapped.swap_colors(), board);
+    }
+
+    #[test]
+    fn three_check_rules() {
+        assert_eq!(Board::three_check().is_three_check(), true);
+        assert_eq!(Board::default().is_three_check(), false);
+        // Checks are not counted without Three-check rules
+        let board: Board = Board::default()
+            .play_uci_moves(&["e2e4", "d7d6", "f1b5"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.get_checks(WHITE), 0);
+        let board: Board = Board::three_check()
+            .play_uci_moves(&["e2e4", "d7d6", "f1b5", "c7c6", "b5c6", "b8c6"])
+            .ok()
+            .unwrap();
+        assert_eq!(board.get_checks(WHITE), 2);
+        assert_eq!(board.get_checks(BLACK), 0);
+        assert_eq!(board.has_three_checks(WHITE), false);
+        // Third check wins, even though it's not a checkmate
+        let board: Board = match board.play_move(Move::Piece(D1, H5)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        let board: Board = match board.play_move(Move::Piece(E7, E6)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        assert_eq!(
+            board.play_move(Move::Piece(H5, F7)),
+            MoveResult::Victory(WHITE)
+        );
+        let board: Board = board.replay_move(Move::Piece(H5, F7), None);
+        assert_eq!(board.has_three_checks(WHITE), true);
+        assert_eq!(board.is_checkmate(), false);
+        // Double check given by a promotion counts once
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(BLACK, A8))
+            .piece(Piece::Bishop(WHITE, H1))
+            .piece(Piece::Pawn(WHITE, B7))
+            .piece(Piece::King(WHITE, E1))
+            .build()
+            .with_three_check_rules(true);
+        let board: Board = match board.play_move(Move::Piece(B7, B8)) {
+            MoveResult::Promote(board, _) => board,
+            _ => panic!("Unexpected move result"),
+        };
+        assert_eq!(board.get_checks(WHITE), 1);
+        match board.promote(Promotion::Queen) {
+            MoveResult::Continuing(board) => assert_eq!(board.get_checks(WHITE), 1),
+            _ => panic!("Unexpected move result"),
+        }
+        let board: Board = board.remove_piece(H1).with_checks(WHITE, 2);
+        assert_eq!(board.get_checks(WHITE), 2);
+        assert_eq!(board.promote(Promotion::Queen), MoveResult::Victory(WHITE));
+    }
+
     #[test]
     fn horde() {
         let board: Board = Board::horde();
@@ -1237,6 +2306,35 @@ mod test {
         // Turn
         assert_eq!(board.turn, WHITE);
     }
+    #[test]
+    fn no_castling() {
+        let board: Board = Board::no_castling();
+        assert_eq!(
+            board.get_castling_rights(WHITE).can_kingside_castle(),
+            false
+        );
+        assert_eq!(
+            board.get_castling_rights(WHITE).can_queenside_castle(),
+            false
+        );
+        assert_eq!(
+            board.get_castling_rights(BLACK).can_kingside_castle(),
+            false
+        );
+        assert_eq!(
+            board.get_castling_rights(BLACK).can_queenside_castle(),
+            false
+        );
+        assert_eq!(
+            board.get_player_pieces(WHITE),
+            Board::default().get_player_pieces(WHITE)
+        );
+        assert_eq!(
+            board.to_fen().as_str(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1"
+        );
+    }
+
     #[test]
     fn dunsany() {
         let board: Board = Board::dunsany();
@@ -1342,23 +2440,443 @@ mod test {
     }
 
     #[test]
-    fn get_player_pieces() {
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let hash = |board: &Board| {
+            let mut hasher = DefaultHasher::new();
+            board.hash(&mut hasher);
+            hasher.finish()
+        };
+        // Equal boards hash equal
+        let board: Board = Board::default().apply_move(Move::Piece(E2, E4));
+        let other: Board =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e3")
+                .ok()
+                .unwrap();
+        assert_eq!(board, other);
+        assert_eq!(hash(&board), hash(&other));
+        // The piece taken on the last turn is part of the board state
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, A1))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Knight(BLACK, A8))
+            .build()
+            .apply_move(Move::Piece(A1, A8));
+        let other: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, A8))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(board.get_taken_piece(), Some(Piece::Knight(BLACK, A8)));
+        assert_eq!(board.position_key(), other.position_key());
+        assert_ne!(board, other);
+        assert_ne!(hash(&board), hash(&other));
+    }
+
+    #[test]
+    fn same_position() {
+        // 1. e4 d5 2. exd5 Qxd5 3. Nc3 Qa5
+        let mut board: Board = Board::default();
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(D8, D5),
+        ] {
+            board = board.apply_move(m).change_turn();
+        }
+        assert_eq!(board.get_taken_piece(), Some(Piece::Pawn(WHITE, D5)));
+        let other: Board =
+            Board::from_fen("rnb1kbnr/ppp1pppp/8/3q4/8/8/PPPP1PPP/RNBQKBNR w KQkq - 0 3")
+                .ok()
+                .unwrap();
+        assert_eq!(other.get_taken_piece(), None);
+        assert_eq!(board.same_position(&other), true);
+        assert_ne!(board, other);
+        // En passant square counts only when the capture is possible
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .change_turn();
+        let other: Board =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -")
+                .ok()
+                .unwrap();
+        assert_eq!(board.get_en_passant(), Some(E3));
+        assert_eq!(board.same_position(&other), true);
+        assert_ne!(board, other);
+        let board: Board =
+            Board::from_fen("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3")
+                .ok()
+                .unwrap();
+        assert_eq!(board.same_position(&board.with_en_passant(None)), false);
+        // En passant capture would expose the king to the rook
+        let board: Board = Board::from_fen("8/8/8/8/k2pP2R/8/8/4K3 b - e3")
+            .ok()
+            .unwrap();
+        assert_eq!(board.same_position(&board.with_en_passant(None)), true);
+        assert_eq!(board.same_position(&board.change_turn()), false);
+    }
+
+    #[test]
+    fn position_key() {
+        use std::collections::HashSet;
+        let mut keys: HashSet<PositionKey> = HashSet::new();
+        // Same position reached playing knights back and forth
+        let mut board: Board = Board::default();
+        keys.insert(board.position_key());
+        for m in [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ] {
+            board = board.apply_move(m).change_turn();
+        }
+        keys.insert(board.position_key());
+        assert_eq!(keys.len(), 1);
+        // Taken piece doesn't change position
+        let mut other: Board = board;
+        other.taken_piece = Some(Piece::Pawn(WHITE, A2));
+        assert_ne!(board, other);
+        keys.insert(other.position_key());
+        assert_eq!(keys.len(), 1);
+        // Different turn
+        keys.insert(board.change_turn().position_key());
+        assert_eq!(keys.len(), 2);
+        // Different castling rights
+        let mut other: Board = board;
+        other.white_castling_rights.disable_all();
+        keys.insert(other.position_key());
+        assert_eq!(keys.len(), 3);
+    }
+
+    #[test]
+    fn get_player_pieces() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::Queen(WHITE, D1))
+            .piece(Piece::Bishop(WHITE, D3))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(
+            board.get_player_pieces(WHITE),
+            vec![Piece::Bishop(WHITE, D3), Piece::Queen(WHITE, D1)]
+        );
+    }
+
+    #[test]
+    fn bishops_on_color() {
+        let board: Board = Board::default();
+        assert_eq!(board.bishops_on_color(WHITE, true), 1);
+        assert_eq!(board.bishops_on_color(WHITE, false), 1);
+        assert_eq!(board.bishops_on_color(BLACK, true), 1);
+        assert_eq!(board.bishops_on_color(BLACK, false), 1);
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Bishop(WHITE, C1))
+            .piece(Piece::Bishop(WHITE, E3))
+            .piece(Piece::Bishop(WHITE, B1))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(board.bishops_on_color(WHITE, false), 2);
+        assert_eq!(board.bishops_on_color(WHITE, true), 1);
+        assert_eq!(board.bishops_on_color(BLACK, true), 0);
+        assert_eq!(board.bishops_on_color(BLACK, false), 0);
+    }
+
+    #[test]
+    fn get_king_position() {
+        let board: Board = Board::horde();
+        assert_eq!(board.get_king_pos(BLACK).unwrap(), E8);
+        assert_eq!(board.get_king_pos(WHITE), None);
+    }
+
+    #[test]
+    fn passed_pawns() {
+        assert_eq!(Board::default().passed_pawns(WHITE), vec![]);
+        assert_eq!(Board::default().is_passed_pawn(E2), false);
+        // The a-pawn is passed, the d-pawn is contested by the e-pawn and the h-pawn is blocked
+        let board: Board = Board::from_fen("4k3/4p2p/8/8/3P4/8/P6P/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.is_passed_pawn(A2), true);
+        assert_eq!(board.is_passed_pawn(D4), false);
+        assert_eq!(board.is_passed_pawn(H2), false);
+        assert_eq!(board.passed_pawns(WHITE), vec![A2]);
+        assert_eq!(board.is_passed_pawn(E7), false);
+        // Pawns which have passed each other are both passed
+        let board: Board = Board::from_fen("4k3/7p/8/3P4/4p3/8/P6P/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.passed_pawns(BLACK), vec![E4]);
+        assert_eq!(board.passed_pawns(WHITE), vec![A2, D5]);
+        // Not a pawn
+        assert_eq!(board.is_passed_pawn(E1), false);
+        assert_eq!(board.is_passed_pawn(C3), false);
+    }
+
+    #[test]
+    fn all_kings() {
+        assert_eq!(Board::default().all_kings(), vec![(WHITE, E1), (BLACK, E8)]);
+        assert_eq!(Board::horde().all_kings(), vec![(BLACK, E8)]);
+        assert_eq!(BoardBuilder::default().build().all_kings(), vec![]);
+    }
+
+    /// Count the leaf nodes of the move generation tree at `depth`
+    fn perft(board: &Board, depth: usize) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+        board
+            .get_legal_moves(board.get_turn())
+            .into_iter()
+            .map(|m| {
+                match board
+                    .apply_move(m)
+                    .check_available_pawn_promotion()
+                    .promotion
+                    .is_some()
+                {
+                    true => [
+                        Promotion::Queen,
+                        Promotion::Rook,
+                        Promotion::Bishop,
+                        Promotion::Knight,
+                    ]
+                    .iter()
+                    .map(|p| perft(&board.replay_move(m, Some(*p)), depth - 1))
+                    .sum(),
+                    false => perft(&board.replay_move(m, None), depth - 1),
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn perft_move_generation() {
+        assert_eq!(perft(&Board::default(), 1), 20);
+        assert_eq!(perft(&Board::default(), 2), 400);
+        assert_eq!(perft(&Board::default(), 3), 8902);
+        // Castles, en passant and pins
+        let board: Board =
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+                .ok()
+                .unwrap();
+        assert_eq!(perft(&board, 1), 48);
+        assert_eq!(perft(&board, 2), 2039);
+        // Promotions
+        let board: Board = Board::from_fen("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - -")
+            .ok()
+            .unwrap();
+        assert_eq!(perft(&board, 1), 24);
+        assert_eq!(perft(&board, 2), 496);
+    }
+
+    /// Check `move_gives_check` against the check after applying each legal move, up to `depth`
+    fn assert_move_gives_check(board: &Board, depth: usize) {
+        if depth == 0 {
+            return;
+        }
+        let mut moves: Vec<Move> = board.get_legal_moves(board.get_turn());
+        moves.push(Move::Resign);
+        for m in moves {
+            assert_eq!(
+                board.move_gives_check(m),
+                board.apply_move(m).is_in_check(!board.get_turn()),
+                "{} {:?}",
+                board.to_fen(),
+                m
+            );
+            if m != Move::Resign {
+                let next: Board = board.replay_move(m, Some(Promotion::Queen));
+                assert_move_gives_check(&next, depth - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn move_gives_check() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        ]
+        .iter()
+        {
+            assert_move_gives_check(&Board::from_fen(fen).unwrap(), 3);
+        }
+        // Castling
+        let board: Board = Board::from_fen("5k2/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+        assert_eq!(board.move_gives_check(Move::KingSideCastle), true);
+        assert_eq!(board.move_gives_check(Move::Piece(H1, H8)), true);
+        assert_eq!(board.move_gives_check(Move::Piece(H1, G1)), false);
+        // Discovered check by en passant
+        let board: Board = Board::from_fen("8/8/8/1k1pP2R/8/8/8/4K3 w - d6 0 1").unwrap();
+        assert_eq!(board.move_gives_check(Move::Piece(E5, D6)), true);
+        assert_eq!(board.move_gives_check(Move::Piece(E5, E6)), false);
+        // Drops
+        let board: Board = Board::from_fen("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1")
+            .unwrap()
+            .with_rules(Rules::Crazyhouse);
+        assert_eq!(
+            board.move_gives_check(Move::Drop(PieceKind::Knight, D6)),
+            true
+        );
+        assert_eq!(
+            board.move_gives_check(Move::Drop(PieceKind::Knight, D5)),
+            false
+        );
+    }
+
+    #[test]
+    fn get_piece_attacks() {
+        // The rook is pinned, but it still attacks the squares on its rank and file
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, E2))
+            .piece(Piece::Pawn(WHITE, D4))
+            .piece(Piece::Rook(BLACK, E8))
+            .piece(Piece::King(BLACK, A8))
+            .build();
+        assert_eq!(
+            board.get_legal_moves(WHITE).contains(&Move::Piece(E2, A2)),
+            false
+        );
+        assert_eq!(
+            board.get_piece_attacks(E2),
+            vec![A2, B2, C2, D2, F2, G2, H2, E3, E4, E5, E6, E7, E8]
+        );
+        // The pawn attacks the empty diagonals
+        assert_eq!(board.get_piece_attacks(D4), vec![C5, E5]);
+        assert_eq!(board.get_piece_attacks(E5), vec![]);
+        // The king attacks the adjacent squares only, not the castling squares
+        let board: Board = Board::default();
+        assert_eq!(board.get_piece_attacks(E1), vec![]);
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, H1))
+            .piece(Piece::King(BLACK, E8))
+            .enable_castling()
+            .build();
+        assert_eq!(board.get_piece_attacks(E1), vec![D1, F1, D2, E2, F2]);
+        assert_eq!(board.get_piece_attacks(E1).contains(&G1), false);
+    }
+
+    #[test]
+    fn attacked_squares() {
+        let board: Board = Board::default();
+        // Third rank, attacked by pawns and knights
+        assert_eq!(
+            board.attacked_squares(WHITE),
+            vec![A3, B3, C3, D3, E3, F3, G3, H3]
+        );
+        assert_eq!(
+            board.attacked_squares(BLACK),
+            vec![A6, B6, C6, D6, E6, F6, G6, H6]
+        );
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Pawn(WHITE, B3))
+            .piece(Piece::King(BLACK, H8))
+            .build();
+        // A2 and B2 are attacked by the king only, A4 and C4 by the pawn only, B3 is occupied by the pawn
+        assert_eq!(board.attacked_squares(WHITE), vec![B1, A2, B2, A4, C4]);
+    }
+
+    #[test]
+    fn parse_san() {
+        let board: Board = Board::default();
+        assert_eq!(board.parse_san("e4"), Ok((Move::Piece(E2, E4), None)));
+        assert_eq!(board.parse_san(" Nf3!? "), Ok((Move::Piece(G1, F3), None)));
+        assert_eq!(board.parse_san("e5"), Err(MoveParseError::IllegalMove));
+        assert_eq!(board.parse_san("Ke2"), Err(MoveParseError::IllegalMove));
+        assert_eq!(board.parse_san(""), Err(MoveParseError::BadFormat));
+        assert_eq!(board.parse_san("e2 e4"), Err(MoveParseError::BadFormat));
+        // Castles, disambiguation and promotions
+        let board: Board = Board::from_fen("r3k2r/1P6/8/8/8/8/8/R3K1NR w KQkq - 0 1").unwrap();
+        assert_eq!(board.parse_san("O-O-O"), Ok((Move::QueenSideCastle, None)));
+        assert_eq!(board.parse_san("0-0-0+"), Ok((Move::QueenSideCastle, None)));
+        assert_eq!(
+            board.parse_san("bxa8=N"),
+            Ok((Move::Piece(B7, A8), Some(Promotion::Knight)))
+        );
+        assert_eq!(
+            board.parse_san("b8=Q+"),
+            Ok((Move::Piece(B7, B8), Some(Promotion::Queen)))
+        );
+        assert_eq!(board.parse_san("b8"), Err(MoveParseError::IllegalMove));
+        // Every legal move is parsed back from its SAN
+        for m in board.get_legal_moves(WHITE) {
+            let promotion: Option<Promotion> = match m {
+                Move::Piece(B7, _) => Some(Promotion::Rook),
+                _ => None,
+            };
+            assert_eq!(
+                board.parse_san(&board.to_san(m, promotion)),
+                Ok((m, promotion))
+            );
+        }
+    }
+
+    #[test]
+    fn piece_positions_agree_with_squares() {
+        let agree = |board: &Board| -> bool {
+            board
+                .iter()
+                .all(|(pos, piece)| piece.map(|x| x.get_pos() == pos).unwrap_or(true))
+        };
+        // Castles, en passant, captures and promotions
+        let mut board: Board =
+            Board::from_fen("r3k2r/1P6/8/3pP3/8/8/8/R3K2R w KQkq d6 0 1").unwrap();
+        for (m, promotion) in [
+            (Move::Piece(E5, D6), None),
+            (Move::KingSideCastle, None),
+            (Move::QueenSideCastle, None),
+            (Move::Piece(F8, F1), None),
+            (Move::Piece(B7, A8), Some(Promotion::Queen)),
+        ]
+        .iter()
+        {
+            board = board.replay_move(*m, *promotion);
+            assert!(agree(&board), "{:?}", m);
+        }
+        assert_eq!(board.get_piece(A8), Some(Piece::Queen(WHITE, A8)));
+        // Every legal move from a few positions
+        for board in [
+            Board::default(),
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+                .unwrap(),
+        ]
+        .iter()
+        {
+            for m in board.get_legal_moves(board.get_turn()) {
+                assert!(agree(&board.apply_move(m)), "{:?}", m);
+            }
+        }
+    }
+
+    #[test]
+    fn control_map() {
+        let index = |pos: Position| (pos.get_row() * 8 + pos.get_col()) as usize;
+        let map: [i8; 64] = Board::default().control_map();
+        assert_eq!(map[index(F3)], 3);
+        assert_eq!(map[index(E3)], 2);
+        assert_eq!(map[index(F6)], -3);
+        assert_eq!(map[index(E4)], 0);
+        assert_eq!(map.iter().map(|x| *x as i32).sum::<i32>(), 0);
+        // The rook controls the e-file up to the pawn; the pieces defending their own pieces are not counted
         let board: Board = BoardBuilder::default()
-            .piece(Piece::Queen(WHITE, D1))
-            .piece(Piece::Bishop(WHITE, D3))
-            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Rook(WHITE, E1))
+            .piece(Piece::Pawn(BLACK, E5))
+            .piece(Piece::King(BLACK, E6))
             .build();
-        assert_eq!(
-            board.get_player_pieces(WHITE),
-            vec![Piece::Bishop(WHITE, D3), Piece::Queen(WHITE, D1)]
-        );
-    }
-
-    #[test]
-    fn get_king_position() {
-        let board: Board = Board::horde();
-        assert_eq!(board.get_king_pos(BLACK).unwrap(), E8);
-        assert_eq!(board.get_king_pos(WHITE), None);
+        let map: [i8; 64] = board.control_map();
+        assert_eq!(map[index(E4)], 1);
+        assert_eq!(map[index(E5)], 1);
+        assert_eq!(map[index(D4)], -1);
+        assert_eq!(map[index(E7)], -1);
+        assert_eq!(map[index(B2)], 1);
+        assert_eq!(map[index(H8)], 0);
     }
 
     #[test]
@@ -1421,6 +2939,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn has_any_legal_move() {
+        let fens: [&str; 7] = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            // Fool's mate
+            "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+            // Stalemate
+            "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1",
+            // Check, only the king can move
+            "4k3/8/8/8/8/8/3PPP2/r3K3 w - - 0 1",
+            // Pinned pieces
+            "4k3/4r3/8/8/8/8/4R3/4K3 w - - 0 1",
+            // Only a drop saves from stalemate
+            "7k/5Q2/6K1/8/8/8/8/8[n] b - - 0 1",
+            // Crazyhouse mate
+            "6rk/6pp/8/8/8/8/8/4K2R[] w - - 0 1",
+        ];
+        for fen in fens.iter() {
+            let board: Board = Board::from_fen(fen).ok().unwrap();
+            for color in [WHITE, BLACK].iter() {
+                assert_eq!(
+                    board.has_any_legal_move(*color),
+                    !board.get_legal_moves(*color).is_empty(),
+                    "{} ({})",
+                    fen,
+                    color
+                );
+            }
+        }
+        let board: Board = Board::from_fen(fens[1]).ok().unwrap();
+        assert_eq!(board.is_checkmate(), true);
+        assert_eq!(board.has_any_legal_move(WHITE), false);
+        let board: Board = Board::from_fen(fens[2]).ok().unwrap();
+        assert_eq!(board.is_stalemate(), true);
+        assert_eq!(board.has_any_legal_move(BLACK), false);
+        let board: Board = Board::from_fen(fens[5]).ok().unwrap();
+        assert_eq!(board.is_stalemate(), false);
+        assert_eq!(board.has_any_legal_move(BLACK), true);
+    }
+
     #[test]
     fn get_piece_legal_moves() {
         let board: Board = Board::default();
@@ -1443,6 +3001,290 @@ mod test {
         assert_eq!(board.get_player_value(BLACK), 89.5);
     }
 
+    #[test]
+    fn evaluate_centipawns() {
+        let board: Board = Board::default();
+        assert_eq!(board.evaluate_centipawns(WHITE), 0);
+        assert_eq!(board.evaluate_centipawns(BLACK), 0);
+        // 1. e4: the pawn gains 40 centipawns leaving e2 (-20) for e4 (+20)
+        let board: Board = board.apply_move(Move::Piece(E2, E4)).change_turn();
+        assert_eq!(board.evaluate_centipawns(WHITE), 40);
+        assert_eq!(board.evaluate_centipawns(BLACK), -40);
+        // Queen odds
+        let board: Board = Board::default().remove_piece(D1);
+        assert_eq!(board.evaluate_centipawns(WHITE), -895);
+        assert_eq!(board.evaluate_centipawns(BLACK), 895);
+        // King and rook against king
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, A1))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(board.evaluate_centipawns(WHITE), 500);
+    }
+
+    #[test]
+    fn with_en_passant() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Pawn(WHITE, E5))
+            .piece(Piece::Pawn(BLACK, D5))
+            .piece(Piece::King(BLACK, A8))
+            .build();
+        assert!(!board.get_legal_moves(WHITE).contains(&Move::Piece(E5, D6)));
+        let board: Board = board.with_en_passant(Some(D6));
+        assert_eq!(board.get_en_passant(), Some(D6));
+        assert!(board.get_legal_moves(WHITE).contains(&Move::Piece(E5, D6)));
+        let board: Board = board.apply_move(Move::Piece(E5, D6));
+        assert_eq!(board.get_piece(D5), None);
+        assert_eq!(board.get_piece(D6), Some(Piece::Pawn(WHITE, D6)));
+        assert_eq!(board.with_en_passant(None).get_en_passant(), None);
+    }
+
+    #[test]
+    fn with_castling_rights() {
+        let board: Board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w - -")
+            .ok()
+            .unwrap();
+        assert_eq!(board.can_kingside_castle(WHITE), false);
+        assert_eq!(
+            board.get_castling_rights(WHITE),
+            CastlingRights::new(false, false)
+        );
+        let board: Board = board.with_castling_rights(WHITE, CastlingRights::new(true, false));
+        assert_eq!(board.can_kingside_castle(WHITE), true);
+        assert_eq!(board.can_queenside_castle(WHITE), false);
+        assert_eq!(board.can_kingside_castle(BLACK), false);
+        assert_eq!(board.to_fen().as_str(), "r3k2r/8/8/8/8/8/8/R3K2R w K - 0 1");
+    }
+
+    #[test]
+    fn smallest_attacker() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Rook(WHITE, E1))
+            .piece(Piece::Pawn(WHITE, D4))
+            .piece(Piece::Pawn(BLACK, E5))
+            .piece(Piece::King(BLACK, A8))
+            .build();
+        assert_eq!(board.get_attackers(E5, WHITE).len(), 2);
+        assert_eq!(
+            board.smallest_attacker(E5, WHITE),
+            Some(Piece::Pawn(WHITE, D4))
+        );
+        assert_eq!(
+            board.smallest_attacker(E4, WHITE),
+            Some(Piece::Rook(WHITE, E1))
+        );
+        assert_eq!(
+            board.smallest_attacker(B2, WHITE),
+            Some(Piece::King(WHITE, A1))
+        );
+        assert_eq!(board.smallest_attacker(E5, BLACK), None);
+        assert_eq!(board.get_attackers(H8, WHITE), vec![]);
+    }
+
+    #[test]
+    fn see_ge() {
+        // Rook takes undefended pawn
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Rook(WHITE, E2))
+            .piece(Piece::Pawn(BLACK, E5))
+            .piece(Piece::King(BLACK, A8))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 0), true);
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 1), true);
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 2), false);
+        // Quiet moves and castling
+        assert_eq!(board.see_ge(Move::Piece(E2, E3), 0), true);
+        assert_eq!(board.see_ge(Move::Piece(E2, E3), 1), false);
+        assert_eq!(board.see_ge(Move::KingSideCastle, 0), true);
+        // Pawn defended by rook: rook is lost
+        let board: Board = BoardBuilder::from(board)
+            .piece(Piece::Rook(BLACK, E8))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 0), false);
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), -4), true);
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), -3), false);
+        // X-ray: second rook behind the first one wins the pawn
+        let board: Board = BoardBuilder::from(board)
+            .piece(Piece::Rook(WHITE, E1))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 1), true);
+        assert_eq!(board.see_ge(Move::Piece(E2, E5), 2), false);
+        // Queen takes pawn defended by pawn
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Queen(WHITE, D1))
+            .piece(Piece::Pawn(BLACK, D5))
+            .piece(Piece::Pawn(BLACK, C6))
+            .piece(Piece::King(BLACK, A8))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(D1, D5), 0), false);
+        assert_eq!(board.see_ge(Move::Piece(D1, D5), -8), true);
+        // King recaptures, unless the square is defended
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, A1))
+            .piece(Piece::Knight(WHITE, C3))
+            .piece(Piece::Pawn(BLACK, D5))
+            .piece(Piece::King(BLACK, E6))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(C3, D5), 1), false);
+        assert_eq!(board.see_ge(Move::Piece(C3, D5), -2), true);
+        let board: Board = BoardBuilder::from(board)
+            .piece(Piece::Rook(WHITE, D1))
+            .build();
+        assert_eq!(board.see_ge(Move::Piece(C3, D5), 1), true);
+        assert_eq!(board.see_ge(Move::Piece(C3, D5), 2), false);
+    }
+
+    #[test]
+    fn to_san() {
+        let board: Board = Board::default();
+        assert_eq!(board.to_san(Move::Piece(E2, E4), None).as_str(), "e4");
+        assert_eq!(board.to_san(Move::Piece(G1, F3), None).as_str(), "Nf3");
+        assert_eq!(board.to_san(Move::Resign, None).as_str(), "");
+        // Disambiguation, captures and castling
+        let board: Board = BoardBuilder::default()
+            .enable_castling()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, H1))
+            .piece(Piece::Knight(WHITE, B1))
+            .piece(Piece::Knight(WHITE, F1))
+            .piece(Piece::Rook(WHITE, A3))
+            .piece(Piece::Rook(WHITE, A5))
+            .piece(Piece::Pawn(WHITE, B7))
+            .piece(Piece::Pawn(WHITE, E5))
+            .piece(Piece::Pawn(BLACK, D6))
+            .piece(Piece::Knight(BLACK, C8))
+            .piece(Piece::King(BLACK, G8))
+            .build();
+        assert_eq!(board.to_san(Move::Piece(B1, D2), None).as_str(), "Nbd2");
+        assert_eq!(board.to_san(Move::Piece(A3, A4), None).as_str(), "R3a4");
+        assert_eq!(board.to_san(Move::Piece(E5, D6), None).as_str(), "exd6");
+        assert_eq!(board.to_san(Move::KingSideCastle, None).as_str(), "O-O");
+        assert_eq!(
+            board
+                .to_san(Move::Piece(B7, C8), Some(Promotion::Queen))
+                .as_str(),
+            "bxc8=Q+"
+        );
+        assert_eq!(
+            board
+                .to_san(Move::Piece(B7, B8), Some(Promotion::Knight))
+                .as_str(),
+            "b8=N"
+        );
+    }
+
+    #[test]
+    fn mobility() {
+        let board: Board = Board::default();
+        assert_eq!(board.mobility(WHITE), 20);
+        // Mobility for player who is not moving
+        assert_eq!(board.mobility(BLACK), 20);
+        assert_eq!(board.get_turn(), WHITE);
+        // Open position
+        let open: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Queen(WHITE, D4))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(open.mobility(WHITE), 32);
+        // Cramped position
+        let cramped: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, H1))
+            .piece(Piece::Queen(WHITE, G1))
+            .piece(Piece::Pawn(WHITE, G2))
+            .piece(Piece::Pawn(WHITE, H2))
+            .piece(Piece::Pawn(WHITE, F2))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert!(open.mobility(WHITE) > cramped.mobility(WHITE));
+    }
+
+    #[test]
+    fn positional_value() {
+        let board: Board = Board::default();
+        assert_eq!(board.positional_value(WHITE), 0.0);
+        // Knight is better placed in the center
+        let board: Board = board
+            .apply_move(Move::Piece(G1, F3))
+            .change_turn()
+            .apply_move(Move::Piece(B8, A6));
+        assert_eq!(board.positional_value(WHITE), 4.0);
+        assert_eq!(board.positional_value(BLACK), -4.0);
+        assert_eq!(board.get_material_advantage(WHITE), 0);
+        // Values are consistent
+        let board: Board = board.remove_piece(D8).remove_piece(A2);
+        for color in [WHITE, BLACK] {
+            assert_eq!(
+                board.positional_value(color) + (board.get_material_advantage(color) * 10) as f64,
+                board.get_player_value(color)
+            );
+        }
+    }
+
+    #[test]
+    fn material_tracking() {
+        // Compute values from scratch
+        fn material_advantage(board: &Board, color: Color) -> i32 {
+            board
+                .squares
+                .iter()
+                .filter_map(|x| x.get_piece())
+                .map(|x| match x.get_color() == color {
+                    true => x.get_material_value(),
+                    false => -x.get_material_value(),
+                })
+                .sum()
+        }
+        fn player_value(board: &Board, color: Color) -> f64 {
+            board
+                .squares
+                .iter()
+                .filter_map(|x| x.get_piece())
+                .map(|x| match x.get_color() == color {
+                    true => x.get_weighted_value(),
+                    false => -x.get_weighted_value(),
+                })
+                .sum()
+        }
+        // Play pseudo-random moves
+        let mut board: Board = Board::default();
+        let mut seed: usize = 42;
+        for _ in 0..80 {
+            for color in [WHITE, BLACK] {
+                assert_eq!(
+                    board.get_material_advantage(color),
+                    material_advantage(&board, color)
+                );
+                assert_eq!(board.get_player_value(color), player_value(&board, color));
+            }
+            let moves: Vec<Move> = board.get_legal_moves(board.get_turn());
+            if moves.is_empty() {
+                break;
+            }
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % 2147483648;
+            board = match board.play_move(moves[seed % moves.len()]) {
+                MoveResult::Continuing(board) => board,
+                MoveResult::Promote(board, _) => match board.promote(Promotion::Queen) {
+                    MoveResult::Continuing(board) => board,
+                    _ => break,
+                },
+                _ => break,
+            };
+        }
+        // Modifiers
+        let board: Board = Board::default().queen_all(WHITE).remove_all(BLACK);
+        assert_eq!(
+            board.get_material_advantage(WHITE),
+            material_advantage(&board, WHITE)
+        );
+        assert_eq!(board.get_player_value(BLACK), player_value(&board, BLACK));
+    }
+
     #[test]
     fn get_rating() {
         let board: Board = Board::default();
@@ -1520,6 +3362,26 @@ mod test {
         assert_eq!(board.is_check(), true);
     }
 
+    #[test]
+    fn checkers() {
+        let board: Board = Board::default();
+        assert_eq!(board.checkers(), vec![]);
+        // Double check
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Rook(WHITE, E2))
+            .piece(Piece::Knight(WHITE, F6))
+            .piece(Piece::Bishop(WHITE, A1))
+            .player_moving(BLACK)
+            .build();
+        assert_eq!(
+            board.checkers(),
+            vec![Piece::Knight(WHITE, F6), Piece::Rook(WHITE, E2)]
+        );
+        assert_eq!(BoardBuilder::default().build().checkers(), vec![]);
+    }
+
     #[test]
     fn can_kingside_castle() {
         let board: Board = Board::default();
@@ -1577,6 +3439,63 @@ mod test {
         assert_eq!(board.can_queenside_castle(BLACK), false);
     }
 
+    #[test]
+    fn castling_through_check() {
+        let board: Board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq -")
+            .ok()
+            .unwrap();
+        assert_eq!(board.can_kingside_castle(WHITE), true);
+        assert_eq!(board.can_queenside_castle(WHITE), true);
+        assert_eq!(board.can_kingside_castle(BLACK), true);
+        assert_eq!(board.can_queenside_castle(BLACK), true);
+        // An enemy rook controls the file of the square: (file, kingside allowed, queenside allowed)
+        for (col, kingside, queenside) in [
+            (1, true, true),   // b: the king doesn't cross it
+            (2, true, false),  // c: the king lands on it
+            (3, true, false),  // d: the king passes through it
+            (4, false, false), // e: the king is in check
+            (5, false, true),  // f: the king passes through it
+            (6, false, true),  // g: the king lands on it
+            (7, true, true),   // h: only the rook is attacked
+        ]
+        .iter()
+        {
+            for color in [WHITE, BLACK].iter() {
+                let row: i32 = match *color {
+                    WHITE => 3,
+                    BLACK => 4,
+                };
+                let board: Board = BoardBuilder::from(board.with_turn(*color))
+                    .piece(Piece::Rook(!*color, Position::new(row, *col)))
+                    .build();
+                assert_eq!(board.can_kingside_castle(*color), *kingside);
+                assert_eq!(board.can_queenside_castle(*color), *queenside);
+                assert_eq!(board.is_legal_move(Move::KingSideCastle, *color), *kingside);
+                assert_eq!(
+                    board.is_legal_move(Move::QueenSideCastle, *color),
+                    *queenside
+                );
+                assert_eq!(
+                    board
+                        .get_legal_moves(*color)
+                        .contains(&Move::QueenSideCastle),
+                    *queenside
+                );
+            }
+        }
+        // A bishop controlling the transit square on the diagonal
+        let board: Board = Board::from_fen("r3k2r/8/8/8/8/8/6b1/R3K2R w KQkq -")
+            .ok()
+            .unwrap();
+        assert_eq!(board.can_kingside_castle(WHITE), false);
+        assert_eq!(board.can_queenside_castle(WHITE), true);
+        let board: Board = Board::from_fen("r3k2r/8/8/8/8/8/1n6/R3K2R w KQkq -")
+            .ok()
+            .unwrap();
+        assert_eq!(board.can_queenside_castle(WHITE), false);
+        assert_eq!(board.can_kingside_castle(WHITE), true);
+    }
+
     #[test]
     fn is_legal_move() {
         let board: Board = Board::default();
@@ -1635,9 +3554,15 @@ mod test {
         board.add_piece(Piece::Knight(WHITE, E3));
         assert_eq!(board.has_sufficient_material(WHITE), false);
         assert_eq!(board.has_insufficient_material(WHITE), true);
-        // Two bishops
+        // Two bishops on squares of opposite colors
         board.add_piece(Piece::Bishop(WHITE, E2));
         board.add_piece(Piece::Bishop(WHITE, E3));
+        assert_eq!(board.has_sufficient_material(WHITE), true);
+        assert_eq!(board.has_insufficient_material(WHITE), false);
+        // Two bishops on squares of the same color
+        board = board.remove_piece(E3);
+        board.add_piece(Piece::Bishop(WHITE, D3));
+        assert_eq!(board.material_signature().as_str(), "KBBv");
         assert_eq!(board.has_sufficient_material(WHITE), false);
         assert_eq!(board.has_insufficient_material(WHITE), true);
         // Leave condition
@@ -1648,6 +3573,52 @@ mod test {
         assert_eq!(board.has_insufficient_material(WHITE), false);
     }
 
+    #[test]
+    fn is_dead_position() {
+        assert_eq!(Board::default().is_dead_position(), false);
+        // Bishops on squares of opposite colors against a lone king
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Bishop(WHITE, C1))
+            .piece(Piece::Bishop(WHITE, F1))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(board.has_sufficient_material(WHITE), true);
+        assert_eq!(board.is_dead_position(), false);
+        assert_eq!(board.is_stalemate(), false);
+        // Bishops on squares of the same color
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Bishop(WHITE, C1))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Bishop(BLACK, F8))
+            .build();
+        assert_eq!(C1.square_color(), F8.square_color());
+        assert_eq!(board.is_dead_position(), true);
+        assert_eq!(board.is_stalemate(), true);
+        // Bishops on squares of opposite colors
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Bishop(WHITE, C1))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Bishop(BLACK, C8))
+            .build();
+        assert_eq!(board.is_dead_position(), false);
+        assert_eq!(board.is_stalemate(), false);
+        // Knight against a lone king
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Knight(WHITE, C1))
+            .piece(Piece::King(BLACK, E8))
+            .build();
+        assert_eq!(board.is_dead_position(), true);
+        // Knight against bishop
+        let board: Board = BoardBuilder::from(board)
+            .piece(Piece::Bishop(BLACK, C8))
+            .build();
+        assert_eq!(board.is_dead_position(), false);
+    }
+
     #[test]
     fn is_stalemate() {
         let board: Board = Board::default();
@@ -1677,6 +3648,64 @@ mod test {
         assert_eq!(board.is_checkmate(), true);
     }
 
+    #[test]
+    fn path_clear() {
+        let board: Board = Board::default();
+        // Orthogonal
+        assert_eq!(board.path_clear(A3, H3), true);
+        assert_eq!(board.path_clear(E3, E6), true);
+        assert_eq!(board.path_clear(E2, E7), true);
+        assert_eq!(board.path_clear(A1, A8), false);
+        assert_eq!(board.path_clear(A8, A1), false);
+        assert_eq!(board.path_clear(A1, H1), false);
+        // Diagonal
+        assert_eq!(board.path_clear(C1, H6), false);
+        assert_eq!(board.path_clear(B2, G7), true);
+        assert_eq!(board.path_clear(G7, B2), true);
+        assert_eq!(board.path_clear(A1, H8), false);
+        assert_eq!(board.path_clear(H1, A8), false);
+        // Adjacent and same square
+        assert_eq!(board.path_clear(E1, E2), true);
+        assert_eq!(board.path_clear(E4, E4), true);
+        // Not aligned
+        assert_eq!(board.path_clear(B1, C3), false);
+        assert_eq!(board.path_clear(A3, H4), false);
+    }
+
+    #[test]
+    fn game_status() {
+        assert_eq!(Board::default().game_status(), GameStatus::InProgress);
+        // Check
+        let board: Board =
+            Board::from_fen("rnbqkbnr/ppp2ppp/8/1B1pp3/4P3/8/PPPP1PPP/RNBQK1NR b KQkq - 1 3")
+                .unwrap();
+        assert_eq!(board.game_status(), GameStatus::Check);
+        // Fool's mate
+        let board: Board =
+            Board::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+                .unwrap();
+        assert_eq!(board.game_status(), GameStatus::Checkmate(BLACK));
+        // Stalemate
+        let board: Board = Board::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(board.game_status(), GameStatus::Stalemate);
+        // Insufficient material
+        let board: Board = Board::from_fen("8/8/4k3/8/8/2B5/4K3/8 w - - 0 1").unwrap();
+        assert_eq!(board.game_status(), GameStatus::InsufficientMaterial);
+        assert_eq!(board.game_status().is_over(), true);
+        // Variant victory
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Rook(BLACK, A4))
+            .player_moving(WHITE)
+            .build()
+            .with_horde_rules(true);
+        assert_eq!(board.game_status(), GameStatus::VariantVictory(BLACK));
+        assert_eq!(
+            board.with_horde_rules(false).game_status(),
+            GameStatus::Stalemate
+        );
+    }
+
     #[test]
     fn get_best_next_move() {
         let board: Board = Board::default();
@@ -1685,6 +3714,125 @@ mod test {
         assert_eq!(board.get_best_next_move(2), (Move::Resign, -999999.0));
     }
 
+    #[test]
+    fn material_signature() {
+        assert_eq!(
+            Board::default().material_signature().as_str(),
+            "KQRRBBNNPPPPPPPPvKQRRBBNNPPPPPPPP"
+        );
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::Rook(BLACK, A8))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Queen(WHITE, D1))
+            .piece(Piece::King(WHITE, E1))
+            .build();
+        assert_eq!(board.material_signature().as_str(), "KQvKR");
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, E5))
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, A1))
+            .piece(Piece::King(BLACK, E8))
+            .piece(Piece::Rook(BLACK, A8))
+            .build();
+        assert_eq!(board.material_signature().as_str(), "KRPvKR");
+        assert_eq!(
+            BoardBuilder::default()
+                .build()
+                .material_signature()
+                .as_str(),
+            "v"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let board: Board = Board::default();
+        let squares: Vec<(Position, Option<Piece>)> = board.iter().collect();
+        assert_eq!(squares.len(), 64);
+        assert_eq!(squares[0], (A1, Some(Piece::Rook(WHITE, A1))));
+        assert_eq!(squares[4], (E1, Some(Piece::King(WHITE, E1))));
+        assert_eq!(squares[8], (A2, Some(Piece::Pawn(WHITE, A2))));
+        assert_eq!(squares[28], (E4, None));
+        assert_eq!(squares[59], (D8, Some(Piece::Queen(BLACK, D8))));
+        assert_eq!(squares[63], (H8, Some(Piece::Rook(BLACK, H8))));
+        // Each piece is placed on its square
+        for (pos, piece) in squares.iter() {
+            assert_eq!(*piece, board.get_piece(*pos));
+        }
+        let pieces: Vec<Piece> = board.pieces().collect();
+        assert_eq!(pieces.len(), 32);
+        assert!(pieces
+            .iter()
+            .all(|x| x.get_pos().get_row() < 2 || x.get_pos().get_row() > 5));
+        assert_eq!(pieces[0], Piece::Rook(WHITE, A1));
+        assert_eq!(pieces[16], Piece::Pawn(BLACK, A7));
+        assert_eq!(pieces[31], Piece::Rook(BLACK, H8));
+        assert_eq!(BoardBuilder::default().build().pieces().count(), 0);
+    }
+
+    #[test]
+    fn piece_grid() {
+        let grid: [[Option<Piece>; 8]; 8] = Board::default().piece_grid();
+        assert_eq!(grid[0][0], Some(Piece::Rook(BLACK, A8)));
+        assert_eq!(grid[0][4], Some(Piece::King(BLACK, E8)));
+        assert_eq!(grid[1][3], Some(Piece::Pawn(BLACK, D7)));
+        assert_eq!(grid[4][4], None);
+        assert_eq!(grid[6][7], Some(Piece::Pawn(WHITE, H2)));
+        assert_eq!(grid[7][3], Some(Piece::Queen(WHITE, D1)));
+        assert_eq!(grid[7][7], Some(Piece::Rook(WHITE, H1)));
+        let grid: [[Option<Piece>; 8]; 8] = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .piece_grid();
+        assert_eq!(grid[4][4], Some(Piece::Pawn(WHITE, E4)));
+        assert_eq!(grid[6][4], None);
+    }
+
+    #[test]
+    fn king_distance_to_edge() {
+        let board: Board = Board::default();
+        assert_eq!(board.king_distance_to_edge(WHITE), Some(0));
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, D4))
+            .piece(Piece::King(BLACK, B7))
+            .build();
+        assert_eq!(board.king_distance_to_edge(WHITE), Some(3));
+        assert_eq!(board.king_distance_to_edge(BLACK), Some(1));
+        assert_eq!(Board::empty().king_distance_to_edge(WHITE), None);
+    }
+
+    #[test]
+    fn mop_up() {
+        // Not applied if opponent has other pieces than king
+        assert_eq!(Board::default().mop_up(WHITE), 0.0);
+        // Not applied without a queen or rook
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, D4))
+            .piece(Piece::Bishop(WHITE, C1))
+            .piece(Piece::King(BLACK, E6))
+            .build();
+        assert_eq!(board.mop_up(WHITE), 0.0);
+        // Enemy king closer to the edge is better
+        let center: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E2))
+            .piece(Piece::Rook(WHITE, A1))
+            .piece(Piece::King(BLACK, E5))
+            .build();
+        let mut edge: Board = center.remove_piece(E5);
+        edge.add_piece(Piece::King(BLACK, E7));
+        assert!(edge.mop_up(WHITE) > center.mop_up(WHITE));
+        assert_eq!(center.mop_up(BLACK), 0.0);
+        // KQ vs K: the best move takes space from the enemy king
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, B7))
+            .piece(Piece::Queen(WHITE, A1))
+            .piece(Piece::King(BLACK, E6))
+            .build();
+        let (m, _) = board.get_best_next_move(0);
+        assert_eq!(m, Move::Piece(B7, C6));
+        assert_eq!(board.mobility(BLACK), 6);
+        assert_eq!(board.apply_move(m).mobility(BLACK), 3);
+    }
+
     #[test]
     fn get_worst_next_move() {
         let board: Board = Board::default();
@@ -1715,6 +3863,7 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn set_turn() {
         let board: Board = Board::default().set_turn(BLACK);
         assert_eq!(board.get_turn(), BLACK);
@@ -1722,6 +3871,27 @@ mod test {
         assert_eq!(board.get_turn(), WHITE);
     }
 
+    #[test]
+    fn with_turn() {
+        let board: Board = Board::default();
+        let black: Board = board.with_turn(BLACK);
+        assert_eq!(black.get_turn(), BLACK);
+        // The original board is unchanged
+        assert_eq!(board.get_turn(), WHITE);
+        assert_eq!(black.with_turn(WHITE), board);
+    }
+
+    #[test]
+    fn set_turn_mut() {
+        let mut board: Board = Board::default();
+        board.set_turn_mut(BLACK);
+        assert_eq!(board.get_turn(), BLACK);
+        board.set_turn_mut(BLACK);
+        assert_eq!(board.get_turn(), BLACK);
+        board.set_turn_mut(WHITE);
+        assert_eq!(board, Board::default());
+    }
+
     #[test]
     fn change_turn() {
         let board: Board = Board::default().change_turn();
@@ -1881,4 +4051,16 @@ mod test {
     fn fmt_board() {
         Board::default().to_string();
     }
+
+    #[test]
+    fn fmt_board_ascii() {
+        let output: String = format!("{:#}", Board::default());
+        assert!(output.is_ascii());
+        assert!(output.contains("8 |rnbqkbnr|"));
+        assert!(output.contains("7 |pppppppp|"));
+        assert!(output.contains("6 |#.#.#.#.|"));
+        assert!(output.contains("2 |PPPPPPPP|"));
+        assert!(output.contains("1 |RNBQKBNR|"));
+        assert!(!output.contains('\x1b'));
+    }
 }
@@ -0,0 +1,161 @@
+//! # Odds
+//!
+//! This module exposes the handicaps of the odds games, where the stronger player gives away material or moves
+//! to the weaker one.
+//! <https://en.wikipedia.org/wiki/Handicap_(chess)>
+
+use super::{Board, CastlingRights, Color, Position};
+
+/// ## Odds
+///
+/// Describes the handicap given by a player in an odds game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Odds {
+    /// The queenside knight is removed
+    KnightOdds,
+    /// The queenside rook is removed, so the player can't castle queenside
+    RookOdds,
+    /// The queen is removed
+    QueenOdds,
+    /// The f-pawn is removed and the opponent moves first
+    PawnAndMove,
+    /// The opponent moves first and plays two moves in a row
+    TwoMoveOdds,
+}
+
+impl Board {
+    /// ### with_odds
+    ///
+    /// Get a new board where `color` gives the provided odds to the opponent.
+    /// The pieces are removed from their starting squares, so this is meant to be called on the initial position
+    /// (e.g. `Board::default().with_odds(Color::White, Odds::RookOdds)`).
+    ///
+    /// The extra move granted by `TwoMoveOdds` is not encoded in FEN
+    pub fn with_odds(&self, color: Color, odds: Odds) -> Self {
+        let rank: i32 = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        match odds {
+            Odds::KnightOdds => self.remove_piece(Position::new(rank, 1)),
+            Odds::RookOdds => {
+                let mut rights: CastlingRights = self.get_castling_rights(color);
+                rights.disable_queenside();
+                self.remove_piece(Position::new(rank, 0))
+                    .with_castling_rights(color, rights)
+            }
+            Odds::QueenOdds => self.remove_piece(Position::queen_pos(color)),
+            Odds::PawnAndMove => {
+                let pawn_rank: i32 = match color {
+                    Color::White => 1,
+                    Color::Black => 6,
+                };
+                self.remove_piece(Position::new(pawn_rank, 5))
+                    .with_turn(!color)
+            }
+            Odds::TwoMoveOdds => {
+                let mut result = self.with_turn(!color);
+                result.odds_moves = 1;
+                result
+            }
+        }
+    }
+
+    /// ### get_odds_moves
+    ///
+    /// Get the amount of moves the player to move can still play before the turn passes to the opponent,
+    /// granted by `Odds::TwoMoveOdds`
+    pub fn get_odds_moves(&self) -> u8 {
+        self.odds_moves
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{Move, MoveResult, Piece};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn material_odds() {
+        let board: Board = Board::default().with_odds(Color::White, Odds::KnightOdds);
+        assert_eq!(board.get_piece(B1), None);
+        assert_eq!(board.get_piece(G1), Some(Piece::Knight(Color::White, G1)));
+        assert_eq!(board.get_player_pieces(Color::White).len(), 15);
+        assert_eq!(board.get_player_pieces(Color::Black).len(), 16);
+        assert_eq!(board.get_material_advantage(Color::Black), 3);
+        assert_eq!(board.get_turn(), Color::White);
+        let board: Board = Board::default().with_odds(Color::Black, Odds::QueenOdds);
+        assert_eq!(board.get_piece(D8), None);
+        assert_eq!(board.get_material_advantage(Color::White), 9);
+        assert_eq!(
+            board.get_castling_rights(Color::Black),
+            CastlingRights::default()
+        );
+    }
+
+    #[test]
+    fn rook_odds() {
+        let board: Board = Board::default().with_odds(Color::White, Odds::RookOdds);
+        assert_eq!(board.get_piece(A1), None);
+        assert_eq!(board.get_piece(H1), Some(Piece::Rook(Color::White, H1)));
+        let rights: CastlingRights = board.get_castling_rights(Color::White);
+        assert_eq!(rights.can_kingside_castle(), true);
+        assert_eq!(rights.can_queenside_castle(), false);
+        assert_eq!(
+            board.get_castling_rights(Color::Black),
+            CastlingRights::default()
+        );
+        assert_eq!(
+            board.to_fen().as_str(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/1NBQKBNR w Kkq - 0 1"
+        );
+        let board: Board = Board::default().with_odds(Color::Black, Odds::RookOdds);
+        assert_eq!(board.get_piece(A8), None);
+        assert_eq!(
+            board
+                .get_castling_rights(Color::Black)
+                .can_queenside_castle(),
+            false
+        );
+    }
+
+    #[test]
+    fn pawn_and_move() {
+        let board: Board = Board::default().with_odds(Color::White, Odds::PawnAndMove);
+        assert_eq!(board.get_piece(F2), None);
+        assert_eq!(board.get_piece(F7), Some(Piece::Pawn(Color::Black, F7)));
+        assert_eq!(board.get_turn(), Color::Black);
+        let board: Board = Board::default().with_odds(Color::Black, Odds::PawnAndMove);
+        assert_eq!(board.get_piece(F7), None);
+        assert_eq!(board.get_turn(), Color::White);
+    }
+
+    #[test]
+    fn two_move_odds() {
+        let board: Board = Board::default().with_odds(Color::White, Odds::TwoMoveOdds);
+        assert_eq!(board.get_turn(), Color::Black);
+        assert_eq!(board.get_odds_moves(), 1);
+        assert_eq!(board.get_player_pieces(Color::White).len(), 16);
+        // Black plays two moves, then the turn passes to white
+        let board: Board = match board.play_move(Move::Piece(E7, E5)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected result"),
+        };
+        assert_eq!(board.get_turn(), Color::Black);
+        assert_eq!(board.get_odds_moves(), 0);
+        let board: Board = match board.play_move(Move::Piece(D7, D5)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected result"),
+        };
+        assert_eq!(board.get_turn(), Color::White);
+        let board: Board = match board.play_move(Move::Piece(E2, E4)) {
+            MoveResult::Continuing(board) => board,
+            _ => panic!("Unexpected result"),
+        };
+        assert_eq!(board.get_turn(), Color::Black);
+    }
+}
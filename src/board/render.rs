@@ -0,0 +1,754 @@
+//! # Render
+//!
+//! This module exposes the configurable text renderers for `Board`
+
+use super::{Board, Color, Move, Position, BLACK, WHITE};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// ANSI escape to highlight the squares of the last move
+const ANSI_LAST_MOVE: &str = "\x1b[43m";
+/// ANSI escape to highlight a king in check
+const ANSI_CHECK: &str = "\x1b[41m";
+/// ANSI escape to reset the style
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// ## RenderError
+///
+/// Describes why the board couldn't be rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderError {
+    /// The buffer is too small to contain the rendered board, which requires the provided amount of bytes
+    BufferTooSmall(usize),
+}
+
+impl core::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BufferTooSmall(required) => {
+                write!(
+                    f,
+                    "The buffer is too small: {} bytes are required",
+                    required
+                )
+            }
+        }
+    }
+}
+
+/// ## RenderOptions
+///
+/// Options for `Board::render`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// If enabled, ranks and files labels are written on the side and below the board.
+    /// Default: true
+    pub coordinates: bool,
+    /// If enabled, pieces are rendered with unicode chess symbols, otherwise with ASCII letters (uppercase for white).
+    /// Default: true
+    pub unicode: bool,
+    /// If enabled, highlights are rendered using ANSI escape sequences; without ANSI nothing is highlighted.
+    /// Default: false
+    pub ansi: bool,
+    /// The player whose side of the board is at the bottom.
+    /// Default: White
+    pub perspective: Color,
+    /// The last move played, whose squares are highlighted.
+    /// Default: None
+    pub last_move: Option<Move>,
+    /// If enabled, the king of the player to move is highlighted when in check.
+    /// Default: true
+    pub highlight_check: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            coordinates: true,
+            unicode: true,
+            ansi: false,
+            perspective: Color::White,
+            last_move: None,
+            highlight_check: true,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// ### coordinates
+    ///
+    /// Set coordinates option
+    pub fn coordinates(mut self, enabled: bool) -> Self {
+        self.coordinates = enabled;
+        self
+    }
+
+    /// ### unicode
+    ///
+    /// Set unicode option
+    pub fn unicode(mut self, enabled: bool) -> Self {
+        self.unicode = enabled;
+        self
+    }
+
+    /// ### ansi
+    ///
+    /// Set ANSI option
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    /// ### perspective
+    ///
+    /// Set the player whose side of the board is at the bottom
+    pub fn perspective(mut self, color: Color) -> Self {
+        self.perspective = color;
+        self
+    }
+
+    /// ### last_move
+    ///
+    /// Set the last move to highlight
+    pub fn last_move(mut self, m: Option<Move>) -> Self {
+        self.last_move = m;
+        self
+    }
+
+    /// ### highlight_check
+    ///
+    /// Set highlight check option
+    pub fn highlight_check(mut self, enabled: bool) -> Self {
+        self.highlight_check = enabled;
+        self
+    }
+}
+
+/// ## BoardDisplay
+///
+/// A configurable view of a `Board` implementing `Display`, with borders, the material advantage,
+/// the player to move and the rating bar on the side. Get it with `Board::display`.
+/// This is the only board renderer: `Board::render` and `Board::render_ascii` are configurations of it
+#[derive(Debug, Clone, Copy)]
+pub struct BoardDisplay<'a> {
+    board: &'a Board,
+    ansi: bool,
+    perspective: Option<Color>,
+    coordinates: bool,
+    highlight_threats: bool,
+    highlight_check: bool,
+    rating_bar: Option<usize>,
+    ascii: bool,
+    highlighted: &'a [Position],
+    borders: bool,
+    details: bool,
+    spacing: bool,
+    shade_squares: bool,
+}
+
+impl<'a> BoardDisplay<'a> {
+    /// ### ansi
+    ///
+    /// If enabled, highlights are rendered using ANSI escape sequences; without ANSI nothing is highlighted.
+    /// Default: true
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    /// ### perspective
+    ///
+    /// Set the player whose side of the board is at the bottom.
+    /// Default: the player to move
+    pub fn perspective(mut self, color: Color) -> Self {
+        self.perspective = Some(color);
+        self
+    }
+
+    /// ### coordinates
+    ///
+    /// If enabled, ranks and files labels are written around the board.
+    /// Default: true
+    pub fn coordinates(mut self, enabled: bool) -> Self {
+        self.coordinates = enabled;
+        self
+    }
+
+    /// ### highlight_threats
+    ///
+    /// If enabled, the squares threatened by each player and the en passant square are highlighted (requires ANSI).
+    /// Default: true
+    pub fn highlight_threats(mut self, enabled: bool) -> Self {
+        self.highlight_threats = enabled;
+        self
+    }
+
+    /// ### highlight_check
+    ///
+    /// If enabled, the king of the player to move is highlighted when in check (requires ANSI).
+    /// Default: false
+    pub fn highlight_check(mut self, enabled: bool) -> Self {
+        self.highlight_check = enabled;
+        self
+    }
+
+    /// ### rating_bar
+    ///
+    /// Set the depth used to compute the rating bar; `None` hides the rating bar.
+    /// Default: `Some(2)`
+    pub fn rating_bar(mut self, depth: Option<usize>) -> Self {
+        self.rating_bar = depth;
+        self
+    }
+
+    /// ### ascii
+    ///
+    /// If enabled, pieces and borders are rendered with plain ASCII characters, otherwise with unicode symbols.
+    /// Default: false
+    pub fn ascii(mut self, enabled: bool) -> Self {
+        self.ascii = enabled;
+        self
+    }
+
+    /// ### highlight_squares
+    ///
+    /// Set the squares to highlight, such as the squares of the last move (requires ANSI).
+    /// Default: none
+    pub fn highlight_squares(mut self, squares: &'a [Position]) -> Self {
+        self.highlighted = squares;
+        self
+    }
+
+    /// ### borders
+    ///
+    /// If enabled, the board is framed by borders and the files are written both above and below the board.
+    /// Default: true
+    pub fn borders(mut self, enabled: bool) -> Self {
+        self.borders = enabled;
+        self
+    }
+
+    /// ### details
+    ///
+    /// If enabled, the material advantage and the player to move are written on the side of the board.
+    /// Default: true
+    pub fn details(mut self, enabled: bool) -> Self {
+        self.details = enabled;
+        self
+    }
+
+    /// ### spacing
+    ///
+    /// If enabled, squares (and files labels) are separated by a space.
+    /// Default: false
+    pub fn spacing(mut self, enabled: bool) -> Self {
+        self.spacing = enabled;
+        self
+    }
+
+    /// ### shade_squares
+    ///
+    /// If enabled, empty squares are drawn as light and dark squares, otherwise as dots.
+    /// Default: true
+    pub fn shade_squares(mut self, enabled: bool) -> Self {
+        self.shade_squares = enabled;
+        self
+    }
+
+    /// ### rating_bar_text
+    ///
+    /// Get the rating bar, where the share of white is filled with dark squares and the share of black with light squares
+    fn rating_bar_text(&self, depth: usize, light_square: char, dark_square: char) -> String {
+        let (white_score, black_score): (f64, f64) = self.board.get_rating(depth);
+        dark_square
+            .to_string()
+            .repeat((white_score * 0.16_f64) as usize)
+            + &light_square
+                .to_string()
+                .repeat((black_score * 0.16_f64) as usize)
+    }
+}
+
+impl<'a> core::fmt::Display for BoardDisplay<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let board: &Board = self.board;
+        let turn: Color = board.get_turn();
+        let perspective: Color = self.perspective.unwrap_or(turn);
+        // Get charset
+        let (light_square, dark_square) = match (self.shade_squares, self.ascii) {
+            (true, true) => ('.', '#'),
+            (true, false) => ('░', '▓'),
+            (false, true) => ('.', '.'),
+            (false, false) => ('·', '·'),
+        };
+        let (top_border, bottom_border, side_border) = match (self.borders, self.ascii) {
+            (false, _) => ("", "", ""),
+            (true, true) => ("+--------+", "+--------+", "|"),
+            (true, false) => ("╔════════╗", "╚════════╝", "║"),
+        };
+        // Files are aligned to the squares, past the side border
+        let files_indent: &str = match self.borders {
+            true => " ",
+            false => "",
+        };
+        let separator: &str = match self.spacing {
+            true => " ",
+            false => "",
+        };
+        let check: Option<Position> = match self.ansi && self.highlight_check && board.is_check() {
+            true => board.get_king_pos(turn),
+            false => None,
+        };
+        // Prepare labels
+        let cols: [i32; 8] = match perspective {
+            WHITE => [0, 1, 2, 3, 4, 5, 6, 7],
+            BLACK => [7, 6, 5, 4, 3, 2, 1, 0],
+        };
+        let indent: &str = match self.coordinates {
+            true => "  ",
+            false => "",
+        };
+        let write_files = |f: &mut core::fmt::Formatter| -> core::fmt::Result {
+            write!(f, "{}{}", indent, files_indent)?;
+            for (i, col) in cols.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "{}", separator)?;
+                }
+                write!(f, "{}", (b'a' + *col as u8) as char)?;
+            }
+            writeln!(f)
+        };
+        // Write board
+        if self.coordinates && self.borders {
+            write_files(f)?;
+        }
+        if self.borders {
+            writeln!(f, "{}{}", indent, top_border)?;
+        }
+        let mut square_color = !perspective;
+        for row in 0..8 {
+            let print_row = match perspective {
+                WHITE => 7 - row,
+                BLACK => row,
+            };
+            if self.coordinates {
+                write!(f, "{} ", print_row + 1)?;
+            }
+            write!(f, "{}", side_border)?;
+            for (i, print_col) in cols.iter().enumerate() {
+                let pos = Position::new(print_row, *print_col);
+                let s: char = match board.get_piece(pos) {
+                    Some(piece) if self.ascii => piece.to_ascii_char(),
+                    Some(piece) => piece.to_unicode_char(),
+                    None => match square_color {
+                        WHITE => light_square,
+                        BLACK => dark_square,
+                    },
+                };
+                if i > 0 {
+                    write!(f, "{}", separator)?;
+                }
+                let threats: bool = self.ansi && self.highlight_threats;
+                if Some(pos) == check {
+                    write!(f, "{}{}{}", ANSI_CHECK, s, ANSI_RESET)?;
+                } else if self.ansi && self.highlighted.contains(&pos) {
+                    write!(f, "{}{}{}", ANSI_LAST_MOVE, s, ANSI_RESET)?;
+                } else if threats && Some(pos) == board.get_en_passant() {
+                    write!(f, "\x1b[34m{}\x1b[m\x1b[0m", s)?;
+                } else if threats && board.is_threatened(pos, turn) {
+                    write!(f, "\x1b[31m{}\x1b[m\x1b[0m", s)?;
+                } else if threats && board.is_threatened(pos, !turn) {
+                    write!(f, "\x1b[32m{}\x1b[m\x1b[0m", s)?;
+                } else {
+                    write!(f, "{}", s)?;
+                }
+                square_color = !square_color;
+            }
+            write!(f, "{}", side_border)?;
+            if row == 2 && self.details {
+                let white_adv = board.get_material_advantage(WHITE);
+                let black_adv = board.get_material_advantage(BLACK);
+                match white_adv.cmp(&black_adv) {
+                    Ordering::Equal => write!(f, " Both sides have equal material")?,
+                    Ordering::Greater => write!(f, " White +{} points", white_adv)?,
+                    Ordering::Less => write!(f, " Black +{} points", black_adv)?,
+                }
+            } else if row == 3 && self.details {
+                write!(f, " {} to move", turn)?;
+            } else if let (4, Some(depth)) = (row, self.rating_bar) {
+                write!(
+                    f,
+                    " [{}]",
+                    self.rating_bar_text(depth, light_square, dark_square)
+                )?;
+            }
+            writeln!(f)?;
+            square_color = !square_color;
+        }
+        if self.borders {
+            writeln!(f, "{}{}", indent, bottom_border)?;
+        }
+        if self.coordinates {
+            write_files(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Board {
+    /// ### display
+    ///
+    /// Get a configurable view of the board implementing `Display`.
+    /// The default configuration is the same used by the `Display` implementation of `Board`
+    pub fn display(&self) -> BoardDisplay<'_> {
+        BoardDisplay {
+            board: self,
+            ansi: true,
+            perspective: None,
+            coordinates: true,
+            highlight_threats: true,
+            highlight_check: false,
+            rating_bar: Some(2),
+            ascii: false,
+            highlighted: &[],
+            borders: true,
+            details: true,
+            spacing: false,
+            shade_squares: true,
+        }
+    }
+
+    /// ### render
+    ///
+    /// Render the board as text, one line per rank, according to the provided options.
+    /// Squares are separated by a space; empty squares are rendered as `.` (`·` with unicode)
+    pub fn render(&self, opts: RenderOptions) -> String {
+        let last_move: [Option<Position>; 2] = self.last_move_squares(opts.last_move);
+        let highlighted: Vec<Position> = last_move.iter().flatten().copied().collect();
+        self.plain_display(opts.perspective, opts.coordinates)
+            .ansi(opts.ansi)
+            .ascii(!opts.unicode)
+            .spacing(true)
+            .highlight_check(opts.highlight_check)
+            .highlight_squares(&highlighted)
+            .to_string()
+    }
+
+    /// ### render_ascii
+    ///
+    /// Render the board as a plain ASCII diagram into `buf`, without allocating; this is meant for embedded targets.
+    /// Each rank is written on a line from the eighth to the first, with a character for each square
+    /// (uppercase letters for white, lowercase for black and `.` for empty squares).
+    /// If `coordinates` is enabled, each rank starts with its number and a space, and the files are written on the last line.
+    /// Returns the amount of bytes written, or `RenderError::BufferTooSmall` if the diagram doesn't fit in `buf`,
+    /// in which case nothing is written
+    pub fn render_ascii(&self, buf: &mut [u8], coordinates: bool) -> Result<usize, RenderError> {
+        let required: usize = match coordinates {
+            true => 11 * 9,
+            false => 9 * 8,
+        };
+        if buf.len() < required {
+            return Err(RenderError::BufferTooSmall(required));
+        }
+        let mut len: usize = 0;
+        let mut push = |byte: u8| {
+            buf[len] = byte;
+            len += 1;
+        };
+        for row in (0..8).rev() {
+            if coordinates {
+                push(b'1' + row as u8);
+                push(b' ');
+            }
+            for col in 0..8 {
+                push(match self.get_piece(Position::new(row, col)) {
+                    Some(piece) => piece.to_ascii_char() as u8,
+                    None => b'.',
+                });
+            }
+            push(b'\n');
+        }
+        if coordinates {
+            push(b' ');
+            push(b' ');
+            (b'a'..=b'h').for_each(&mut push);
+            push(b'\n');
+        }
+        Ok(len)
+    }
+
+    /// ### plain_display
+    ///
+    /// Get a display of the board without borders, details, rating bar and threats, from the `perspective` player side
+    fn plain_display(&self, perspective: Color, coordinates: bool) -> BoardDisplay<'_> {
+        self.display()
+            .perspective(perspective)
+            .coordinates(coordinates)
+            .borders(false)
+            .details(false)
+            .rating_bar(None)
+            .highlight_threats(false)
+            .shade_squares(false)
+    }
+
+    /// ### last_move_squares
+    ///
+    /// Get the squares of the last move, played by the opponent of the player to move
+    fn last_move_squares(&self, m: Option<Move>) -> [Option<Position>; 2] {
+        let color: Color = !self.get_turn();
+        let king: Position = Position::king_pos(color);
+        match m {
+            Some(Move::Piece(from, to)) => [Some(from), Some(to)],
+            Some(Move::Drop(_, to)) => [Some(to), None],
+            Some(Move::KingSideCastle) => [Some(king), Some(king.next_right().next_right())],
+            Some(Move::QueenSideCastle) => [Some(king), Some(king.next_left().next_left())],
+            Some(Move::Resign) | None => [None, None],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display_plain_ascii() {
+        let out: String = Board::default()
+            .display()
+            .ascii(true)
+            .ansi(false)
+            .rating_bar(None)
+            .to_string();
+        assert_eq!(
+            out.as_str(),
+            "   abcdefgh
+  +--------+
+8 |rnbqkbnr|
+7 |pppppppp|
+6 |#.#.#.#.| Both sides have equal material
+5 |.#.#.#.#| White to move
+4 |#.#.#.#.|
+3 |.#.#.#.#|
+2 |PPPPPPPP|
+1 |RNBQKBNR|
+  +--------+
+   abcdefgh
+"
+        );
+        // The default configuration is used by `Display` for `Board`
+        assert_eq!(
+            Board::default().display().to_string(),
+            Board::default().to_string()
+        );
+        assert_eq!(
+            Board::default()
+                .display()
+                .ascii(true)
+                .ansi(false)
+                .to_string(),
+            alloc::format!("{:#}", Board::default())
+        );
+    }
+
+    #[test]
+    fn display_flipped_perspective() {
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .change_turn();
+        let out: String = board
+            .display()
+            .ascii(true)
+            .ansi(false)
+            .coordinates(false)
+            .rating_bar(None)
+            .perspective(Color::White)
+            .to_string();
+        assert_eq!(
+            out.as_str(),
+            "+--------+
+|rnbqkbnr|
+|pppppppp|
+|#.#.#.#.| Both sides have equal material
+|.#.#.#.#| Black to move
+|#.#.P.#.|
+|.#.#.#.#|
+|PPPP#PPP|
+|RNBQKBNR|
++--------+
+"
+        );
+        let out: String = board
+            .display()
+            .ascii(true)
+            .ansi(false)
+            .rating_bar(Some(1))
+            .to_string();
+        assert!(out.starts_with("   hgfedcba\n  +--------+\n1 |RNBKQBNR|\n"));
+        assert!(out.contains("\n4 |#.#P#.#.| Black to move\n"));
+    }
+
+    #[test]
+    fn display_highlights() {
+        let board: Board = Board::default();
+        let out: String = board
+            .display()
+            .rating_bar(None)
+            .ascii(true)
+            .highlight_threats(false)
+            .highlight_squares(&[E2, E4])
+            .to_string();
+        assert_eq!(out.matches("\x1b[43m").count(), 2);
+        assert_eq!(out.matches('\x1b').count(), 4);
+        // Threats
+        let out: String = board.display().ascii(true).rating_bar(None).to_string();
+        assert!(out.contains("\x1b[32m"));
+        assert!(out.contains("\x1b[31m"));
+        // No highlight without ANSI
+        let out: String = board
+            .display()
+            .rating_bar(None)
+            .ansi(false)
+            .highlight_squares(&[E2, E4])
+            .to_string();
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn display_layout() {
+        let out: String = Board::default()
+            .display()
+            .ascii(true)
+            .ansi(false)
+            .rating_bar(None)
+            .borders(false)
+            .details(false)
+            .spacing(true)
+            .shade_squares(false)
+            .to_string();
+        assert_eq!(
+            out.as_str(),
+            Board::default()
+                .render(RenderOptions::default().unicode(false))
+                .as_str()
+        );
+        let out: String = Board::default()
+            .display()
+            .ascii(true)
+            .ansi(false)
+            .rating_bar(None)
+            .coordinates(false)
+            .spacing(true)
+            .to_string();
+        assert!(out.starts_with("+--------+\n|r n b q k b n r|\n"));
+        assert!(out.contains("\n|# . # . # . # .| Both sides have equal material\n"));
+        // Check
+        let board: Board = Board::from_fen("4k3/8/8/8/8/8/8/4R1K1 b - -").ok().unwrap();
+        let out: String = board
+            .display()
+            .ascii(true)
+            .highlight_threats(false)
+            .rating_bar(None)
+            .to_string();
+        assert!(!out.contains('\x1b'));
+        let out: String = board
+            .display()
+            .ascii(true)
+            .highlight_threats(false)
+            .highlight_check(true)
+            .rating_bar(None)
+            .to_string();
+        assert!(out.contains("\x1b[41mk\x1b[0m"));
+    }
+
+    #[test]
+    fn render_ascii() {
+        let mut buf: [u8; 128] = [0; 128];
+        let len: usize = Board::default().render_ascii(&mut buf, false).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &b"rnbqkbnr\npppppppp\n........\n........\n........\n........\nPPPPPPPP\nRNBQKBNR\n"[..]
+        );
+        let len: usize = Board::default().render_ascii(&mut buf, true).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &b"8 rnbqkbnr\n7 pppppppp\n6 ........\n5 ........\n4 ........\n3 ........\n2 PPPPPPPP\n1 RNBQKBNR\n  abcdefgh\n"[..]
+        );
+        // Buffer too small
+        let mut buf: [u8; 80] = [0; 80];
+        assert_eq!(Board::default().render_ascii(&mut buf, false), Ok(72));
+        assert_eq!(
+            Board::default().render_ascii(&mut buf, true),
+            Err(RenderError::BufferTooSmall(99))
+        );
+        assert_eq!(
+            Board::default().render_ascii(&mut buf[..71], false),
+            Err(RenderError::BufferTooSmall(72))
+        );
+    }
+
+    #[test]
+    fn render_default() {
+        let out: String = Board::default().render(RenderOptions::default());
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "8 ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜");
+        assert_eq!(lines[4], "4 · · · · · · · ·");
+        assert_eq!(lines[8], "  a b c d e f g h");
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn render_ascii_without_coordinates() {
+        let out: String =
+            Board::default().render(RenderOptions::default().unicode(false).coordinates(false));
+        assert_eq!(
+            out.as_str(),
+            "r n b q k b n r\np p p p p p p p\n. . . . . . . .\n. . . . . . . .\n. . . . . . . .\n. . . . . . . .\nP P P P P P P P\nR N B Q K B N R\n"
+        );
+    }
+
+    #[test]
+    fn render_black_perspective() {
+        let out: String = Board::default().render(
+            RenderOptions::default()
+                .unicode(false)
+                .perspective(Color::Black),
+        );
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "1 R N B K Q B N R");
+        assert_eq!(lines[7], "8 r n b k q b n r");
+        assert_eq!(lines[8], "  h g f e d c b a");
+    }
+
+    #[test]
+    fn render_highlights() {
+        let board: Board = Board::default()
+            .apply_move(Move::Piece(E2, E4))
+            .change_turn();
+        let opts: RenderOptions = RenderOptions::default()
+            .unicode(false)
+            .last_move(Some(Move::Piece(E2, E4)));
+        // No highlight without ANSI
+        assert!(!board.render(opts).contains('\x1b'));
+        let out: String = board.render(opts.ansi(true));
+        assert_eq!(out.matches(ANSI_LAST_MOVE).count(), 2);
+        assert_eq!(out.matches(ANSI_CHECK).count(), 0);
+        assert!(out.contains("\x1b[43mP\x1b[0m"));
+        // Check
+        let board: Board = Board::from_fen("4k3/8/8/8/8/8/8/4R1K1 b - -").ok().unwrap();
+        let opts: RenderOptions = RenderOptions::default().unicode(false).ansi(true);
+        assert!(board.render(opts).contains("\x1b[41mk\x1b[0m"));
+        assert!(!board.render(opts.highlight_check(false)).contains('\x1b'));
+        // Castling
+        let board: Board = Board::from_fen("4k3/8/8/8/8/8/8/5RK1 b - -").ok().unwrap();
+        let out: String = board.render(opts.last_move(Some(Move::KingSideCastle)));
+        assert!(out.contains("\x1b[43m.\x1b[0m"));
+        assert!(out.contains("\x1b[43mK\x1b[0m"));
+    }
+}
@@ -0,0 +1,194 @@
+//! # Repetition
+//!
+//! This module exposes the `RepetitionTracker`, which keeps the history of the positions reached on a `Board`,
+//! so repetitions can be detected without a `Game`
+
+use super::{Board, PositionKey};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// ## RepetitionTracker
+///
+/// Keeps the history of the positions reached during a game.
+/// Since `Board` is `Copy`, the history can't be stored in the board itself, so the tracker must be
+/// updated by the caller with `push` every time a move is played
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepetitionTracker {
+    positions: Vec<PositionKey>,
+}
+
+impl RepetitionTracker {
+    /// ### new
+    ///
+    /// Instantiate a new tracker, starting from the provided board
+    pub fn new(board: &Board) -> Self {
+        Self {
+            positions: vec![board.position_key()],
+        }
+    }
+
+    /// ### push
+    ///
+    /// Track the position reached after a move
+    pub fn push(&mut self, board: &Board) {
+        self.positions.push(board.position_key());
+    }
+
+    /// ### len
+    ///
+    /// Get the amount of tracked positions
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// ### is_empty
+    ///
+    /// Returns whether no position is tracked
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// ### repetitions
+    ///
+    /// Count the times the position of `board` has occurred
+    pub fn repetitions(&self, board: &Board) -> usize {
+        let key: PositionKey = board.position_key();
+        self.positions.iter().filter(|x| **x == key).count()
+    }
+
+    /// ### is_threefold_repetition
+    ///
+    /// Returns whether the last tracked position has occurred at least 3 times
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.is_n_repetition(3)
+    }
+
+    /// ### is_fivefold_repetition
+    ///
+    /// Returns whether the last tracked position has occurred at least 5 times
+    pub fn is_fivefold_repetition(&self) -> bool {
+        self.is_n_repetition(5)
+    }
+
+    /// ### is_n_repetition
+    ///
+    /// Returns whether the last tracked position has occurred at least `n` times
+    fn is_n_repetition(&self, n: usize) -> bool {
+        match self.positions.last() {
+            Some(last) => self.positions.iter().filter(|x| *x == last).count() >= n,
+            None => false,
+        }
+    }
+}
+
+impl Board {
+    /// ### is_threefold_repetition
+    ///
+    /// Returns whether the position of the board has occurred at least 3 times in the history kept by `tracker`
+    /// (see `repetition_count`)
+    pub fn is_threefold_repetition(&self, tracker: &RepetitionTracker) -> bool {
+        self.repetition_count(tracker) >= 3
+    }
+
+    /// ### repetition_count
+    ///
+    /// Get how many times the position of the board has occurred in the history kept by `tracker`.
+    /// The board must be pushed to `tracker` before, so the count is 1 for the first occurrence;
+    /// a position which has never been pushed counts 0
+    pub fn repetition_count(&self, tracker: &RepetitionTracker) -> usize {
+        tracker.repetitions(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{Move, MoveResult};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn threefold_repetition() {
+        let mut board: Board = Board::default();
+        let mut tracker: RepetitionTracker = RepetitionTracker::new(&board);
+        assert_eq!(tracker.len(), 1);
+        assert_eq!(tracker.is_empty(), false);
+        // Knights go back and forth twice
+        let moves: [Move; 8] = [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ];
+        for (i, m) in moves.iter().enumerate() {
+            assert_eq!(tracker.is_threefold_repetition(), false);
+            assert_eq!(board.repetition_count(&tracker), i / 4 + 1);
+            assert_eq!(board.is_threefold_repetition(&tracker), false);
+            board = match board.play_move(*m) {
+                MoveResult::Continuing(board) => board,
+                _ => panic!("Unexpected move result at {}", i),
+            };
+            tracker.push(&board);
+        }
+        assert_eq!(tracker.len(), 9);
+        assert_eq!(tracker.repetitions(&board), 3);
+        assert_eq!(tracker.is_threefold_repetition(), true);
+        assert_eq!(tracker.is_fivefold_repetition(), false);
+        assert_eq!(board.is_threefold_repetition(&tracker), true);
+        // Position after Nf3 occurred twice
+        let board: Board = board.apply_move(Move::Piece(G1, F3)).change_turn();
+        assert_eq!(tracker.repetitions(&board), 2);
+        assert_eq!(board.repetition_count(&tracker), 2);
+        assert_eq!(board.is_threefold_repetition(&tracker), false);
+    }
+
+    #[test]
+    fn repetition_count() {
+        let board: Board = Board::default();
+        assert_eq!(board.repetition_count(&RepetitionTracker::new(&board)), 1);
+        // Rooks shuffle back and forth: the counts of the pushed positions increase
+        let board: Board = Board::from_fen("r3k3/8/8/8/8/8/8/4K2R w - -").ok().unwrap();
+        let mut tracker: RepetitionTracker = RepetitionTracker::new(&board);
+        let mut board: Board = board;
+        let mut counts: Vec<usize> = Vec::new();
+        for m in [
+            Move::Piece(H1, H2),
+            Move::Piece(A8, A7),
+            Move::Piece(H2, H1),
+            Move::Piece(A7, A8),
+        ]
+        .iter()
+        .cycle()
+        .take(12)
+        {
+            board = board.apply_move(*m).change_turn();
+            let before: usize = board.repetition_count(&tracker);
+            tracker.push(&board);
+            counts.push(board.repetition_count(&tracker));
+            assert_eq!(*counts.last().unwrap(), before + 1);
+            // Both methods count on the same history
+            assert_eq!(
+                board.is_threefold_repetition(&tracker),
+                board.repetition_count(&tracker) >= 3
+            );
+        }
+        assert_eq!(counts, vec![1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4]);
+        // Earlier positions are not overcounted
+        let board: Board = Board::from_fen("r3k3/8/8/8/8/8/8/4K2R w - -").ok().unwrap();
+        assert_eq!(board.repetition_count(&tracker), 4);
+        let board: Board = board.apply_move(Move::Piece(H1, H2)).change_turn();
+        assert_eq!(board.repetition_count(&tracker), 3);
+        assert_eq!(board.is_threefold_repetition(&tracker), true);
+        // Never reached
+        let board: Board = board.apply_move(Move::Piece(A8, A1)).change_turn();
+        assert_eq!(board.repetition_count(&tracker), 0);
+        assert_eq!(board.is_threefold_repetition(&tracker), false);
+    }
+}
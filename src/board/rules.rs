@@ -0,0 +1,341 @@
+//! # Rules
+//!
+//! This module exposes the `VariantRules` trait, which describes how a chess variant changes the rules of
+//! standard chess, and the rules of the variants supported by the `Board`.
+//! Since the board is `Copy`, the rules in use are stored as the `Rules` enum, which dispatches the hooks
+//! to the rules of the variant.
+
+use super::{Board, Color, Move, BLACK, WHITE};
+
+use alloc::vec::Vec;
+
+/// ## VariantRules
+///
+/// The hooks a chess variant can use to change the rules of standard chess.
+/// Each hook has the standard chess behaviour as default implementation, except for `pawn_double_step_ranks`
+pub trait VariantRules {
+    /// ### extra_victory
+    ///
+    /// Returns the winner, if the variant grants the victory to a player in the provided position
+    /// (besides checkmate). `last_move` is the move which led to the position, if known
+    fn extra_victory(&self, _board: &Board, _last_move: Option<Move>) -> Option<Color> {
+        None
+    }
+
+    /// ### filter_legal_moves
+    ///
+    /// Remove from the legal moves of `color` the moves forbidden by the variant.
+    /// Checkmate and stalemate detection (`Board::has_any_legal_move`) doesn't apply the filter,
+    /// so it must never remove all the legal moves
+    fn filter_legal_moves(&self, _board: &Board, _color: Color, _moves: &mut Vec<Move>) {}
+
+    /// ### allow_castling
+    ///
+    /// Returns whether players can castle
+    fn allow_castling(&self) -> bool {
+        true
+    }
+
+    /// ### allow_drops
+    ///
+    /// Returns whether players can drop the pieces of their reserve on the board
+    fn allow_drops(&self) -> bool {
+        false
+    }
+
+    /// ### count_checks
+    ///
+    /// Returns whether the checks delivered by each player are counted
+    fn count_checks(&self) -> bool {
+        false
+    }
+
+    /// ### insufficient_material_draws
+    ///
+    /// Returns whether the game is drawn when both players have insufficient material
+    fn insufficient_material_draws(&self) -> bool {
+        true
+    }
+
+    /// ### requires_king
+    ///
+    /// Returns whether `color` must have a king on the board
+    fn requires_king(&self, _color: Color) -> bool {
+        true
+    }
+
+    /// ### pawn_double_step_ranks
+    ///
+    /// Get the rows (0-7) where the pawns of `color` can advance two squares
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32];
+}
+
+/// ## StandardRules
+///
+/// The rules of standard chess
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StandardRules;
+
+impl VariantRules for StandardRules {
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32] {
+        standard_double_step_ranks(color)
+    }
+}
+
+/// ## HordeRules
+///
+/// The rules of Horde chess:
+///
+/// - white pawns on the first rank can advance two squares (no en passant is possible after this move)
+/// - black wins when white has no pieces left, instead of stalemate
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HordeRules;
+
+impl VariantRules for HordeRules {
+    fn extra_victory(&self, board: &Board, _last_move: Option<Move>) -> Option<Color> {
+        match board.get_player_pieces(WHITE).is_empty() {
+            true => Some(BLACK),
+            false => None,
+        }
+    }
+
+    fn requires_king(&self, color: Color) -> bool {
+        color == BLACK
+    }
+
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32] {
+        match color {
+            WHITE => &[0, 1],
+            BLACK => standard_double_step_ranks(color),
+        }
+    }
+}
+
+/// ## ThreeCheckRules
+///
+/// The rules of Three-check chess: the checks delivered by each player are counted and the player who delivers
+/// the third check wins
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreeCheckRules;
+
+impl VariantRules for ThreeCheckRules {
+    fn extra_victory(&self, board: &Board, _last_move: Option<Move>) -> Option<Color> {
+        Color::both()
+            .iter()
+            .copied()
+            .find(|color| board.get_checks(*color) >= 3)
+    }
+
+    fn count_checks(&self) -> bool {
+        true
+    }
+
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32] {
+        standard_double_step_ranks(color)
+    }
+}
+
+/// ## CrazyhouseRules
+///
+/// The rules of Crazyhouse: captured pieces go to the reserve of the capturer and can be dropped back on the board,
+/// so the game can't be drawn by insufficient material
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CrazyhouseRules;
+
+impl VariantRules for CrazyhouseRules {
+    fn allow_drops(&self) -> bool {
+        true
+    }
+
+    fn insufficient_material_draws(&self) -> bool {
+        false
+    }
+
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32] {
+        standard_double_step_ranks(color)
+    }
+}
+
+/// ## Rules
+///
+/// The rules in use on a `Board`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rules {
+    /// Standard chess (also used by the variants which only change the starting position)
+    #[default]
+    Standard,
+    /// Horde chess
+    Horde,
+    /// Three-check chess
+    ThreeCheck,
+    /// Crazyhouse
+    Crazyhouse,
+}
+
+/// Call a hook on the rules of the variant
+macro_rules! dispatch {
+    ($rules:expr, $r:ident => $call:expr) => {
+        match $rules {
+            Rules::Standard => {
+                let $r = StandardRules;
+                $call
+            }
+            Rules::Horde => {
+                let $r = HordeRules;
+                $call
+            }
+            Rules::ThreeCheck => {
+                let $r = ThreeCheckRules;
+                $call
+            }
+            Rules::Crazyhouse => {
+                let $r = CrazyhouseRules;
+                $call
+            }
+        }
+    };
+}
+
+impl VariantRules for Rules {
+    fn extra_victory(&self, board: &Board, last_move: Option<Move>) -> Option<Color> {
+        dispatch!(self, r => r.extra_victory(board, last_move))
+    }
+
+    fn filter_legal_moves(&self, board: &Board, color: Color, moves: &mut Vec<Move>) {
+        dispatch!(self, r => r.filter_legal_moves(board, color, moves))
+    }
+
+    fn allow_castling(&self) -> bool {
+        dispatch!(self, r => r.allow_castling())
+    }
+
+    fn allow_drops(&self) -> bool {
+        dispatch!(self, r => r.allow_drops())
+    }
+
+    fn count_checks(&self) -> bool {
+        dispatch!(self, r => r.count_checks())
+    }
+
+    fn insufficient_material_draws(&self) -> bool {
+        dispatch!(self, r => r.insufficient_material_draws())
+    }
+
+    fn requires_king(&self, color: Color) -> bool {
+        dispatch!(self, r => r.requires_king(color))
+    }
+
+    fn pawn_double_step_ranks(&self, color: Color) -> &'static [i32] {
+        dispatch!(self, r => r.pawn_double_step_ranks(color))
+    }
+}
+
+/// ### standard_double_step_ranks
+///
+/// Get the rank where the pawns of `color` start in standard chess
+fn standard_double_step_ranks(color: Color) -> &'static [i32] {
+    match color {
+        WHITE => &[1],
+        BLACK => &[6],
+    }
+}
+
+impl Board {
+    /// ### get_rules
+    ///
+    /// Get the rules in use on the board
+    pub fn get_rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// ### with_rules
+    ///
+    /// Get a new board using the provided rules.
+    /// The rules of different variants can't be combined, so enabling the rules of a variant
+    /// (e.g. with `with_horde_rules`) replaces the rules in use
+    pub fn with_rules(&self, rules: Rules) -> Self {
+        let mut result = *self;
+        result.rules = rules;
+        result
+    }
+
+    /// ### with_variant_flag
+    ///
+    /// Enable or disable the provided rules. Disabling rules which are not in use does nothing
+    pub(crate) fn with_variant_flag(&self, rules: Rules, enabled: bool) -> Self {
+        match (enabled, self.rules == rules) {
+            (true, _) => self.with_rules(rules),
+            (false, true) => self.with_rules(Rules::Standard),
+            (false, false) => *self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{BoardBuilder, Piece};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn rules() {
+        assert_eq!(Board::default().get_rules(), Rules::Standard);
+        assert_eq!(Board::horde().get_rules(), Rules::Horde);
+        assert_eq!(Board::three_check().get_rules(), Rules::ThreeCheck);
+        assert_eq!(Board::crazyhouse().get_rules(), Rules::Crazyhouse);
+        assert_eq!(Board::dunsany().get_rules(), Rules::Standard);
+        let board: Board = Board::default().with_rules(Rules::Horde);
+        assert_eq!(board.is_horde(), true);
+        // Disabling other rules does nothing
+        assert_eq!(board.with_three_check_rules(false).is_horde(), true);
+        assert_eq!(board.with_horde_rules(false).get_rules(), Rules::Standard);
+        assert_eq!(
+            board.with_crazyhouse_rules(true).get_rules(),
+            Rules::Crazyhouse
+        );
+    }
+
+    #[test]
+    fn hooks() {
+        for rules in [
+            Rules::Standard,
+            Rules::Horde,
+            Rules::ThreeCheck,
+            Rules::Crazyhouse,
+        ]
+        .iter()
+        {
+            assert_eq!(rules.allow_castling(), true);
+            assert_eq!(rules.pawn_double_step_ranks(BLACK), &[6]);
+        }
+        assert_eq!(Rules::Standard.pawn_double_step_ranks(WHITE), &[1]);
+        assert_eq!(Rules::Horde.pawn_double_step_ranks(WHITE), &[0, 1]);
+        assert_eq!(Rules::Crazyhouse.allow_drops(), true);
+        assert_eq!(Rules::Standard.allow_drops(), false);
+        assert_eq!(Rules::Crazyhouse.insufficient_material_draws(), false);
+        assert_eq!(Rules::ThreeCheck.count_checks(), true);
+        assert_eq!(Rules::Horde.count_checks(), false);
+        assert_eq!(Rules::Standard.requires_king(WHITE), true);
+        assert_eq!(Rules::Horde.requires_king(WHITE), false);
+        assert_eq!(Rules::Horde.requires_king(BLACK), true);
+        // Extra victory
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(BLACK, E8))
+            .build()
+            .with_rules(Rules::Horde);
+        assert_eq!(Rules::Horde.extra_victory(&board, None), Some(BLACK));
+        assert_eq!(Rules::Standard.extra_victory(&board, None), None);
+        let board: Board = Board::three_check().with_checks(WHITE, 3);
+        assert_eq!(
+            Rules::ThreeCheck.extra_victory(&board, Some(Move::Piece(E2, E4))),
+            Some(WHITE)
+        );
+        assert_eq!(
+            Rules::ThreeCheck.extra_victory(&Board::three_check(), None),
+            None
+        );
+    }
+}
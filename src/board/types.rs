@@ -2,7 +2,7 @@
 //!
 //! This module exposes different kind of types for `Board`
 
-use super::{Board, Color, Move, Position};
+use super::{Board, CastlingRights, Color, Move, Position, Reserve, Square};
 
 /// ## RatedMove
 ///
@@ -12,7 +12,7 @@ pub type RatedMove = (Move, f64);
 /// ## Promotion
 ///
 /// Defines the kind of promotion to perform whenever a pawn reaches the last line
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Promotion {
     Queen,
     Knight,
@@ -20,6 +20,81 @@ pub enum Promotion {
     Rook,
 }
 
+/// ## GameStatus
+///
+/// Describes the status of the game for the player to move, as returned by `Board::game_status()` and `Game::status()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GameStatus {
+    /// The game is still in play and the player to move is not in check
+    InProgress,
+    /// The game is still in play and the player to move is in check
+    Check,
+    /// The player to move has been checkmated. This stores the color of the winner
+    Checkmate(Color),
+    /// The game has been won through a victory condition of the variant being played
+    /// (e.g. three checks in Three-check, all the white pieces captured in Horde). This stores the color of the winner
+    VariantVictory(Color),
+    /// The player to move has no legal moves and is not in check
+    Stalemate,
+    /// Neither player can checkmate with the material left on the board
+    InsufficientMaterial,
+    /// Fifty moves have been played by each player without captures or pawn moves.
+    /// NOTE: this cannot be detected by `Board`. Only `Game` reports it
+    FiftyMove,
+    /// The current position has occurred at least as many times as the repetition threshold.
+    /// This stores the times the position has occurred.
+    /// NOTE: this cannot be detected by `Board`. Only `Game` reports it
+    Repetition(usize),
+}
+
+impl GameStatus {
+    /// ### is_over
+    ///
+    /// Returns whether the game has ended on the board (checkmate, variant victory, stalemate or insufficient material).
+    /// Fifty-move rule and repetitions are not considered, since, depending on the game options, they may just allow the
+    /// players to claim a draw
+    pub fn is_over(&self) -> bool {
+        matches!(
+            self,
+            GameStatus::Checkmate(_)
+                | GameStatus::VariantVictory(_)
+                | GameStatus::Stalemate
+                | GameStatus::InsufficientMaterial
+        )
+    }
+}
+
+/// ## PositionKey
+///
+/// Identifies a position on the board: pieces placement, player to move, castling rights, en passant square
+/// (only if the en passant capture is possible) and, in Crazyhouse, the reserves.
+/// Unlike `Board`, two keys are equal if they describe the same position, no matter of what happened on the last turn,
+/// so it can be used as key in hash maps and sets (e.g. for repetitions and transpositions)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PositionKey {
+    squares: [Square; 64],
+    turn: Color,
+    white_castling_rights: CastlingRights,
+    black_castling_rights: CastlingRights,
+    en_passant: Option<Position>,
+    white_reserve: Reserve,
+    black_reserve: Reserve,
+}
+
+impl From<&Board> for PositionKey {
+    fn from(board: &Board) -> Self {
+        Self {
+            squares: board.squares,
+            turn: board.turn,
+            white_castling_rights: board.white_castling_rights,
+            black_castling_rights: board.black_castling_rights,
+            en_passant: board.capturable_en_passant(),
+            white_reserve: board.white_reserve,
+            black_reserve: board.black_reserve,
+        }
+    }
+}
+
 /// ## MoveResult
 ///
 /// Describes the result of a move being played on the board.
@@ -0,0 +1,208 @@
+//! # UCI
+//!
+//! This module exposes the parser for the moves in UCI (long algebraic) notation, such as `e2e4`, `e1g1` or `e7e8q`,
+//! and drops (`N@f3`) for Crazyhouse.
+//! <https://www.chessprogramming.org/UCI>
+
+use super::{Board, Move, Piece, PieceKind, Position, Promotion};
+
+use core::str::FromStr;
+
+/// ## UciError
+///
+/// Describes an error while playing a list of UCI moves.
+/// Each variant contains the index of the move which caused the error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UciError {
+    /// The move is not in UCI notation
+    BadMove(usize),
+    /// The move is not legal in the current position
+    IllegalMove(usize),
+}
+
+impl core::fmt::Display for UciError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadMove(index) => write!(f, "Bad UCI move at index {}", index),
+            Self::IllegalMove(index) => write!(f, "Illegal move at index {}", index),
+        }
+    }
+}
+
+impl Board {
+    /// ### parse_uci_move
+    ///
+    /// Parse a move in UCI notation against the current position.
+    /// King moves of two squares from the starting square are converted to castles; drops are written as `N@f3`.
+    /// Returns the move and the promotion, if any; returns `None` if the move is not in UCI notation.
+    /// The legality of the move is not checked
+    pub fn parse_uci_move(&self, token: &str) -> Option<(Move, Option<Promotion>)> {
+        if !token.is_ascii() || (token.len() != 4 && token.len() != 5) {
+            return None;
+        }
+        if token.len() == 4 && &token[1..2] == "@" {
+            let kind: PieceKind = token.chars().next().and_then(PieceKind::from_ascii_char)?;
+            return Some((
+                Move::Drop(kind, Position::from_str(&token[2..4]).ok()?),
+                None,
+            ));
+        }
+        let from: Position = Position::from_str(&token[0..2]).ok()?;
+        let to: Position = Position::from_str(&token[2..4]).ok()?;
+        let promotion: Option<Promotion> = match token.get(4..5) {
+            None => None,
+            Some("q") => Some(Promotion::Queen),
+            Some("r") => Some(Promotion::Rook),
+            Some("b") => Some(Promotion::Bishop),
+            Some("n") => Some(Promotion::Knight),
+            Some(_) => return None,
+        };
+        let m: Move = match self.get_piece(from) {
+            Some(Piece::King(color, _))
+                if from == Position::king_pos(color) && from.get_row() == to.get_row() =>
+            {
+                match to.get_col() - from.get_col() {
+                    2 => Move::KingSideCastle,
+                    -2 => Move::QueenSideCastle,
+                    _ => Move::Piece(from, to),
+                }
+            }
+            _ => Move::Piece(from, to),
+        };
+        Some((m, promotion))
+    }
+
+    /// ### play_uci_moves
+    ///
+    /// Play a list of moves in UCI notation, starting from the current position, and return the reached position.
+    /// Promotions must be provided as move suffix (e.g. `e7e8q`).
+    /// This is what a UCI front-end needs to handle the `position ... moves ...` command
+    pub fn play_uci_moves(&self, moves: &[&str]) -> Result<Board, UciError> {
+        let mut board: Board = *self;
+        for (i, token) in moves.iter().enumerate() {
+            let (m, promotion) = board.parse_uci_move(token).ok_or(UciError::BadMove(i))?;
+            if board.is_checkmate()
+                || board.is_stalemate()
+                || !board.is_legal_move(m, board.get_turn())
+            {
+                return Err(UciError::IllegalMove(i));
+            }
+            let promoting: bool = board
+                .apply_move(m)
+                .check_available_pawn_promotion()
+                .promotion
+                .is_some();
+            if promoting != promotion.is_some() {
+                return Err(UciError::IllegalMove(i));
+            }
+            board = board.replay_move(m, promotion);
+        }
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::Color;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_uci_move() {
+        let board: Board = Board::default();
+        assert_eq!(
+            board.parse_uci_move("e2e4"),
+            Some((Move::Piece(E2, E4), None))
+        );
+        assert_eq!(
+            board.parse_uci_move("e7e8q"),
+            Some((Move::Piece(E7, E8), Some(Promotion::Queen)))
+        );
+        assert_eq!(
+            board.parse_uci_move("a2a1n"),
+            Some((Move::Piece(A2, A1), Some(Promotion::Knight)))
+        );
+        assert_eq!(
+            board.parse_uci_move("e1g1"),
+            Some((Move::KingSideCastle, None))
+        );
+        assert_eq!(
+            board.parse_uci_move("e8c8"),
+            Some((Move::QueenSideCastle, None))
+        );
+        // Not a king
+        assert_eq!(
+            board.parse_uci_move("d1f1"),
+            Some((Move::Piece(D1, F1), None))
+        );
+        // Drops
+        assert_eq!(
+            board.parse_uci_move("N@f3"),
+            Some((Move::Drop(PieceKind::Knight, F3), None))
+        );
+        assert_eq!(board.parse_uci_move("X@f3"), None);
+        assert_eq!(board.parse_uci_move("N@f9"), None);
+        assert_eq!(board.parse_uci_move("e2e4x"), None);
+        assert_eq!(board.parse_uci_move("e2e9"), None);
+        assert_eq!(board.parse_uci_move("e2"), None);
+        assert_eq!(board.parse_uci_move("e2e4q1"), None);
+        assert_eq!(board.parse_uci_move("è2e4"), None);
+    }
+
+    #[test]
+    fn play_uci_moves() {
+        let board: Board = Board::default()
+            .play_uci_moves(&["e2e4", "e7e5", "g1f3", "b8c6", "f1c4", "g8f6", "e1g1"])
+            .ok()
+            .unwrap();
+        assert_eq!(
+            board.to_fen().as_str(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 0 1"
+        );
+        // Promotion
+        let board: Board = Board::from_fen("8/4P1k1/8/8/8/8/8/4K3 w - -").ok().unwrap();
+        assert_eq!(
+            board.play_uci_moves(&["e7e8n"]).ok().unwrap().get_piece(E8),
+            Some(Piece::Knight(Color::White, E8))
+        );
+        assert_eq!(
+            board.play_uci_moves(&["e7e8"]).err().unwrap(),
+            UciError::IllegalMove(0)
+        );
+        // Errors
+        assert_eq!(
+            Board::default()
+                .play_uci_moves(&["e2e4", "e7e4"])
+                .err()
+                .unwrap(),
+            UciError::IllegalMove(1)
+        );
+        assert_eq!(
+            Board::default()
+                .play_uci_moves(&["e2e4", "e7"])
+                .err()
+                .unwrap(),
+            UciError::BadMove(1)
+        );
+        assert_eq!(
+            Board::default().play_uci_moves(&["e2e3q"]).err().unwrap(),
+            UciError::IllegalMove(0)
+        );
+        // No moves after checkmate
+        assert_eq!(
+            Board::default()
+                .play_uci_moves(&["f2f3", "e7e5", "g2g4", "d8h4", "a2a3"])
+                .err()
+                .unwrap(),
+            UciError::IllegalMove(4)
+        );
+        assert_eq!(
+            UciError::BadMove(3).to_string().as_str(),
+            "Bad UCI move at index 3"
+        );
+    }
+}
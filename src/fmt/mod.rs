@@ -1,2 +1,9 @@
+//! # Fmt
+//!
+//! This module exposes the parsers for the textual notations of the moves
+
 // TODO: make a trait to parse and get Board
 // TODO: make a trait to serialize and get notation
+
+mod pgn;
+pub use pgn::MoveParseError;
@@ -4,11 +4,43 @@
 
 // TODO: time uses TimeControl ?
 
+use crate::{Move, ParsePositionError, Position};
+
 use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
 use core::convert::TryFrom;
+use core::str::FromStr;
+
+/// ## MoveParseError
+///
+/// Describes why a move couldn't be parsed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MoveParseError {
+    /// The move is not in any of the supported formats
+    BadFormat,
+    /// One of the squares of the move is not a valid position
+    BadPosition(ParsePositionError),
+    /// The move is not legal in the position, or it's ambiguous
+    IllegalMove,
+}
+
+impl From<ParsePositionError> for MoveParseError {
+    fn from(err: ParsePositionError) -> Self {
+        Self::BadPosition(err)
+    }
+}
+
+impl core::fmt::Display for MoveParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadFormat => write!(f, "Invalid move format"),
+            Self::BadPosition(err) => write!(f, "Invalid position: {}", err),
+            Self::IllegalMove => write!(f, "Illegal or ambiguous move"),
+        }
+    }
+}
 
 /// Try to parse a Move from a string.
 ///
@@ -29,7 +61,7 @@ use core::convert::TryFrom;
 ///
 /// Parsing a move such as `"knight to e4"` or `"Qxe4"` will NOT work.
 impl TryFrom<String> for Move {
-    type Error = String;
+    type Error = MoveParseError;
 
     fn try_from(repr: String) -> Result<Self, Self::Error> {
         let repr = repr.trim().to_string();
@@ -43,19 +75,96 @@ impl TryFrom<String> for Move {
             other => {
                 let words = other.split_whitespace().collect::<Vec<&str>>();
 
-                if words.len() == 1 && words[0].len() == 4 {
+                if words.len() == 1 && words[0].len() == 4 && words[0].is_ascii() {
                     Self::Piece(
-                        Position::pgn(&words[0][..2])?,
-                        Position::pgn(&words[0][2..4])?,
+                        parse_position(&words[0][..2])?,
+                        parse_position(&words[0][2..4])?,
                     )
                 } else if words.len() == 2 {
-                    Self::Piece(Position::pgn(&words[0])?, Position::pgn(&words[1])?)
+                    Self::Piece(parse_position(words[0])?, parse_position(words[1])?)
                 } else if words.len() == 3 && words[1] == "to" {
-                    Self::Piece(Position::pgn(&words[0])?, Position::pgn(&words[2])?)
+                    Self::Piece(parse_position(words[0])?, parse_position(words[2])?)
                 } else {
-                    return Err(format!("invalid move format `{}`", other));
+                    return Err(MoveParseError::BadFormat);
                 }
             }
         })
     }
 }
+
+/// Parse a move from a string, in any of the formats accepted by `TryFrom<String>`
+/// (e.g. `"e2e4".parse::<Move>()`)
+impl FromStr for Move {
+    type Err = MoveParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
+    }
+}
+
+/// ### parse_position
+///
+/// Parse a square of a move (e.g. `e4`)
+fn parse_position(repr: &str) -> Result<Position, MoveParseError> {
+    Ok(Position::from_str(repr)?)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn move_from_str() {
+        assert_eq!("e2e4".parse::<Move>(), Ok(Move::Piece(E2, E4)));
+        assert_eq!("e2 e4".parse::<Move>(), Ok(Move::Piece(E2, E4)));
+        assert_eq!(" e2 to e4 ".parse::<Move>(), Ok(Move::Piece(E2, E4)));
+        assert_eq!("O-O".parse::<Move>(), Ok(Move::KingSideCastle));
+        assert_eq!("0-0".parse::<Move>(), Ok(Move::KingSideCastle));
+        assert_eq!("O-O-O".parse::<Move>(), Ok(Move::QueenSideCastle));
+        assert_eq!(
+            "castle queenside".parse::<Move>(),
+            Ok(Move::QueenSideCastle)
+        );
+        assert_eq!("resign".parse::<Move>(), Ok(Move::Resign));
+        assert_eq!("resigns".parse::<Move>(), Ok(Move::Resign));
+        assert_eq!(
+            Move::try_from(String::from("g1 f3")),
+            Ok(Move::Piece(G1, F3))
+        );
+        // Errors
+        assert_eq!(
+            "Qxe4".parse::<Move>(),
+            Err(MoveParseError::BadPosition(ParsePositionError::BadFile(
+                'Q'
+            )))
+        );
+        assert_eq!("Nf3".parse::<Move>(), Err(MoveParseError::BadFormat));
+        assert_eq!(
+            "knight to e4".parse::<Move>(),
+            Err(MoveParseError::BadPosition(ParsePositionError::BadLength))
+        );
+        assert_eq!(
+            "e2e9".parse::<Move>(),
+            Err(MoveParseError::BadPosition(ParsePositionError::BadRank(
+                '9'
+            )))
+        );
+        assert_eq!(
+            "i2 e4".parse::<Move>(),
+            Err(MoveParseError::BadPosition(ParsePositionError::BadFile(
+                'i'
+            )))
+        );
+        assert_eq!(
+            MoveParseError::BadPosition(ParsePositionError::BadRank('9'))
+                .to_string()
+                .as_str(),
+            "Invalid position: Invalid rank '9'"
+        );
+        assert_eq!("".parse::<Move>(), Err(MoveParseError::BadFormat));
+    }
+}
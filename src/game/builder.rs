@@ -2,10 +2,47 @@
 //!
 //! this module exposes a helper struct to build `Game` struct
 
-use super::{Board, Clock, Duration, Game, GameMove, Metadata, Options};
+use super::{
+    Board, Clock, Duration, Game, GameMove, Metadata, MetadataResult, Options, Variant,
+    VictoryReason,
+};
+use crate::{Color, Move, Odds, Rules};
 
 use alloc::vec::Vec;
 
+/// ## GameBuildError
+///
+/// Describes the consistency check which failed while building a `Game`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameBuildError {
+    /// The player has more than one king on the board
+    TooManyKings(Color),
+    /// The move at the provided index (half-move) of the history can't be replayed from the initial board
+    IllegalHistoryMove(usize),
+    /// The board obtained replaying the moves history differs from the game board
+    HistoryMismatch,
+    /// The result in the metadata is not compatible with the board state
+    ResultMismatch,
+    /// The player has no time left, but the game is still in progress
+    ClockExpired(Color),
+}
+
+impl core::fmt::Display for GameBuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::TooManyKings(color) => write!(f, "{} has more than one king", color),
+            Self::IllegalHistoryMove(ply) => {
+                write!(f, "Move at ply {} can't be replayed", ply)
+            }
+            Self::HistoryMismatch => write!(f, "Moves history doesn't lead to the board"),
+            Self::ResultMismatch => write!(f, "Result is not compatible with the board"),
+            Self::ClockExpired(color) => {
+                write!(f, "{} has no time left, but the game is in progress", color)
+            }
+        }
+    }
+}
+
 /// ## GameBuilder
 ///
 /// Helper struct to build `Game`
@@ -22,11 +59,71 @@ impl Default for GameBuilder {
 }
 
 impl GameBuilder {
+    /// ### horde
+    ///
+    /// Create a builder for a game of the Horde variant
+    pub fn horde() -> Self {
+        Self::default()
+            .board(Board::horde())
+            .variant(Variant::Horde)
+    }
+
+    /// ### dunsany
+    ///
+    /// Create a builder for a game of the Dunsany's chess variant
+    pub fn dunsany() -> Self {
+        Self::default()
+            .board(Board::dunsany())
+            .variant(Variant::Dunsany)
+    }
+
+    /// ### three_check
+    ///
+    /// Create a builder for a game of the Three-check variant
+    pub fn three_check() -> Self {
+        Self::default()
+            .board(Board::three_check())
+            .variant(Variant::ThreeCheck)
+    }
+
+    /// ### crazyhouse
+    ///
+    /// Create a builder for a game of the Crazyhouse variant
+    pub fn crazyhouse() -> Self {
+        Self::default()
+            .board(Board::crazyhouse())
+            .variant(Variant::Crazyhouse)
+    }
+
     /// ### board
     ///
-    /// Set board for game
+    /// Set board for game.
+    /// The board is also used as the initial board of the game; if you're restoring a game,
+    /// use `initial_board` to set the board the game started from.
     pub fn board(mut self, board: Board) -> Self {
-        self.game.as_mut().unwrap().board = board;
+        let game: &mut Game = self.game.as_mut().unwrap();
+        game.board = board;
+        game.initial_board = board;
+        self
+    }
+
+    /// ### initial_board
+    ///
+    /// Set the board the game started from.
+    /// Must be called after `board`, since `board` also sets the initial board.
+    pub fn initial_board(mut self, board: Board) -> Self {
+        self.game.as_mut().unwrap().initial_board = board;
+        self
+    }
+
+    /// ### odds
+    ///
+    /// Make `color` give the provided odds to the opponent, applying them to both the board and the initial board.
+    /// Must be called after `board`
+    pub fn odds(mut self, color: Color, odds: Odds) -> Self {
+        let game: &mut Game = self.game.as_mut().unwrap();
+        game.board = game.board.with_odds(color, odds);
+        game.initial_board = game.initial_board.with_odds(color, odds);
         self
     }
 
@@ -62,11 +159,191 @@ impl GameBuilder {
         self
     }
 
+    /// ### variant
+    ///
+    /// Set the chess variant played in the game
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.game.as_mut().unwrap().variant = variant;
+        self
+    }
+
+    /// ### victory_reason
+    ///
+    /// Set the reason which brought the winner to victory; the winner is taken from the metadata result.
+    /// If the game has been won and the reason is not set, it's restored from the board, the moves and the clock
+    /// when the game is built, falling back to `VictoryReason::Unknown`
+    pub fn victory_reason(mut self, reason: Option<VictoryReason>) -> Self {
+        self.game.as_mut().unwrap().victory_reason = reason;
+        self
+    }
+
     /// ### build
     ///
-    /// Take `Game` structure out from builder
+    /// Take `Game` structure out from builder.
+    /// The game is not validated, except for debug builds where the same consistency checks
+    /// of `try_build` are asserted; use `try_build` to validate user provided data
     pub fn build(mut self) -> Game {
-        self.game.take().unwrap()
+        let game: Game = self.take_game();
+        debug_assert_eq!(validate(&game), Ok(()));
+        game
+    }
+
+    /// ### try_build
+    ///
+    /// Take `Game` structure out from builder, checking that it's consistent:
+    ///
+    /// - in standard chess each player has at most one king
+    /// - the moves history, if any, can be replayed from the initial board and leads to the board
+    /// - the result is compatible with the board (e.g. a checkmated player can't win)
+    /// - the players have time left, if the game is still in progress
+    pub fn try_build(mut self) -> Result<Game, GameBuildError> {
+        let game: Game = self.take_game();
+        validate(&game)?;
+        Ok(game)
+    }
+
+    /// ### take_game
+    ///
+    /// Take `Game` structure out from builder, completing it with the variant rules, the positions reached
+    /// and the victory reason
+    fn take_game(&mut self) -> Game {
+        let game: Game = Self::with_variant_rules(self.game.take().unwrap());
+        let game: Game = Self::with_history(game);
+        Self::with_victory_reason(game)
+    }
+
+    /// ### with_variant_rules
+    ///
+    /// Enable the rules of the game variant on the boards
+    fn with_variant_rules(mut game: Game) -> Game {
+        let rules: Rules = game.variant.rules();
+        game.board = game.board.with_rules(rules);
+        game.initial_board = game.initial_board.with_rules(rules);
+        game
+    }
+
+    /// ### with_history
+    ///
+    /// Count the positions reached and the half-moves since the last pawn move or capture from the moves history
+    fn with_history(mut game: Game) -> Game {
+        game.track_history();
+        game
+    }
+
+    /// ### with_victory_reason
+    ///
+    /// Restore the reason of the victory, if the game has been won and the reason has not been set
+    fn with_victory_reason(mut game: Game) -> Game {
+        if let (None, Some(winner)) = (game.victory_reason, game.winner()) {
+            game.victory_reason = Some(game.restored_victory_reason(winner));
+        }
+        game
+    }
+}
+
+// -- validation
+
+/// ### validate
+///
+/// Check game consistency
+fn validate(game: &Game) -> Result<(), GameBuildError> {
+    if game.variant == Variant::Standard {
+        for color in Color::both().iter().copied() {
+            let kings: usize = game
+                .board
+                .get_player_pieces(color)
+                .iter()
+                .filter(|x| x.is_king())
+                .count();
+            if kings > 1 {
+                return Err(GameBuildError::TooManyKings(color));
+            }
+        }
+    }
+    validate_history(game)?;
+    validate_result(game)?;
+    if game.metadata.result() == MetadataResult::InProgress {
+        for color in Color::both().iter().copied() {
+            if game.clock.timeout(color) {
+                return Err(GameBuildError::ClockExpired(color));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// ### validate_history
+///
+/// Check whether the moves history can be replayed from the initial board and leads to the board
+fn validate_history(game: &Game) -> Result<(), GameBuildError> {
+    if game.moves.is_empty() {
+        return Ok(());
+    }
+    let mut board: Board = game.initial_board;
+    for (ply, m) in game.moves.iter().enumerate() {
+        let resigned: bool = m.itself == Move::Resign && ply + 1 == game.moves.len();
+        if m.player != board.get_turn() || (!resigned && !board.is_legal_move(m.itself, m.player)) {
+            return Err(GameBuildError::IllegalHistoryMove(ply));
+        }
+        board = board.replay_move(m.itself, m.promotion);
+    }
+    match board.same_position(&game.board) {
+        true => Ok(()),
+        false => Err(GameBuildError::HistoryMismatch),
+    }
+}
+
+/// ### validate_result
+///
+/// Check whether the result is compatible with the board state.
+/// A win is compatible only with a checkmate, a resignation, a timeout of the loser or a forfeit;
+/// a checkmate or a stalemate on the board must be reported in the result
+fn validate_result(game: &Game) -> Result<(), GameBuildError> {
+    let turn: Color = game.board.get_turn();
+    let winner: Option<Color> = match game.metadata.result() {
+        MetadataResult::WhiteWins => Some(Color::White),
+        MetadataResult::BlackWins => Some(Color::Black),
+        _ => None,
+    };
+    let compatible: bool = if game.board.is_horde_wiped_out() {
+        matches!(
+            game.metadata.result(),
+            MetadataResult::BlackWins | MetadataResult::Unknown | MetadataResult::Abandoned
+        )
+    } else if let Some(color) = Color::both()
+        .iter()
+        .copied()
+        .find(|x| game.board.has_three_checks(*x))
+    {
+        matches!(
+            game.metadata.result(),
+            MetadataResult::Unknown | MetadataResult::Abandoned
+        ) || winner == Some(color)
+    } else if game.board.is_checkmate() {
+        matches!(
+            game.metadata.result(),
+            MetadataResult::Unknown | MetadataResult::Abandoned
+        ) || winner == Some(!turn)
+    } else if game.board.is_stalemate() {
+        matches!(
+            game.metadata.result(),
+            MetadataResult::DrawnGame | MetadataResult::Unknown | MetadataResult::Abandoned
+        )
+    } else if game.victory_reason == Some(VictoryReason::Forfeit) {
+        winner.is_some()
+    } else if let Some(winner) = winner {
+        let resigned: bool = game
+            .moves
+            .last()
+            .map(|x| x.itself == Move::Resign && x.player == !winner)
+            .unwrap_or(false);
+        resigned || game.clock.timeout(!winner)
+    } else {
+        true
+    };
+    match compatible {
+        true => Ok(()),
+        false => Err(GameBuildError::ResultMismatch),
     }
 }
 
@@ -74,7 +351,10 @@ impl GameBuilder {
 mod test {
 
     use super::*;
-    use crate::{Color, Move};
+    use crate::position::*;
+    use crate::{BoardBuilder, Piece};
+
+    use alloc::string::ToString;
 
     use pretty_assertions::assert_eq;
 
@@ -85,7 +365,7 @@ mod test {
             .metadata(Metadata::default().with_date(2021, 08, 08))
             .moves(vec![GameMove::new(
                 Move::Resign,
-                Color::White,
+                Color::Black,
                 1,
                 Duration::from_secs(60),
                 None,
@@ -94,6 +374,7 @@ mod test {
             .timeout(Duration::from_secs(3), Duration::from_secs(5))
             .build();
         assert_eq!(game.board.get_turn(), Color::Black);
+        assert_eq!(game.initial_board, Board::dunsany());
         assert_eq!(game.metadata.date().unwrap().year(), 2021);
         assert_eq!(game.moves.len(), 1);
         assert_eq!(
@@ -101,6 +382,244 @@ mod test {
             (Duration::from_secs(3), Duration::from_secs(5))
         );
         // TODO: add option test
+        // Initial board
+        let game: Game = GameBuilder::default()
+            .board(Board::dunsany().with_turn(Color::White))
+            .initial_board(Board::dunsany())
+            .build();
+        assert_eq!(game.board.get_turn(), Color::White);
+        assert_eq!(game.initial_board, Board::dunsany());
+    }
+
+    #[test]
+    fn game_builder_variant() {
+        let game: Game = GameBuilder::default().build();
+        assert_eq!(game.variant(), Variant::Standard);
+        let game: Game = GameBuilder::horde().build();
+        assert_eq!(game.variant(), Variant::Horde);
+        assert_eq!(game.board, Board::horde());
+        assert_eq!(game.initial_board, Board::horde());
+        let game: Game = GameBuilder::dunsany().build();
+        assert_eq!(game.variant(), Variant::Dunsany);
+        assert_eq!(game.board, Board::dunsany());
+        let game: Game = GameBuilder::default()
+            .variant(Variant::Chess960 { sp: 518 })
+            .build();
+        assert_eq!(game.variant(), Variant::Chess960 { sp: 518 });
+    }
+
+    #[test]
+    fn game_builder_odds() {
+        let game: Game = GameBuilder::default()
+            .odds(Color::White, Odds::RookOdds)
+            .build();
+        assert_eq!(game.board.get_piece(A1), None);
+        assert_eq!(game.initial_board, game.board);
+        assert_eq!(
+            game.board
+                .get_castling_rights(Color::White)
+                .can_queenside_castle(),
+            false
+        );
+        let game: Game = GameBuilder::default()
+            .odds(Color::White, Odds::PawnAndMove)
+            .build();
+        assert_eq!(game.board.get_piece(F2), None);
+        assert_eq!(game.turn(), (Color::Black, 1));
+        // Two moves odds
+        let mut game: Game = GameBuilder::default()
+            .odds(Color::Black, Odds::TwoMoveOdds)
+            .build();
+        assert!(game.play_move(Move::Piece(E2, E4), Duration::ZERO).is_ok());
+        assert_eq!(game.board.get_turn(), Color::White);
+        assert!(game.play_move(Move::Piece(D2, D4), Duration::ZERO).is_ok());
+        assert_eq!(game.board.get_turn(), Color::Black);
+        assert_eq!(game.board_at(2), Some(game.board));
+    }
+
+    #[test]
+    fn game_try_build() {
+        // Consistent game
+        let game: Game = GameBuilder::default()
+            .board(
+                Board::default()
+                    .apply_move(Move::Piece(E2, E4))
+                    .change_turn(),
+            )
+            .initial_board(Board::default())
+            .moves(vec![GameMove::new(
+                Move::Piece(E2, E4),
+                Color::White,
+                1,
+                Duration::from_secs(1),
+                None,
+                None,
+            )])
+            .try_build()
+            .ok()
+            .unwrap();
+        assert_eq!(game.moves.len(), 1);
+        // Resigned game
+        assert!(GameBuilder::default()
+            .moves(vec![GameMove::new(
+                Move::Resign,
+                Color::White,
+                1,
+                Duration::from_secs(1),
+                None,
+                None,
+            )])
+            .metadata(Metadata::default().with_result(MetadataResult::BlackWins))
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn game_try_build_too_many_kings() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::King(Color::White, D1))
+            .piece(Piece::King(Color::Black, E8))
+            .build();
+        assert_eq!(
+            GameBuilder::default()
+                .board(board)
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::TooManyKings(Color::White)
+        );
+        // Not checked in variants
+        assert!(GameBuilder::default()
+            .board(board)
+            .variant(Variant::Horde)
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn game_try_build_history() {
+        let game_move = |m: Move, player: Color| {
+            GameMove::new(m, player, 1, Duration::from_secs(1), None, None)
+        };
+        // Illegal move
+        assert_eq!(
+            GameBuilder::default()
+                .moves(vec![
+                    game_move(Move::Piece(E2, E4), Color::White),
+                    game_move(Move::Piece(E7, E4), Color::Black),
+                ])
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::IllegalHistoryMove(1)
+        );
+        // Wrong player
+        assert_eq!(
+            GameBuilder::default()
+                .moves(vec![game_move(Move::Piece(E7, E5), Color::Black)])
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::IllegalHistoryMove(0)
+        );
+        // Board is not the one reached by moves
+        assert_eq!(
+            GameBuilder::default()
+                .moves(vec![game_move(Move::Piece(E2, E4), Color::White)])
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::HistoryMismatch
+        );
+    }
+
+    #[test]
+    fn game_try_build_result() {
+        // Win in the starting position
+        assert_eq!(
+            GameBuilder::default()
+                .metadata(Metadata::default().with_result(MetadataResult::WhiteWins))
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::ResultMismatch
+        );
+        // Win on time
+        assert!(GameBuilder::default()
+            .metadata(Metadata::default().with_result(MetadataResult::WhiteWins))
+            .timeout(Duration::from_secs(10), Duration::ZERO)
+            .try_build()
+            .is_ok());
+        // Checkmated player wins
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::Black, G8))
+            .piece(Piece::Queen(Color::White, D8))
+            .piece(Piece::Rook(Color::White, B7))
+            .piece(Piece::King(Color::White, C3))
+            .player_moving(Color::Black)
+            .build();
+        assert_eq!(
+            GameBuilder::default()
+                .board(board)
+                .metadata(Metadata::default().with_result(MetadataResult::BlackWins))
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::ResultMismatch
+        );
+        assert!(GameBuilder::default()
+            .board(board)
+            .metadata(Metadata::default().with_result(MetadataResult::WhiteWins))
+            .try_build()
+            .is_ok());
+        // Stalemate must be a draw
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::Black, H8))
+            .piece(Piece::Queen(Color::White, F7))
+            .piece(Piece::King(Color::White, G6))
+            .player_moving(Color::Black)
+            .build();
+        assert_eq!(
+            GameBuilder::default()
+                .board(board)
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::ResultMismatch
+        );
+        assert!(GameBuilder::default()
+            .board(board)
+            .metadata(Metadata::default().with_result(MetadataResult::DrawnGame))
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn game_try_build_clock() {
+        assert_eq!(
+            GameBuilder::default()
+                .timeout(Duration::from_secs(10), Duration::ZERO)
+                .try_build()
+                .err()
+                .unwrap(),
+            GameBuildError::ClockExpired(Color::Black)
+        );
+        assert_eq!(
+            GameBuildError::ClockExpired(Color::Black)
+                .to_string()
+                .as_str(),
+            "Black has no time left, but the game is in progress"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn game_build_inconsistent() {
+        GameBuilder::default()
+            .timeout(Duration::ZERO, Duration::ZERO)
+            .build();
     }
 
     #[test]
@@ -1,6 +1,7 @@
 //! # Clock
 //!
 //! This module exposes a chess clock in order to track remaining time for each player
+//! and the time sources to measure the time taken by the players to move
 
 use core::time::Duration;
 
@@ -82,12 +83,127 @@ impl Clock {
     }
 }
 
+/// ## TimeSource
+///
+/// A monotonic clock, used to measure the time taken by the players to move (see `TurnTimer`).
+/// Implement it on top of the timer of your platform; with the `std` feature, `StdTimeSource` is provided
+pub trait TimeSource {
+    /// ### now
+    ///
+    /// Get the time elapsed since an arbitrary point in time, which must not change.
+    /// The returned value must never decrease
+    fn now(&self) -> Duration;
+}
+
+/// ## StdTimeSource
+///
+/// A time source backed by `std::time::Instant`
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct StdTimeSource {
+    origin: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl Default for StdTimeSource {
+    fn default() -> Self {
+        Self {
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TimeSource for StdTimeSource {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// ## TurnTimer
+///
+/// Measures the time taken by each turn using a `TimeSource`, so that it can be charged to the player's clock
+/// (see `Game::play_move_timed`)
+#[derive(Debug, Clone)]
+pub struct TurnTimer<T: TimeSource> {
+    source: T,
+    turn_start: Duration,
+}
+
+impl<T: TimeSource> TurnTimer<T> {
+    /// ### new
+    ///
+    /// Instantiates a new `TurnTimer`, starting the first turn
+    pub fn new(source: T) -> Self {
+        let turn_start: Duration = source.now();
+        Self { source, turn_start }
+    }
+
+    /// ### start_turn
+    ///
+    /// Start measuring a new turn, discarding the time elapsed since the previous turn started
+    pub fn start_turn(&mut self) {
+        self.turn_start = self.source.now();
+    }
+
+    /// ### elapsed
+    ///
+    /// Get the time elapsed since the current turn started
+    pub fn elapsed(&self) -> Duration {
+        self.source.now().saturating_sub(self.turn_start)
+    }
+
+    /// ### lap
+    ///
+    /// Get the time taken by the current turn and start the next one
+    pub fn lap(&mut self) -> Duration {
+        let now: Duration = self.source.now();
+        let elapsed: Duration = now.saturating_sub(self.turn_start);
+        self.turn_start = now;
+        elapsed
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
 
+    /// A time source advancing by one second every time it's read
+    struct MockTimeSource {
+        time: core::cell::Cell<Duration>,
+    }
+
+    impl TimeSource for MockTimeSource {
+        fn now(&self) -> Duration {
+            let now: Duration = self.time.get();
+            self.time.set(now + Duration::from_secs(1));
+            now
+        }
+    }
+
+    #[test]
+    fn turn_timer() {
+        let mut timer: TurnTimer<MockTimeSource> = TurnTimer::new(MockTimeSource {
+            time: core::cell::Cell::new(Duration::from_secs(100)),
+        });
+        assert_eq!(timer.elapsed(), Duration::from_secs(1));
+        assert_eq!(timer.elapsed(), Duration::from_secs(2));
+        assert_eq!(timer.lap(), Duration::from_secs(3));
+        assert_eq!(timer.lap(), Duration::from_secs(1));
+        timer.start_turn();
+        assert_eq!(timer.elapsed(), Duration::from_secs(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_time_source() {
+        let source: StdTimeSource = StdTimeSource::default();
+        let before: Duration = source.now();
+        assert!(source.now() >= before);
+    }
+
     #[test]
     fn clock_struct() {
         let clock: Clock = Clock {
@@ -0,0 +1,154 @@
+//! # Cursor
+//!
+//! This module exposes a cursor to walk through the history of a `Game`
+
+use super::{Board, Game, GameMove};
+
+use alloc::vec::Vec;
+
+/// ## GameCursor
+///
+/// A cursor to walk forward and back through the positions of a game, without mutating it.
+/// The position at ply `0` is the initial board of the game, while the position at ply `n` is the board after `n` half-moves.
+/// Replayed boards are cached, so once a position has been reached, getting back to it is O(1).
+///
+/// The cursor borrows the game, so the game can't be mutated while a cursor exists;
+/// once a new move has been played, a new cursor must be obtained with `Game::cursor()`.
+#[derive(Debug, Clone)]
+pub struct GameCursor<'a> {
+    game: &'a Game,
+    boards: Vec<Board>,
+    ply: usize,
+}
+
+impl<'a> GameCursor<'a> {
+    /// ### new
+    ///
+    /// Instantiates a new `GameCursor` at the beginning of the game
+    pub(super) fn new(game: &'a Game) -> Self {
+        Self {
+            game,
+            boards: vec![*game.initial_board()],
+            ply: 0,
+        }
+    }
+
+    // -- getters
+
+    /// ### current_ply
+    ///
+    /// Get the amount of half-moves played in the current position
+    pub fn current_ply(&self) -> usize {
+        self.ply
+    }
+
+    /// ### board
+    ///
+    /// Get the board for the current position
+    pub fn board(&self) -> &Board {
+        &self.boards[self.ply]
+    }
+
+    /// ### last_move
+    ///
+    /// Get the move which produced the current position.
+    /// Returns `None` at the beginning of the game
+    pub fn last_move(&self) -> Option<&GameMove> {
+        match self.ply {
+            0 => None,
+            ply => self.game.moves().get(ply - 1),
+        }
+    }
+
+    // -- navigation
+
+    /// ### seek
+    ///
+    /// Move the cursor to the position after `ply` half-moves and return the board.
+    /// Returns `None` if `ply` exceeds the amount of played moves; in this case the cursor is not moved
+    pub fn seek(&mut self, ply: usize) -> Option<&Board> {
+        if ply > self.game.moves().len() {
+            return None;
+        }
+        // Replay missing positions
+        while self.boards.len() <= ply {
+            let board: &Board = self.boards.last().unwrap();
+            let m: &GameMove = &self.game.moves()[self.boards.len() - 1];
+            self.boards.push(board.replay_move(m.itself, m.promotion));
+        }
+        self.ply = ply;
+        Some(self.board())
+    }
+
+    /// ### step_forward
+    ///
+    /// Move the cursor to the next position and return the board.
+    /// Returns `None` if already at the last position
+    pub fn step_forward(&mut self) -> Option<&Board> {
+        self.seek(self.ply + 1)
+    }
+
+    /// ### step_back
+    ///
+    /// Move the cursor to the previous position and return the board.
+    /// Returns `None` if already at the beginning of the game
+    pub fn step_back(&mut self) -> Option<&Board> {
+        match self.ply {
+            0 => None,
+            ply => self.seek(ply - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::Move;
+
+    use core::time::Duration;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn game_cursor() {
+        let mut game: Game = Game::default();
+        let moves: [Move; 4] = [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(D8, D5),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let mut cursor: GameCursor = game.cursor();
+        assert_eq!(cursor.current_ply(), 0);
+        assert_eq!(cursor.board(), game.initial_board());
+        assert_eq!(cursor.last_move(), None);
+        assert_eq!(cursor.step_back(), None);
+        // Step forward
+        for ply in 1..=4 {
+            assert_eq!(cursor.step_forward().copied(), game.board_at(ply));
+            assert_eq!(cursor.current_ply(), ply);
+            assert_eq!(cursor.last_move(), game.moves().get(ply - 1));
+        }
+        assert_eq!(cursor.step_forward(), None);
+        assert_eq!(cursor.current_ply(), 4);
+        assert_eq!(cursor.board(), game.board());
+        assert_eq!(cursor.last_move().unwrap().itself, Move::Piece(D8, D5));
+        // Step back
+        assert_eq!(cursor.step_back().copied(), game.board_at(3));
+        assert_eq!(cursor.current_ply(), 3);
+        // Seek
+        assert_eq!(cursor.seek(1).copied(), game.board_at(1));
+        assert_eq!(cursor.seek(5), None);
+        assert_eq!(cursor.current_ply(), 1);
+        // Play a new move; a new cursor is required
+        assert!(game
+            .play_move(Move::Piece(B1, C3), Duration::from_secs(1))
+            .is_ok());
+        let mut cursor: GameCursor = game.cursor();
+        assert_eq!(cursor.seek(5), Some(game.board()));
+    }
+}
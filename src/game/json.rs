@@ -0,0 +1,1140 @@
+//! # JSON
+//!
+//! This module exposes a minimal JSON encoder and decoder for `Game`, so that games can be stored
+//! without depending on serde.
+//! The decoder only supports what's required by the `Game` schema: numbers must be unsigned integers.
+
+use super::metadata::{Country, Metadata, Player, Result as MetadataResult, Title};
+use super::{
+    Game, GameBuildError, GameBuilder, GameMove, MoveFlags, Options, Variant, VictoryReason,
+};
+use crate::{Board, Color, FenError, Move, Piece, PieceKind, Position, Promotion};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::str::FromStr;
+use core::time::Duration;
+
+/// ## JsonError
+///
+/// Describes an error while decoding a game from JSON
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonError {
+    /// The document is not valid JSON
+    Syntax,
+    /// A field is missing or has a bad value
+    BadField(&'static str),
+    /// A board is not a valid FEN string
+    BadBoard(FenError),
+    /// The game is not consistent
+    BadGame(GameBuildError),
+}
+
+impl core::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Syntax => write!(f, "Syntax error"),
+            Self::BadField(field) => write!(f, "Bad field '{}'", field),
+            Self::BadBoard(err) => write!(f, "Bad board: {}", err),
+            Self::BadGame(err) => write!(f, "Bad game: {}", err),
+        }
+    }
+}
+
+/// ## Value
+///
+/// A JSON value
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Game {
+    /// ### to_json
+    ///
+    /// Serialize the game to JSON. The document contains the initial and the current board as FEN,
+    /// the clock, the options, the metadata and the played moves.
+    /// Times are stored in milliseconds; a remaining time which doesn't fit in milliseconds
+    /// (e.g. the unlimited time of the default game) is stored as `null`.
+    pub fn to_json(&self) -> String {
+        let (white_time, black_time) = self.remaining_time();
+        let document: Value = Value::Object(vec![
+            field(
+                "initial_board",
+                Value::String(self.initial_board().to_fen()),
+            ),
+            field("board", Value::String(self.board().to_fen())),
+            field(
+                "clock",
+                Value::Object(vec![
+                    field("white", clock_to_value(white_time)),
+                    field("black", clock_to_value(black_time)),
+                ]),
+            ),
+            field(
+                "options",
+                Value::Object(vec![
+                    field(
+                        "threefold_repetition",
+                        Value::Bool(self.options.threefold_repetition),
+                    ),
+                    field(
+                        "fivefold_repetition",
+                        Value::Bool(self.options.fivefold_repetition),
+                    ),
+                    field(
+                        "repetition_threshold",
+                        Value::Number(self.options.get_repetition_threshold() as u64),
+                    ),
+                    field(
+                        "auto_promote",
+                        optional(self.options.auto_promote, promotion_to_value),
+                    ),
+                    field(
+                        "max_search_depth",
+                        Value::Number(self.options.max_search_depth as u64),
+                    ),
+                    field(
+                        "search_time",
+                        optional(self.options.search_time, |x| {
+                            Value::Number(x.as_millis() as u64)
+                        }),
+                    ),
+                    field("allow_castling", Value::Bool(self.options.allow_castling)),
+                ]),
+            ),
+            field("variant", variant_to_value(self.variant())),
+            field(
+                "victory_reason",
+                optional(self.victory_reason, |x| Value::String(format!("{:?}", x))),
+            ),
+            field("metadata", metadata_to_value(self.metadata())),
+            field(
+                "moves",
+                Value::Array(self.moves().iter().map(move_to_value).collect()),
+            ),
+        ]);
+        let mut json: String = String::new();
+        document.encode(&mut json);
+        json
+    }
+
+    /// ### from_json
+    ///
+    /// Deserialize a game from a JSON document produced by `to_json`
+    pub fn from_json(json: &str) -> Result<Game, JsonError> {
+        let document: Value = Parser::new(json).parse()?;
+        let initial_board: Board =
+            Board::from_fen(document.get("initial_board")?.as_str("initial_board")?)
+                .map_err(JsonError::BadBoard)?;
+        let board: Board = Board::from_fen(document.get("board")?.as_str("board")?)
+            .map_err(JsonError::BadBoard)?;
+        let clock: &Value = document.get("clock")?;
+        let options: &Value = document.get("options")?;
+        let options: Options = Options::default()
+            .threefold_repetition(
+                options
+                    .get("threefold_repetition")?
+                    .as_bool("threefold_repetition")?,
+            )
+            .fivefold_repetition(
+                options
+                    .get("fivefold_repetition")?
+                    .as_bool("fivefold_repetition")?,
+            )
+            .repetition_threshold(
+                usize::try_from(
+                    options
+                        .get("repetition_threshold")?
+                        .as_number("repetition_threshold")?,
+                )
+                .map_err(|_| JsonError::BadField("repetition_threshold"))?,
+            )
+            .auto_promote(optional_promotion_from_value(
+                options.get("auto_promote")?,
+                "auto_promote",
+            )?)
+            .max_search_depth(
+                usize::try_from(
+                    options
+                        .get("max_search_depth")?
+                        .as_number("max_search_depth")?,
+                )
+                .map_err(|_| JsonError::BadField("max_search_depth"))?,
+            )
+            .search_time(match options.get("search_time")?.nullable() {
+                None => None,
+                Some(millis) => Some(Duration::from_millis(millis.as_number("search_time")?)),
+            })
+            .allow_castling(options.get("allow_castling")?.as_bool("allow_castling")?);
+        let moves: Vec<GameMove> = document
+            .get("moves")?
+            .as_array("moves")?
+            .iter()
+            .map(move_from_value)
+            .collect::<Result<Vec<GameMove>, JsonError>>()?;
+        GameBuilder::default()
+            .board(board)
+            .initial_board(initial_board)
+            .timeout(
+                clock_from_value(clock.get("white")?, "white")?,
+                clock_from_value(clock.get("black")?, "black")?,
+            )
+            .options(options)
+            .variant(variant_from_value(document.get("variant")?)?)
+            .victory_reason(victory_reason_from_value(document.get("victory_reason")?)?)
+            .metadata(metadata_from_value(document.get("metadata")?)?)
+            .moves(moves)
+            .try_build()
+            .map_err(JsonError::BadGame)
+    }
+}
+
+// -- encoders
+
+/// ### field
+///
+/// Make an object field
+fn field(key: &str, value: Value) -> (String, Value) {
+    (key.to_string(), value)
+}
+
+/// ### optional
+///
+/// Convert an option to a value; `None` is converted to `null`
+fn optional<T, F: FnOnce(T) -> Value>(value: Option<T>, f: F) -> Value {
+    value.map(f).unwrap_or(Value::Null)
+}
+
+/// ### clock_to_value
+///
+/// Convert remaining time to a JSON value; unlimited time is converted to `null`
+fn clock_to_value(time: Duration) -> Value {
+    match u64::try_from(time.as_millis()) {
+        Ok(millis) => Value::Number(millis),
+        Err(_) => Value::Null,
+    }
+}
+
+/// ### metadata_to_value
+///
+/// Convert metadata to a JSON value
+fn metadata_to_value(metadata: &Metadata) -> Value {
+    Value::Object(vec![
+        field(
+            "event",
+            optional(metadata.event(), |x| Value::String(x.to_string())),
+        ),
+        field(
+            "site",
+            optional(metadata.site(), |x| {
+                Value::Object(vec![
+                    field("city", Value::String(x.city().to_string())),
+                    field("region", Value::String(x.region().to_string())),
+                    field("country", country_to_value(x.country())),
+                ])
+            }),
+        ),
+        field(
+            "date",
+            optional(metadata.date(), |x| {
+                Value::String(format!("{:04}.{:02}.{:02}", x.year(), x.month(), x.day()))
+            }),
+        ),
+        field(
+            "round",
+            optional(metadata.round(), |x| Value::String(x.to_string())),
+        ),
+        field("white", optional(metadata.white_player(), player_to_value)),
+        field("black", optional(metadata.black_player(), player_to_value)),
+        field("result", Value::String(format!("{:?}", metadata.result()))),
+        field(
+            "tags",
+            Value::Array(
+                metadata
+                    .tags()
+                    .iter()
+                    .map(|(k, v)| {
+                        Value::Array(vec![Value::String(k.clone()), Value::String(v.clone())])
+                    })
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+/// ### player_to_value
+///
+/// Convert player to a JSON value
+fn player_to_value(player: &Player) -> Value {
+    Value::Object(vec![
+        field("name", Value::String(player.name().to_string())),
+        field("surname", Value::String(player.surname().to_string())),
+        field("nationality", country_to_value(player.nationality())),
+        field("elo", Value::Number(player.elo() as u64)),
+        field(
+            "title",
+            optional(player.title(), |x| Value::String(x.to_string())),
+        ),
+        field(
+            "fide_id",
+            optional(player.fide_id(), |x| Value::Number(x as u64)),
+        ),
+    ])
+}
+
+/// ### country_to_value
+///
+/// Convert country to a JSON value
+fn country_to_value(country: Country) -> Value {
+    Value::String(country.code().to_string())
+}
+
+/// ### variant_to_value
+///
+/// Convert variant to a JSON value. The starting position is set only for Chess960
+fn variant_to_value(variant: Variant) -> Value {
+    Value::Object(vec![
+        field("name", Value::String(variant.name().to_string())),
+        field(
+            "sp",
+            match variant {
+                Variant::Chess960 { sp } => Value::Number(sp as u64),
+                _ => Value::Null,
+            },
+        ),
+    ])
+}
+
+/// ### move_to_value
+///
+/// Convert a game move to a JSON value
+fn move_to_value(m: &GameMove) -> Value {
+    Value::Object(vec![
+        field(
+            "move",
+            Value::String(match m.itself {
+                Move::Piece(from, to) => format!("{}{}", from, to),
+                Move::KingSideCastle => String::from("O-O"),
+                Move::QueenSideCastle => String::from("O-O-O"),
+                Move::Drop(kind, to) => format!("{}@{}", kind.to_ascii_char(), to),
+                Move::Resign => String::from("resign"),
+            }),
+        ),
+        field("turn", Value::Number(m.turn as u64)),
+        field(
+            "player",
+            Value::String(String::from(match m.player {
+                Color::White => "white",
+                Color::Black => "black",
+            })),
+        ),
+        field("time", Value::Number(m.time.as_millis() as u64)),
+        field(
+            "piece_taken",
+            optional(m.piece_taken, |x| {
+                Value::String(format!("{}{}", x.to_ascii_char(), x.get_pos()))
+            }),
+        ),
+        field("promotion", optional(m.promotion, promotion_to_value)),
+        field("flags", Value::Number(m.flags.bits() as u64)),
+    ])
+}
+
+// -- decoders
+
+/// ### promotion_to_value
+///
+/// Convert a promotion to a JSON value, using the UCI piece letter
+fn promotion_to_value(promotion: Promotion) -> Value {
+    let letter: char = PieceKind::from(promotion)
+        .to_ascii_char()
+        .to_ascii_lowercase();
+    Value::String(letter.to_string())
+}
+
+/// ### clock_from_value
+///
+/// Get remaining time from JSON value
+fn clock_from_value(value: &Value, key: &'static str) -> Result<Duration, JsonError> {
+    match value.nullable() {
+        None => Ok(Duration::MAX),
+        Some(millis) => Ok(Duration::from_millis(millis.as_number(key)?)),
+    }
+}
+
+/// ### metadata_from_value
+///
+/// Get metadata from JSON value
+fn metadata_from_value(value: &Value) -> Result<Metadata, JsonError> {
+    let mut metadata: Metadata = Metadata::default();
+    if let Some(event) = value.get("event")?.nullable() {
+        metadata = metadata.with_event(event.as_str("event")?);
+    }
+    if let Some(site) = value.get("site")?.nullable() {
+        metadata = metadata.with_site(
+            site.get("city")?.as_str("city")?,
+            site.get("region")?.as_str("region")?,
+            country_from_value(site.get("country")?)?,
+        );
+    }
+    if let Some(date) = value.get("date")?.nullable() {
+        let date: Vec<&str> = date.as_str("date")?.split('.').collect();
+        let (year, month, day) = match date.as_slice() {
+            [year, month, day] => (
+                year.parse::<u16>()
+                    .map_err(|_| JsonError::BadField("date"))?,
+                month
+                    .parse::<u8>()
+                    .map_err(|_| JsonError::BadField("date"))?,
+                day.parse::<u8>().map_err(|_| JsonError::BadField("date"))?,
+            ),
+            _ => return Err(JsonError::BadField("date")),
+        };
+        metadata = metadata
+            .try_with_date(year, month, day)
+            .map_err(|_| JsonError::BadField("date"))?;
+    }
+    if let Some(round) = value.get("round")?.nullable() {
+        metadata = metadata
+            .with_round_str(round.as_str("round")?)
+            .map_err(|_| JsonError::BadField("round"))?;
+    }
+    if let Some(player) = value.get("white")?.nullable() {
+        metadata = metadata.with_white_player(player_from_value(player)?);
+    }
+    if let Some(player) = value.get("black")?.nullable() {
+        metadata = metadata.with_black_player(player_from_value(player)?);
+    }
+    metadata = metadata.with_result(match value.get("result")?.as_str("result")? {
+        "WhiteWins" => MetadataResult::WhiteWins,
+        "BlackWins" => MetadataResult::BlackWins,
+        "DrawnGame" => MetadataResult::DrawnGame,
+        "InProgress" => MetadataResult::InProgress,
+        "Abandoned" => MetadataResult::Abandoned,
+        "Unknown" => MetadataResult::Unknown,
+        _ => return Err(JsonError::BadField("result")),
+    });
+    for tag in value.get("tags")?.as_array("tags")?.iter() {
+        match tag.as_array("tags")? {
+            [key, value] => {
+                metadata
+                    .set_tag(key.as_str("tags")?, value.as_str("tags")?)
+                    .map_err(|_| JsonError::BadField("tags"))?;
+            }
+            _ => return Err(JsonError::BadField("tags")),
+        }
+    }
+    Ok(metadata)
+}
+
+/// ### player_from_value
+///
+/// Get player from JSON value
+fn player_from_value(value: &Value) -> Result<Player, JsonError> {
+    let mut player: Player = Player::new(
+        value.get("name")?.as_str("name")?,
+        value.get("surname")?.as_str("surname")?,
+        country_from_value(value.get("nationality")?)?,
+        to_u16(value.get("elo")?.as_number("elo")?, "elo")?,
+    );
+    if let Some(title) = value.get("title")?.nullable() {
+        player = player.with_title(
+            Title::from_str(title.as_str("title")?).map_err(|_| JsonError::BadField("title"))?,
+        );
+    }
+    if let Some(fide_id) = value.get("fide_id")?.nullable() {
+        let fide_id: u64 = fide_id.as_number("fide_id")?;
+        if fide_id > u32::MAX as u64 {
+            return Err(JsonError::BadField("fide_id"));
+        }
+        player = player.with_fide_id(fide_id as u32);
+    }
+    Ok(player)
+}
+
+/// ### country_from_value
+///
+/// Get country from JSON value
+fn country_from_value(value: &Value) -> Result<Country, JsonError> {
+    Country::from_code(value.as_str("country")?).ok_or(JsonError::BadField("country"))
+}
+
+/// ### variant_from_value
+///
+/// Get variant from JSON value
+fn variant_from_value(value: &Value) -> Result<Variant, JsonError> {
+    match value.get("name")?.as_str("variant")? {
+        "Standard" => Ok(Variant::Standard),
+        "Horde" => Ok(Variant::Horde),
+        "Dunsany" => Ok(Variant::Dunsany),
+        "Three-check" => Ok(Variant::ThreeCheck),
+        "Crazyhouse" => Ok(Variant::Crazyhouse),
+        "Chess960" => Ok(Variant::Chess960 {
+            sp: to_u16(value.get("sp")?.as_number("sp")?, "sp")?,
+        }),
+        _ => Err(JsonError::BadField("variant")),
+    }
+}
+
+/// ### move_from_value
+///
+/// Get game move from JSON value
+fn move_from_value(value: &Value) -> Result<GameMove, JsonError> {
+    let itself: Move = match value.get("move")?.as_str("move")? {
+        "O-O" => Move::KingSideCastle,
+        "O-O-O" => Move::QueenSideCastle,
+        "resign" => Move::Resign,
+        m if m.len() == 4 && m.is_ascii() && &m[1..2] == "@" => Move::Drop(
+            m.chars()
+                .next()
+                .and_then(PieceKind::from_ascii_char)
+                .ok_or(JsonError::BadField("move"))?,
+            Position::from_str(&m[2..4]).map_err(|_| JsonError::BadField("move"))?,
+        ),
+        m if m.len() == 4 && m.is_ascii() => Move::Piece(
+            Position::from_str(&m[0..2]).map_err(|_| JsonError::BadField("move"))?,
+            Position::from_str(&m[2..4]).map_err(|_| JsonError::BadField("move"))?,
+        ),
+        _ => return Err(JsonError::BadField("move")),
+    };
+    let player: Color = match value.get("player")?.as_str("player")? {
+        "white" => Color::White,
+        "black" => Color::Black,
+        _ => return Err(JsonError::BadField("player")),
+    };
+    let piece_taken: Option<Piece> = match value.get("piece_taken")?.nullable() {
+        None => None,
+        Some(piece) => {
+            let piece: &str = piece.as_str("piece_taken")?;
+            let mut chars = piece.chars();
+            let piece: Option<Piece> = match (chars.next(), Position::from_str(chars.as_str())) {
+                (Some(ch), Ok(pos)) => Piece::from_ascii_char(ch, pos),
+                _ => None,
+            };
+            Some(piece.ok_or(JsonError::BadField("piece_taken"))?)
+        }
+    };
+    let promotion: Option<Promotion> =
+        optional_promotion_from_value(value.get("promotion")?, "promotion")?;
+    let flags: MoveFlags =
+        MoveFlags::from_bits(to_u8(value.get("flags")?.as_number("flags")?, "flags")?)
+            .ok_or(JsonError::BadField("flags"))?;
+    Ok(GameMove::new(
+        itself,
+        player,
+        to_u16(value.get("turn")?.as_number("turn")?, "turn")?,
+        Duration::from_millis(value.get("time")?.as_number("time")?),
+        piece_taken,
+        promotion,
+    )
+    .with_flags(flags))
+}
+
+/// ### optional_promotion_from_value
+///
+/// Get an optional promotion from a JSON value, which is either `null` or the UCI piece letter
+fn optional_promotion_from_value(
+    value: &Value,
+    key: &'static str,
+) -> Result<Option<Promotion>, JsonError> {
+    match value.nullable() {
+        None => Ok(None),
+        Some(promotion) => match promotion.as_str(key)? {
+            "q" => Ok(Some(Promotion::Queen)),
+            "r" => Ok(Some(Promotion::Rook)),
+            "b" => Ok(Some(Promotion::Bishop)),
+            "n" => Ok(Some(Promotion::Knight)),
+            _ => Err(JsonError::BadField(key)),
+        },
+    }
+}
+
+/// ### victory_reason_from_value
+///
+/// Get the victory reason from a JSON value, which can be `null` if the game has not been won
+fn victory_reason_from_value(value: &Value) -> Result<Option<VictoryReason>, JsonError> {
+    match value.nullable() {
+        None => Ok(None),
+        Some(reason) => match reason.as_str("victory_reason")? {
+            "Checkmate" => Ok(Some(VictoryReason::Checkmate)),
+            "Resign" => Ok(Some(VictoryReason::Resign)),
+            "Timeout" => Ok(Some(VictoryReason::Timeout)),
+            "AllPiecesCaptured" => Ok(Some(VictoryReason::AllPiecesCaptured)),
+            "Forfeit" => Ok(Some(VictoryReason::Forfeit)),
+            "ThreeChecks" => Ok(Some(VictoryReason::ThreeChecks)),
+            "Unknown" => Ok(Some(VictoryReason::Unknown)),
+            _ => Err(JsonError::BadField("victory_reason")),
+        },
+    }
+}
+
+/// ### to_u8
+///
+/// Convert number to u8
+fn to_u8(number: u64, key: &'static str) -> Result<u8, JsonError> {
+    if number > u8::MAX as u64 {
+        Err(JsonError::BadField(key))
+    } else {
+        Ok(number as u8)
+    }
+}
+
+/// ### to_u16
+///
+/// Convert number to u16
+fn to_u16(number: u64, key: &'static str) -> Result<u16, JsonError> {
+    if number > u16::MAX as u64 {
+        Err(JsonError::BadField(key))
+    } else {
+        Ok(number as u16)
+    }
+}
+
+// -- value
+
+impl Value {
+    /// ### get
+    ///
+    /// Get object field
+    fn get(&self, key: &'static str) -> Result<&Value, JsonError> {
+        match self {
+            Value::Object(fields) => fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v)
+                .ok_or(JsonError::BadField(key)),
+            _ => Err(JsonError::BadField(key)),
+        }
+    }
+
+    /// ### nullable
+    ///
+    /// Returns `None` if value is `null`
+    fn nullable(&self) -> Option<&Value> {
+        match self {
+            Value::Null => None,
+            value => Some(value),
+        }
+    }
+
+    /// ### as_str
+    ///
+    /// Get value as string
+    fn as_str(&self, key: &'static str) -> Result<&str, JsonError> {
+        match self {
+            Value::String(s) => Ok(s.as_str()),
+            _ => Err(JsonError::BadField(key)),
+        }
+    }
+
+    /// ### as_number
+    ///
+    /// Get value as number
+    fn as_number(&self, key: &'static str) -> Result<u64, JsonError> {
+        match self {
+            Value::Number(n) => Ok(*n),
+            _ => Err(JsonError::BadField(key)),
+        }
+    }
+
+    /// ### as_bool
+    ///
+    /// Get value as boolean
+    fn as_bool(&self, key: &'static str) -> Result<bool, JsonError> {
+        match self {
+            Value::Bool(b) => Ok(*b),
+            _ => Err(JsonError::BadField(key)),
+        }
+    }
+
+    /// ### as_array
+    ///
+    /// Get value as array
+    fn as_array(&self, key: &'static str) -> Result<&[Value], JsonError> {
+        match self {
+            Value::Array(values) => Ok(values.as_slice()),
+            _ => Err(JsonError::BadField(key)),
+        }
+    }
+
+    /// ### encode
+    ///
+    /// Write the value to `out`
+    fn encode(&self, out: &mut String) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => out.push_str(&n.to_string()),
+            Value::String(s) => encode_string(s, out),
+            Value::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.encode(out);
+                }
+                out.push(']');
+            }
+            Value::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    encode_string(key, out);
+                    out.push(':');
+                    value.encode(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// ### encode_string
+///
+/// Write a quoted and escaped string to `out`
+fn encode_string(s: &str, out: &mut String) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+// -- parser
+
+/// ## Parser
+///
+/// JSON parser
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    /// ### new
+    ///
+    /// Instantiates a new `Parser`
+    fn new(json: &str) -> Self {
+        Self {
+            chars: json.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    /// ### parse
+    ///
+    /// Parse the whole document
+    fn parse(mut self) -> Result<Value, JsonError> {
+        let value: Value = self.parse_value()?;
+        self.skip_whitespaces();
+        if self.pos == self.chars.len() {
+            Ok(value)
+        } else {
+            Err(JsonError::Syntax)
+        }
+    }
+
+    /// ### peek
+    ///
+    /// Get next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// ### next
+    ///
+    /// Consume next character
+    fn next(&mut self) -> Result<char, JsonError> {
+        let ch: char = self.peek().ok_or(JsonError::Syntax)?;
+        self.pos += 1;
+        Ok(ch)
+    }
+
+    /// ### expect
+    ///
+    /// Consume the provided token
+    fn expect(&mut self, token: &str) -> Result<(), JsonError> {
+        for ch in token.chars() {
+            if self.next()? != ch {
+                return Err(JsonError::Syntax);
+            }
+        }
+        Ok(())
+    }
+
+    /// ### skip_whitespaces
+    ///
+    /// Skip whitespaces
+    fn skip_whitespaces(&mut self) {
+        while matches!(
+            self.peek(),
+            Some(' ') | Some('\n') | Some('\r') | Some('\t')
+        ) {
+            self.pos += 1;
+        }
+    }
+
+    /// ### parse_value
+    ///
+    /// Parse any value
+    fn parse_value(&mut self) -> Result<Value, JsonError> {
+        self.skip_whitespaces();
+        match self.peek().ok_or(JsonError::Syntax)? {
+            'n' => self.expect("null").map(|_| Value::Null),
+            't' => self.expect("true").map(|_| Value::Bool(true)),
+            'f' => self.expect("false").map(|_| Value::Bool(false)),
+            '"' => self.parse_string().map(Value::String),
+            '[' => self.parse_array(),
+            '{' => self.parse_object(),
+            '0'..='9' => self.parse_number(),
+            _ => Err(JsonError::Syntax),
+        }
+    }
+
+    /// ### parse_number
+    ///
+    /// Parse an unsigned integer
+    fn parse_number(&mut self) -> Result<Value, JsonError> {
+        let mut number: u64 = 0;
+        while let Some(digit) = self.peek().and_then(|x| x.to_digit(10)) {
+            number = number
+                .checked_mul(10)
+                .and_then(|x| x.checked_add(digit as u64))
+                .ok_or(JsonError::Syntax)?;
+            self.pos += 1;
+        }
+        // Fractions and exponents are not supported
+        match self.peek() {
+            Some('.') | Some('e') | Some('E') => Err(JsonError::Syntax),
+            _ => Ok(Value::Number(number)),
+        }
+    }
+
+    /// ### parse_string
+    ///
+    /// Parse a string
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect("\"")?;
+        let mut s: String = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(s),
+                '\\' => s.push(match self.next()? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    '/' => '/',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => self.parse_unicode_escape()?,
+                    _ => return Err(JsonError::Syntax),
+                }),
+                ch if (ch as u32) < 0x20 => return Err(JsonError::Syntax),
+                ch => s.push(ch),
+            }
+        }
+    }
+
+    /// ### parse_unicode_escape
+    ///
+    /// Parse the hex digits of an unicode escape (`\uXXXX`), including surrogate pairs
+    fn parse_unicode_escape(&mut self) -> Result<char, JsonError> {
+        let high: u32 = self.parse_hex()?;
+        let code: u32 = if (0xd800..0xdc00).contains(&high) {
+            self.expect("\\u")?;
+            let low: u32 = self.parse_hex()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(JsonError::Syntax);
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        core::char::from_u32(code).ok_or(JsonError::Syntax)
+    }
+
+    /// ### parse_hex
+    ///
+    /// Parse 4 hex digits
+    fn parse_hex(&mut self) -> Result<u32, JsonError> {
+        let mut code: u32 = 0;
+        for _ in 0..4 {
+            code = code * 16 + self.next()?.to_digit(16).ok_or(JsonError::Syntax)?;
+        }
+        Ok(code)
+    }
+
+    /// ### parse_array
+    ///
+    /// Parse an array
+    fn parse_array(&mut self) -> Result<Value, JsonError> {
+        self.expect("[")?;
+        let mut values: Vec<Value> = Vec::new();
+        self.skip_whitespaces();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespaces();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Value::Array(values)),
+                _ => return Err(JsonError::Syntax),
+            }
+        }
+    }
+
+    /// ### parse_object
+    ///
+    /// Parse an object
+    fn parse_object(&mut self) -> Result<Value, JsonError> {
+        self.expect("{")?;
+        let mut fields: Vec<(String, Value)> = Vec::new();
+        self.skip_whitespaces();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespaces();
+            let key: String = self.parse_string()?;
+            self.skip_whitespaces();
+            self.expect(":")?;
+            fields.push((key, self.parse_value()?));
+            self.skip_whitespaces();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Value::Object(fields)),
+                _ => return Err(JsonError::Syntax),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn json_round_trip() {
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(300), Duration::from_secs(300))
+            .options(
+                Options::default()
+                    .threefold_repetition(false)
+                    .repetition_threshold(2)
+                    .auto_promote(Some(Promotion::Knight))
+                    .search_time(Some(Duration::from_secs(3))),
+            )
+            .metadata(
+                Metadata::default()
+                    .with_event("Oslo \"Open\"\n")
+                    .with_site("Oslo", "Oslo", Country::Norway)
+                    .with_date(2021, 6, 15)
+                    .with_round(4)
+                    .with_white_player(
+                        Player::new("magnus", "carlsen", Country::Norway, 2882)
+                            .with_title(Title::GrandMaster)
+                            .with_fide_id(1503014),
+                    )
+                    .with_black_player(Player::new(
+                        "hikaru",
+                        "nakamura",
+                        Country::UnitedStates,
+                        2816,
+                    ))
+                    .with_tag("TimeControl", "300")
+                    .ok()
+                    .unwrap(),
+            )
+            .build();
+        let moves: [Move; 5] = [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(D8, D5),
+            Move::Piece(B1, C3),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_millis(1500)).is_ok());
+        }
+        let json: String = game.to_json();
+        let restored: Game = Game::from_json(&json).ok().unwrap();
+        assert_eq!(restored.board().to_fen(), game.board().to_fen());
+        assert_eq!(restored.initial_board(), game.initial_board());
+        assert_eq!(restored.metadata(), game.metadata());
+        assert_eq!(restored.moves(), game.moves());
+        assert_eq!(restored.remaining_time(), game.remaining_time());
+        assert_eq!(restored.options.threefold_repetition, false);
+        assert_eq!(restored.options.repetition_threshold, 2);
+        assert_eq!(restored.options.fivefold_repetition, true);
+        assert_eq!(restored.options.auto_promote, Some(Promotion::Knight));
+        assert_eq!(restored.options.max_search_depth, 2);
+        assert_eq!(restored.options.search_time, Some(Duration::from_secs(3)));
+        assert_eq!(restored.variant(), Variant::Standard);
+        assert_eq!(restored.to_json(), json);
+        // Game can continue
+        let mut restored: Game = restored;
+        assert!(restored
+            .play_move(Move::Piece(D5, A5), Duration::from_secs(1))
+            .is_ok());
+    }
+
+    #[test]
+    fn json_variant() {
+        let game: Game = GameBuilder::horde().build();
+        let restored: Game = Game::from_json(&game.to_json()).ok().unwrap();
+        assert_eq!(restored.variant(), Variant::Horde);
+        assert_eq!(restored.initial_board(), &Board::horde());
+        let game: Game = GameBuilder::default()
+            .variant(Variant::Chess960 { sp: 518 })
+            .build();
+        let restored: Game = Game::from_json(&game.to_json()).ok().unwrap();
+        assert_eq!(restored.variant(), Variant::Chess960 { sp: 518 });
+    }
+
+    #[test]
+    fn json_repetition_threshold() {
+        // Thresholds below the minimum are clamped, both when exported and when imported
+        let game: Game = GameBuilder::default()
+            .options(Options {
+                repetition_threshold: 1,
+                ..Options::default()
+            })
+            .build();
+        let json: String = game.to_json();
+        assert!(json.contains("\"repetition_threshold\":2"));
+        let restored: Game = Game::from_json(&json).ok().unwrap();
+        assert_eq!(restored.options.repetition_threshold, 2);
+        let json: String = Game::default()
+            .to_json()
+            .replace("\"repetition_threshold\":3", "\"repetition_threshold\":0");
+        let restored: Game = Game::from_json(&json).ok().unwrap();
+        assert_eq!(restored.options.repetition_threshold, 2);
+    }
+
+    #[test]
+    fn json_drop() {
+        let mut game: Game = GameBuilder::crazyhouse().build();
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(D8, D5),
+            Move::Drop(PieceKind::Pawn, E2),
+        ]
+        .iter()
+        {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let restored: Game = Game::from_json(&game.to_json()).ok().unwrap();
+        assert_eq!(restored.moves(), game.moves());
+        assert_eq!(restored.board(), game.board());
+        assert_eq!(restored.to_json(), game.to_json());
+    }
+
+    #[test]
+    fn json_move() {
+        let game: Game = Game::default();
+        assert_eq!(
+            game.to_json().as_str(),
+            r#"{"initial_board":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1","board":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1","clock":{"white":null,"black":null},"options":{"threefold_repetition":true,"fivefold_repetition":true,"repetition_threshold":3,"auto_promote":null,"max_search_depth":2,"search_time":null,"allow_castling":true},"variant":{"name":"Standard","sp":null},"victory_reason":null,"metadata":{"event":null,"site":null,"date":null,"round":null,"white":null,"black":null,"result":"InProgress","tags":[]},"moves":[]}"#
+        );
+        let m: GameMove = GameMove::new(
+            Move::QueenSideCastle,
+            Color::Black,
+            12,
+            Duration::from_millis(250),
+            Some(Piece::Pawn(Color::White, A7)),
+            Some(Promotion::Knight),
+        )
+        .with_flags(MoveFlags::CASTLE | MoveFlags::CHECK);
+        let value: Value = move_to_value(&m);
+        assert_eq!(move_from_value(&value), Ok(m));
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(Game::from_json("").err(), Some(JsonError::Syntax));
+        assert_eq!(Game::from_json("{").err(), Some(JsonError::Syntax));
+        assert_eq!(Game::from_json("{} x").err(), Some(JsonError::Syntax));
+        assert_eq!(Game::from_json("[1.5]").err(), Some(JsonError::Syntax));
+        assert_eq!(
+            Game::from_json("{}").err(),
+            Some(JsonError::BadField("initial_board"))
+        );
+        assert_eq!(
+            Game::from_json(r#"{"initial_board": "8/8 w - -"}"#).err(),
+            Some(JsonError::BadBoard(FenError::BadPiecePlacement))
+        );
+        let json: String = Game::default().to_json().replace("InProgress", "Checkmate");
+        assert_eq!(
+            Game::from_json(&json).err(),
+            Some(JsonError::BadField("result"))
+        );
+        let json: String = Game::default().to_json().replace("InProgress", "WhiteWins");
+        assert_eq!(
+            Game::from_json(&json).err(),
+            Some(JsonError::BadGame(GameBuildError::ResultMismatch))
+        );
+        assert_eq!(
+            JsonError::BadField("result").to_string().as_str(),
+            "Bad field 'result'"
+        );
+    }
+
+    #[test]
+    fn json_parser() {
+        assert_eq!(
+            Parser::new(r#" { "a" : [ 1 , true, null, "\u00e8\ud83d\ude00\"\\\/" ] , "b": {} } "#)
+                .parse(),
+            Ok(Value::Object(vec![
+                field(
+                    "a",
+                    Value::Array(vec![
+                        Value::Number(1),
+                        Value::Bool(true),
+                        Value::Null,
+                        Value::String(String::from("è😀\"\\/")),
+                    ])
+                ),
+                field("b", Value::Object(vec![])),
+            ]))
+        );
+        assert_eq!(Parser::new("[1,]").parse(), Err(JsonError::Syntax));
+        assert_eq!(Parser::new("\"\\x\"").parse(), Err(JsonError::Syntax));
+        assert_eq!(
+            Parser::new("99999999999999999999999").parse(),
+            Err(JsonError::Syntax)
+        );
+        let mut out: String = String::new();
+        Value::String(String::from("a\"b\\c\n\u{1}")).encode(&mut out);
+        assert_eq!(out.as_str(), r#""a\"b\\c\n\u0001""#);
+    }
+}
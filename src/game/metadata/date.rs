@@ -3,6 +3,29 @@
 //! Date module for metadata.
 //! Since this library is no-std, it exposes an internal Date type with validation
 
+/// ## DateError
+///
+/// Describes why a date is invalid
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DateError {
+    /// Month is not in range [1-12]
+    BadMonth,
+    /// Day is not in range [1-31]
+    BadDay,
+    /// Date doesn't exist in calendar (e.g. April 31st)
+    NonExistentDate,
+}
+
+impl core::fmt::Display for DateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadMonth => write!(f, "month must be in range [1-12]"),
+            Self::BadDay => write!(f, "day must be in range [1-31]"),
+            Self::NonExistentDate => write!(f, "date doesn't exist"),
+        }
+    }
+}
+
 /// ## Date
 ///
 /// Date type for metadata
@@ -17,15 +40,34 @@ impl Date {
     /// ### new
     ///
     /// Create a new date.
-    /// Panics if date is invalid!
+    ///
+    /// # Panics
+    ///
+    /// Panics if date is invalid! Use `try_new` to validate dates coming from user input
     pub fn new(year: u16, month: u8, day: u8) -> Self {
-        // Validate date
-        match Self::validate(year, month, day) {
+        match Self::try_new(year, month, day) {
             Ok(dt) => dt,
             Err(err) => panic!("Invalid date: {}", err),
         }
     }
 
+    /// ### try_new
+    ///
+    /// Create a new date, validating it.
+    /// If the date is not valid, returns the reason why
+    pub fn try_new(year: u16, month: u8, day: u8) -> Result<Self, DateError> {
+        if !Self::is_month_valid(month) {
+            return Err(DateError::BadMonth);
+        }
+        if !Self::is_day_valid(day) {
+            return Err(DateError::BadDay);
+        }
+        if !Self::date_exists(day, month, year) {
+            return Err(DateError::NonExistentDate);
+        }
+        Ok(Date { year, month, day })
+    }
+
     // -- getter
 
     /// ### year
@@ -51,23 +93,6 @@ impl Date {
 
     // -- validation
 
-    /// ### validate
-    ///
-    /// Validate date and return a date if valid.
-    /// If not valid return an error message
-    fn validate(year: u16, month: u8, day: u8) -> Result<Self, &'static str> {
-        if !Self::is_month_valid(month) {
-            return Err("month must be in range [1-12]");
-        }
-        if !Self::is_day_valid(day) {
-            return Err("day must be in range [1-31]");
-        }
-        if !Self::date_exists(day, month, year) {
-            return Err("date doesn't exist");
-        }
-        Ok(Date { year, month, day })
-    }
-
     /// ### is_month_valid
     ///
     /// Checks whether month is in range [1-12]
@@ -116,19 +141,55 @@ mod test {
 
     use super::*;
 
+    use alloc::string::ToString;
     use pretty_assertions::assert_eq;
 
     #[test]
-    fn validate_date() {
-        assert!(Date::validate(2021, 08, 08).is_ok());
-        assert!(Date::validate(2020, 02, 29).is_ok());
-        assert!(Date::validate(2021, 02, 28).is_ok());
-        assert!(Date::validate(2021, 12, 31).is_ok());
-        assert!(Date::validate(2021, 04, 30).is_ok());
-        assert!(Date::validate(1200, 02, 29).is_ok());
-        assert!(Date::validate(2021, 04, 31).is_err());
-        assert!(Date::validate(2021, 02, 29).is_err());
-        assert!(Date::validate(1800, 02, 29).is_err());
+    fn try_new_date() {
+        assert!(Date::try_new(2021, 8, 8).is_ok());
+        assert!(Date::try_new(2020, 2, 29).is_ok());
+        assert!(Date::try_new(2021, 2, 28).is_ok());
+        assert!(Date::try_new(2021, 12, 31).is_ok());
+        assert!(Date::try_new(2021, 4, 30).is_ok());
+        assert!(Date::try_new(1200, 2, 29).is_ok());
+        assert_eq!(
+            Date::try_new(2021, 4, 31).err().unwrap(),
+            DateError::NonExistentDate
+        );
+        assert_eq!(
+            Date::try_new(2021, 2, 29).err().unwrap(),
+            DateError::NonExistentDate
+        );
+        assert_eq!(
+            Date::try_new(1800, 2, 29).err().unwrap(),
+            DateError::NonExistentDate
+        );
+        assert_eq!(
+            Date::try_new(2021, 0, 1).err().unwrap(),
+            DateError::BadMonth
+        );
+        assert_eq!(
+            Date::try_new(2021, 13, 1).err().unwrap(),
+            DateError::BadMonth
+        );
+        assert_eq!(Date::try_new(2021, 1, 0).err().unwrap(), DateError::BadDay);
+        assert_eq!(Date::try_new(2021, 1, 32).err().unwrap(), DateError::BadDay);
+    }
+
+    #[test]
+    fn date_error_display() {
+        assert_eq!(
+            DateError::BadMonth.to_string().as_str(),
+            "month must be in range [1-12]"
+        );
+        assert_eq!(
+            DateError::BadDay.to_string().as_str(),
+            "day must be in range [1-31]"
+        );
+        assert_eq!(
+            DateError::NonExistentDate.to_string().as_str(),
+            "date doesn't exist"
+        );
     }
 
     #[test]
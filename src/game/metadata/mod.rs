@@ -4,16 +4,41 @@
 //!
 
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
 
 // -- modules
 mod date;
 mod location;
 mod player;
+mod round;
 
 // -- export
-pub use date::Date;
+pub use date::{Date, DateError};
 pub use location::Location;
-pub use player::Player;
+pub use player::{Player, Title};
+pub use round::Round;
+
+/// ## TagError
+///
+/// Describes why a custom tag key is not a valid PGN symbol
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TagError {
+    /// The key is empty
+    EmptyKey,
+    /// The key doesn't start with an alphanumeric character or contains characters other than
+    /// alphanumerics and underscores
+    BadKey,
+}
+
+impl core::fmt::Display for TagError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::EmptyKey => write!(f, "tag key is empty"),
+            Self::BadKey => write!(f, "tag key is not a valid PGN symbol"),
+        }
+    }
+}
 
 /// ## Metadata
 ///
@@ -28,14 +53,16 @@ pub struct Metadata {
     site: Option<Location>,
     /// Starting date of the game
     date: Option<Date>,
-    /// Round number
-    round: Option<u8>,
+    /// Round of the event
+    round: Option<Round>,
     /// Data of player moving white pieces
     white: Option<Player>,
     /// Data of player moving black pieces
     black: Option<Player>,
     /// Result of the game
     result: Result,
+    /// Custom PGN tags (e.g. `TimeControl`, `ECO`, ...), in insertion order
+    tags: Vec<(String, String)>,
 }
 
 /// ## ResultTag
@@ -57,6 +84,26 @@ pub enum Result {
     Unknown,
 }
 
+impl Result {
+    /// ### token
+    ///
+    /// Get the PGN game termination marker for the result (`1-0`, `0-1`, `1/2-1/2` or `*`)
+    pub fn token(&self) -> &'static str {
+        match self {
+            Result::WhiteWins => "1-0",
+            Result::BlackWins => "0-1",
+            Result::DrawnGame => "1/2-1/2",
+            Result::InProgress | Result::Abandoned | Result::Unknown => "*",
+        }
+    }
+}
+
+impl core::fmt::Display for Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.token())
+    }
+}
+
 /// ## Country
 ///
 /// Country as specified in the list of the "International Olympic Committee"
@@ -271,6 +318,457 @@ pub enum Country {
     Zimbabwe,
 }
 
+/// List of all the countries
+pub(crate) const COUNTRIES: &[Country] = &[
+    Country::Afghanistan,
+    Country::Albania,
+    Country::Algeria,
+    Country::Andorra,
+    Country::Angola,
+    Country::AntiguaAndBarbuda,
+    Country::Argentina,
+    Country::Armenia,
+    Country::Aruba,
+    Country::AmericanSamoa,
+    Country::Australia,
+    Country::Austria,
+    Country::Azerbaijan,
+    Country::Bahamas,
+    Country::Bangladesh,
+    Country::Barbados,
+    Country::Burundi,
+    Country::Belgium,
+    Country::Benin,
+    Country::Bermuda,
+    Country::Bhutan,
+    Country::BosniaAndHerzegovina,
+    Country::Belize,
+    Country::Belarus,
+    Country::Bolivia,
+    Country::Botswana,
+    Country::Brazil,
+    Country::Bahrain,
+    Country::Brunei,
+    Country::Bulgaria,
+    Country::BurkinaFaso,
+    Country::CentralAfricanRepublic,
+    Country::Cambodia,
+    Country::Canada,
+    Country::CaymanIslands,
+    Country::RepublicOfTheCongo,
+    Country::Chad,
+    Country::Chile,
+    Country::China,
+    Country::IvoryCoast,
+    Country::Cameroon,
+    Country::DemocraticRepublicOfTheCongo,
+    Country::CookIslands,
+    Country::Colombia,
+    Country::Comoros,
+    Country::CapeVerde,
+    Country::CostaRica,
+    Country::Croatia,
+    Country::Cuba,
+    Country::Cyprus,
+    Country::CzechRepublic,
+    Country::Denmark,
+    Country::Djibouti,
+    Country::Dominica,
+    Country::DominicanRepublic,
+    Country::Ecuador,
+    Country::Egypt,
+    Country::Eritrea,
+    Country::ElSalvador,
+    Country::Spain,
+    Country::Estonia,
+    Country::Ethiopia,
+    Country::Fiji,
+    Country::Finland,
+    Country::France,
+    Country::FederatedStatesOfMicronesia,
+    Country::Gabon,
+    Country::TheGambia,
+    Country::GreatBritain,
+    Country::GuineaBissau,
+    Country::Georgia,
+    Country::EquatorialGuinea,
+    Country::Germany,
+    Country::Ghana,
+    Country::Greece,
+    Country::Grenada,
+    Country::Guatemala,
+    Country::Guinea,
+    Country::Guam,
+    Country::Guyana,
+    Country::Haiti,
+    Country::HongKong,
+    Country::Honduras,
+    Country::Hungary,
+    Country::Indonesia,
+    Country::India,
+    Country::Iran,
+    Country::Ireland,
+    Country::Iraq,
+    Country::Iceland,
+    Country::Israel,
+    Country::VirginIslands,
+    Country::Italy,
+    Country::BritishVirginIslands,
+    Country::Jamaica,
+    Country::Jordan,
+    Country::Japan,
+    Country::Kazakhstan,
+    Country::Kenya,
+    Country::Kyrgyzstan,
+    Country::Kiribati,
+    Country::SouthKorea,
+    Country::Kosovo,
+    Country::SaudiArabia,
+    Country::Kuwait,
+    Country::Laos,
+    Country::Latvia,
+    Country::Libya,
+    Country::Lebanon,
+    Country::Liberia,
+    Country::SaintLucia,
+    Country::Lesotho,
+    Country::Liechtenstein,
+    Country::Lithuania,
+    Country::Luxembourg,
+    Country::Madagascar,
+    Country::Morocco,
+    Country::Malaysia,
+    Country::Malawi,
+    Country::Moldova,
+    Country::Maldives,
+    Country::Mexico,
+    Country::Mongolia,
+    Country::MarshallIslands,
+    Country::NorthMacedonia,
+    Country::Mali,
+    Country::Malta,
+    Country::Montenegro,
+    Country::Monaco,
+    Country::Mozambique,
+    Country::Mauritius,
+    Country::Mauritania,
+    Country::Myanmar,
+    Country::Namibia,
+    Country::Nicaragua,
+    Country::Netherlands,
+    Country::Nepal,
+    Country::Nigeria,
+    Country::Niger,
+    Country::Norway,
+    Country::Nauru,
+    Country::NewZealand,
+    Country::Oman,
+    Country::Pakistan,
+    Country::Panama,
+    Country::Paraguay,
+    Country::Peru,
+    Country::Philippines,
+    Country::Palestine,
+    Country::Palau,
+    Country::PapuaNewGuinea,
+    Country::Poland,
+    Country::Portugal,
+    Country::NorthKorea,
+    Country::PuertoRico,
+    Country::Qatar,
+    Country::Romania,
+    Country::SouthAfrica,
+    Country::Russia,
+    Country::Rwanda,
+    Country::Samoa,
+    Country::Senegal,
+    Country::Seychelles,
+    Country::Singapore,
+    Country::SaintKittsAndNevis,
+    Country::SierraLeone,
+    Country::Slovenia,
+    Country::SanMarino,
+    Country::SolomonIslands,
+    Country::Somalia,
+    Country::Serbia,
+    Country::SriLanka,
+    Country::SouthSudan,
+    Country::SaoTomeAndPrincipe,
+    Country::Sudan,
+    Country::Switzerland,
+    Country::Suriname,
+    Country::Slovakia,
+    Country::Sweden,
+    Country::Eswatini,
+    Country::Syria,
+    Country::Tanzania,
+    Country::Tonga,
+    Country::Thailand,
+    Country::Tajikistan,
+    Country::Turkmenistan,
+    Country::EastTimor,
+    Country::Togo,
+    Country::ChineseTaipei,
+    Country::TrinidadAndTobago,
+    Country::Tunisia,
+    Country::Turkey,
+    Country::Tuvalu,
+    Country::UnitedArabEmirates,
+    Country::Uganda,
+    Country::Ukraine,
+    Country::Uruguay,
+    Country::UnitedStates,
+    Country::Uzbekistan,
+    Country::Vanuatu,
+    Country::Venezuela,
+    Country::Vietnam,
+    Country::SaintVincentAndTheGrenadines,
+    Country::Yemen,
+    Country::Zambia,
+    Country::Zimbabwe,
+];
+
+impl Country {
+    /// ### code
+    ///
+    /// Get the IOC three-letter code for country (e.g. `ITA`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Afghanistan => "AFG",
+            Self::Albania => "ALB",
+            Self::Algeria => "ALG",
+            Self::Andorra => "AND",
+            Self::Angola => "ANG",
+            Self::AntiguaAndBarbuda => "ANT",
+            Self::Argentina => "ARG",
+            Self::Armenia => "ARM",
+            Self::Aruba => "ARU",
+            Self::AmericanSamoa => "ASA",
+            Self::Australia => "AUS",
+            Self::Austria => "AUT",
+            Self::Azerbaijan => "AZE",
+            Self::Bahamas => "BAH",
+            Self::Bangladesh => "BAN",
+            Self::Barbados => "BAR",
+            Self::Burundi => "BDI",
+            Self::Belgium => "BEL",
+            Self::Benin => "BEN",
+            Self::Bermuda => "BER",
+            Self::Bhutan => "BHU",
+            Self::BosniaAndHerzegovina => "BIH",
+            Self::Belize => "BIZ",
+            Self::Belarus => "BLR",
+            Self::Bolivia => "BOL",
+            Self::Botswana => "BOT",
+            Self::Brazil => "BRA",
+            Self::Bahrain => "BRN",
+            Self::Brunei => "BRU",
+            Self::Bulgaria => "BUL",
+            Self::BurkinaFaso => "BUR",
+            Self::CentralAfricanRepublic => "CAF",
+            Self::Cambodia => "CAM",
+            Self::Canada => "CAN",
+            Self::CaymanIslands => "CAY",
+            Self::RepublicOfTheCongo => "CGO",
+            Self::Chad => "CHA",
+            Self::Chile => "CHI",
+            Self::China => "CHN",
+            Self::IvoryCoast => "CIV",
+            Self::Cameroon => "CMR",
+            Self::DemocraticRepublicOfTheCongo => "COD",
+            Self::CookIslands => "COK",
+            Self::Colombia => "COL",
+            Self::Comoros => "COM",
+            Self::CapeVerde => "CPV",
+            Self::CostaRica => "CRC",
+            Self::Croatia => "CRO",
+            Self::Cuba => "CUB",
+            Self::Cyprus => "CYP",
+            Self::CzechRepublic => "CZE",
+            Self::Denmark => "DEN",
+            Self::Djibouti => "DJI",
+            Self::Dominica => "DMA",
+            Self::DominicanRepublic => "DOM",
+            Self::Ecuador => "ECU",
+            Self::Egypt => "EGY",
+            Self::Eritrea => "ERI",
+            Self::ElSalvador => "ESA",
+            Self::Spain => "ESP",
+            Self::Estonia => "EST",
+            Self::Ethiopia => "ETH",
+            Self::Fiji => "FIJ",
+            Self::Finland => "FIN",
+            Self::France => "FRA",
+            Self::FederatedStatesOfMicronesia => "FSM",
+            Self::Gabon => "GAB",
+            Self::TheGambia => "GAM",
+            Self::GreatBritain => "GBR",
+            Self::GuineaBissau => "GBS",
+            Self::Georgia => "GEO",
+            Self::EquatorialGuinea => "GEQ",
+            Self::Germany => "GER",
+            Self::Ghana => "GHA",
+            Self::Greece => "GRE",
+            Self::Grenada => "GRN",
+            Self::Guatemala => "GUA",
+            Self::Guinea => "GUI",
+            Self::Guam => "GUM",
+            Self::Guyana => "GUY",
+            Self::Haiti => "HAI",
+            Self::HongKong => "HKG",
+            Self::Honduras => "HON",
+            Self::Hungary => "HUN",
+            Self::Indonesia => "INA",
+            Self::India => "IND",
+            Self::Iran => "IRI",
+            Self::Ireland => "IRL",
+            Self::Iraq => "IRQ",
+            Self::Iceland => "ISL",
+            Self::Israel => "ISR",
+            Self::VirginIslands => "ISV",
+            Self::Italy => "ITA",
+            Self::BritishVirginIslands => "IVB",
+            Self::Jamaica => "JAM",
+            Self::Jordan => "JOR",
+            Self::Japan => "JPN",
+            Self::Kazakhstan => "KAZ",
+            Self::Kenya => "KEN",
+            Self::Kyrgyzstan => "KGZ",
+            Self::Kiribati => "KIR",
+            Self::SouthKorea => "KOR",
+            Self::Kosovo => "KOS",
+            Self::SaudiArabia => "KSA",
+            Self::Kuwait => "KUW",
+            Self::Laos => "LAO",
+            Self::Latvia => "LAT",
+            Self::Libya => "LBA",
+            Self::Lebanon => "LBN",
+            Self::Liberia => "LBR",
+            Self::SaintLucia => "LCA",
+            Self::Lesotho => "LES",
+            Self::Liechtenstein => "LIE",
+            Self::Lithuania => "LTU",
+            Self::Luxembourg => "LUX",
+            Self::Madagascar => "MAD",
+            Self::Morocco => "MAR",
+            Self::Malaysia => "MAS",
+            Self::Malawi => "MAW",
+            Self::Moldova => "MDA",
+            Self::Maldives => "MDV",
+            Self::Mexico => "MEX",
+            Self::Mongolia => "MGL",
+            Self::MarshallIslands => "MHL",
+            Self::NorthMacedonia => "MKD",
+            Self::Mali => "MLI",
+            Self::Malta => "MLT",
+            Self::Montenegro => "MNE",
+            Self::Monaco => "MON",
+            Self::Mozambique => "MOZ",
+            Self::Mauritius => "MRI",
+            Self::Mauritania => "MTN",
+            Self::Myanmar => "MYA",
+            Self::Namibia => "NAM",
+            Self::Nicaragua => "NCA",
+            Self::Netherlands => "NED",
+            Self::Nepal => "NEP",
+            Self::Nigeria => "NGR",
+            Self::Niger => "NIG",
+            Self::Norway => "NOR",
+            Self::Nauru => "NRU",
+            Self::NewZealand => "NZL",
+            Self::Oman => "OMA",
+            Self::Pakistan => "PAK",
+            Self::Panama => "PAN",
+            Self::Paraguay => "PAR",
+            Self::Peru => "PER",
+            Self::Philippines => "PHI",
+            Self::Palestine => "PLE",
+            Self::Palau => "PLW",
+            Self::PapuaNewGuinea => "PNG",
+            Self::Poland => "POL",
+            Self::Portugal => "POR",
+            Self::NorthKorea => "PRK",
+            Self::PuertoRico => "PUR",
+            Self::Qatar => "QAT",
+            Self::Romania => "ROU",
+            Self::SouthAfrica => "RSA",
+            Self::Russia => "RUS",
+            Self::Rwanda => "RWA",
+            Self::Samoa => "SAM",
+            Self::Senegal => "SEN",
+            Self::Seychelles => "SEY",
+            Self::Singapore => "SGP",
+            Self::SaintKittsAndNevis => "SKN",
+            Self::SierraLeone => "SLE",
+            Self::Slovenia => "SLO",
+            Self::SanMarino => "SMR",
+            Self::SolomonIslands => "SOL",
+            Self::Somalia => "SOM",
+            Self::Serbia => "SRB",
+            Self::SriLanka => "SRI",
+            Self::SouthSudan => "SSD",
+            Self::SaoTomeAndPrincipe => "STP",
+            Self::Sudan => "SUD",
+            Self::Switzerland => "SUI",
+            Self::Suriname => "SUR",
+            Self::Slovakia => "SVK",
+            Self::Sweden => "SWE",
+            Self::Eswatini => "SWZ",
+            Self::Syria => "SYR",
+            Self::Tanzania => "TAN",
+            Self::Tonga => "TGA",
+            Self::Thailand => "THA",
+            Self::Tajikistan => "TJK",
+            Self::Turkmenistan => "TKM",
+            Self::EastTimor => "TLS",
+            Self::Togo => "TOG",
+            Self::ChineseTaipei => "TPE",
+            Self::TrinidadAndTobago => "TTO",
+            Self::Tunisia => "TUN",
+            Self::Turkey => "TUR",
+            Self::Tuvalu => "TUV",
+            Self::UnitedArabEmirates => "UAE",
+            Self::Uganda => "UGA",
+            Self::Ukraine => "UKR",
+            Self::Uruguay => "URU",
+            Self::UnitedStates => "USA",
+            Self::Uzbekistan => "UZB",
+            Self::Vanuatu => "VAN",
+            Self::Venezuela => "VEN",
+            Self::Vietnam => "VIE",
+            Self::SaintVincentAndTheGrenadines => "VIN",
+            Self::Yemen => "YEM",
+            Self::Zambia => "ZAM",
+            Self::Zimbabwe => "ZIM",
+        }
+    }
+
+    /// ### from_code
+    ///
+    /// Get country from its IOC three-letter code (case insensitive).
+    /// Returns `None` if the code is unknown
+    pub fn from_code(code: &str) -> Option<Self> {
+        COUNTRIES
+            .iter()
+            .find(|x| x.code().eq_ignore_ascii_case(code))
+            .copied()
+    }
+}
+
+impl core::fmt::Display for Country {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Country {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Self::from_code(s).ok_or("Unknown country code")
+    }
+}
+
 // -- metadata implementation
 
 impl Default for Metadata {
@@ -283,6 +781,7 @@ impl Default for Metadata {
             white: None,
             black: None,
             result: Result::InProgress,
+            tags: Vec::new(),
         }
     }
 }
@@ -314,8 +813,8 @@ impl Metadata {
     /// ### round
     ///
     /// Get metadata round
-    pub fn round(&self) -> Option<u8> {
-        self.round
+    pub fn round(&self) -> Option<&Round> {
+        self.round.as_ref()
     }
 
     /// ### white_player
@@ -339,6 +838,23 @@ impl Metadata {
         self.result
     }
 
+    /// ### get_tag
+    ///
+    /// Get the value of a custom tag
+    pub fn get_tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// ### tags
+    ///
+    /// Get custom tags, in insertion order
+    pub fn tags(&self) -> &[(String, String)] {
+        self.tags.as_slice()
+    }
+
     // -- setters
 
     /// ### set_result
@@ -348,6 +864,17 @@ impl Metadata {
         self.result = result;
     }
 
+    /// ### set_tag
+    ///
+    /// Set a custom tag to metadata. If the tag is already set, its value is replaced, keeping its position.
+    /// Typed fields (event, site, date, ...) take precedence over custom tags with the same key when exported.
+    /// Returns error if key is not a valid PGN symbol (only alphanumerics and underscores are allowed)
+    pub fn set_tag(&mut self, key: &str, value: &str) -> core::result::Result<(), TagError> {
+        Self::validate_tag_key(key)?;
+        self.insert_tag(key, value);
+        Ok(())
+    }
+
     // -- constructors
 
     /// ### with_event
@@ -369,20 +896,46 @@ impl Metadata {
     /// ### with_date
     ///
     /// Set date to metadata.
-    /// Panics if date is invalid
+    ///
+    /// # Panics
+    ///
+    /// Panics if date is invalid! Use `try_with_date` if the date comes from user input
     pub fn with_date(mut self, year: u16, month: u8, day: u8) -> Self {
         self.date = Some(Date::new(year, month, day));
         self
     }
 
+    /// ### try_with_date
+    ///
+    /// Set date to metadata.
+    /// Returns error if date is invalid
+    pub fn try_with_date(
+        mut self,
+        year: u16,
+        month: u8,
+        day: u8,
+    ) -> core::result::Result<Self, DateError> {
+        self.date = Some(Date::try_new(year, month, day)?);
+        Ok(self)
+    }
+
     /// ### with_round
     ///
-    /// Set round to metadata
+    /// Set round number to metadata
     pub fn with_round(mut self, round: u8) -> Self {
-        self.round = Some(round);
+        self.round = Some(Round::from(round));
         self
     }
 
+    /// ### with_round_str
+    ///
+    /// Set round to metadata, parsing it from the PGN notation (e.g. `3.1` or `?`).
+    /// Returns error if the round is not valid
+    pub fn with_round_str(mut self, round: &str) -> core::result::Result<Self, &'static str> {
+        self.round = Some(Round::from_str(round)?);
+        Ok(self)
+    }
+
     /// ### with_white_player
     ///
     /// Set white player for metadata
@@ -406,6 +959,44 @@ impl Metadata {
         self.result = result;
         self
     }
+
+    /// ### with_tag
+    ///
+    /// Build metadata with provided custom tag.
+    /// Returns error if key is not a valid PGN symbol
+    pub fn with_tag(mut self, key: &str, value: &str) -> core::result::Result<Self, TagError> {
+        self.set_tag(key, value)?;
+        Ok(self)
+    }
+
+    // -- crate
+
+    /// ### insert_tag
+    ///
+    /// Set a custom tag to metadata, without validating the key
+    pub(crate) fn insert_tag(&mut self, key: &str, value: &str) {
+        match self.tags.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.tags.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    // -- private
+
+    /// ### validate_tag_key
+    ///
+    /// Check whether the provided key is a valid PGN tag name
+    fn validate_tag_key(key: &str) -> core::result::Result<(), TagError> {
+        if key.is_empty() {
+            Err(TagError::EmptyKey)
+        } else if key.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            Ok(())
+        } else {
+            Err(TagError::BadKey)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -415,6 +1006,16 @@ mod test {
 
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn fmt_result() {
+        assert_eq!(Result::WhiteWins.to_string().as_str(), "1-0");
+        assert_eq!(Result::BlackWins.to_string().as_str(), "0-1");
+        assert_eq!(Result::DrawnGame.to_string().as_str(), "1/2-1/2");
+        assert_eq!(Result::InProgress.to_string().as_str(), "*");
+        assert_eq!(Result::Abandoned.to_string().as_str(), "*");
+        assert_eq!(Result::Unknown.to_string().as_str(), "*");
+    }
+
     #[test]
     fn metadata_default() {
         let metadata: Metadata = Metadata::default();
@@ -433,6 +1034,7 @@ mod test {
             round: None,
             site: None,
             white: None,
+            tags: Vec::new(),
         };
         assert_eq!(metadata.black, None);
         assert_eq!(metadata.date, None);
@@ -461,11 +1063,113 @@ mod test {
         assert_eq!(metadata.date().unwrap(), &Date::new(2021, 08, 08));
         assert_eq!(metadata.event().unwrap(), "sagra della porchetta vegana");
         assert_eq!(metadata.result(), Result::BlackWins);
-        assert_eq!(metadata.round().unwrap(), 1);
+        assert_eq!(metadata.round().unwrap(), &Round::from(1));
         assert_eq!(metadata.site().unwrap().city(), "moimacco");
         assert_eq!(
             metadata.white_player().unwrap(),
             &Player::new("garri", "kasparov", Country::Russia, 2851)
         );
     }
+
+    #[test]
+    fn metadata_with_round_str() {
+        let metadata: Metadata = Metadata::default().with_round_str("3.1").ok().unwrap();
+        assert_eq!(metadata.round().unwrap(), &Round::Stages(vec![3, 1]));
+        let metadata: Metadata = Metadata::default().with_round_str("?").ok().unwrap();
+        assert_eq!(metadata.round().unwrap(), &Round::Unknown);
+        assert!(Metadata::default().with_round_str("3.a").is_err());
+    }
+
+    #[test]
+    fn metadata_try_with_date() {
+        let metadata: Metadata = Metadata::default().try_with_date(2021, 8, 8).ok().unwrap();
+        assert_eq!(metadata.date().unwrap(), &Date::new(2021, 8, 8));
+        assert_eq!(
+            Metadata::default()
+                .try_with_date(2021, 2, 29)
+                .err()
+                .unwrap(),
+            DateError::NonExistentDate
+        );
+        assert_eq!(
+            Metadata::default()
+                .try_with_date(2021, 14, 1)
+                .err()
+                .unwrap(),
+            DateError::BadMonth
+        );
+    }
+
+    #[test]
+    fn metadata_tags() {
+        let mut metadata: Metadata = Metadata::default()
+            .with_tag("TimeControl", "40/7200:3600")
+            .ok()
+            .unwrap()
+            .with_tag("ECO", "C55")
+            .ok()
+            .unwrap();
+        assert_eq!(metadata.get_tag("TimeControl"), Some("40/7200:3600"));
+        assert_eq!(metadata.get_tag("ECO"), Some("C55"));
+        assert_eq!(metadata.get_tag("Annotator"), None);
+        // Overwrite keeps order
+        assert!(metadata.set_tag("TimeControl", "300+2").is_ok());
+        assert!(metadata.set_tag("White_Title", "GM").is_ok());
+        assert_eq!(metadata.get_tag("TimeControl"), Some("300+2"));
+        assert_eq!(
+            metadata.tags(),
+            &[
+                (String::from("TimeControl"), String::from("300+2")),
+                (String::from("ECO"), String::from("C55")),
+                (String::from("White_Title"), String::from("GM")),
+            ]
+        );
+    }
+
+    #[test]
+    fn metadata_bad_tag() {
+        let mut metadata: Metadata = Metadata::default();
+        assert_eq!(
+            metadata.set_tag("Time Control", "300+2").err().unwrap(),
+            TagError::BadKey
+        );
+        assert_eq!(
+            metadata.set_tag("_Time", "300+2").err().unwrap(),
+            TagError::BadKey
+        );
+        assert_eq!(
+            metadata.set_tag("", "300+2").err().unwrap(),
+            TagError::EmptyKey
+        );
+        assert!(metadata.tags().is_empty());
+        assert_eq!(
+            Metadata::default()
+                .with_tag("Time Control", "300+2")
+                .err()
+                .unwrap(),
+            TagError::BadKey
+        );
+        assert_eq!(TagError::EmptyKey.to_string().as_str(), "tag key is empty");
+        assert_eq!(
+            TagError::BadKey.to_string().as_str(),
+            "tag key is not a valid PGN symbol"
+        );
+    }
+
+    #[test]
+    fn country_code() {
+        assert_eq!(Country::Italy.code(), "ITA");
+        assert_eq!(Country::Norway.code(), "NOR");
+        assert_eq!(Country::UnitedStates.code(), "USA");
+        assert_eq!(Country::from_code("ita"), Some(Country::Italy));
+        assert_eq!(Country::from_code("XYZ"), None);
+        assert_eq!(Country::Germany.to_string().as_str(), "GER");
+        assert_eq!(Country::from_str("Ned").ok().unwrap(), Country::Netherlands);
+        assert!(Country::from_str("").is_err());
+        // Every country round-trips through its code
+        for country in COUNTRIES.iter() {
+            assert_eq!(Country::from_code(country.code()), Some(*country));
+            assert_eq!(country.code().len(), 3);
+        }
+    }
 }
@@ -3,6 +3,7 @@
 //! Exposes the player data for a chess game
 
 use alloc::string::{String, ToString};
+use core::str::FromStr;
 
 use super::Country;
 
@@ -15,6 +16,68 @@ pub struct Player {
     surname: String,
     nationality: Country,
     elo: u16,
+    title: Option<Title>,
+    fide_id: Option<u32>,
+}
+
+/// ## Title
+///
+/// Describes a FIDE title
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Title {
+    /// Grandmaster
+    GrandMaster,
+    /// International master
+    InternationalMaster,
+    /// FIDE master
+    FideMaster,
+    /// Candidate master
+    CandidateMaster,
+    /// Woman grandmaster
+    WomanGrandMaster,
+    /// Woman international master
+    WomanInternationalMaster,
+    /// Woman FIDE master
+    WomanFideMaster,
+    /// Woman candidate master
+    WomanCandidateMaster,
+}
+
+impl core::fmt::Display for Title {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::GrandMaster => "GM",
+                Self::InternationalMaster => "IM",
+                Self::FideMaster => "FM",
+                Self::CandidateMaster => "CM",
+                Self::WomanGrandMaster => "WGM",
+                Self::WomanInternationalMaster => "WIM",
+                Self::WomanFideMaster => "WFM",
+                Self::WomanCandidateMaster => "WCM",
+            }
+        )
+    }
+}
+
+impl FromStr for Title {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "GM" => Ok(Self::GrandMaster),
+            "IM" => Ok(Self::InternationalMaster),
+            "FM" => Ok(Self::FideMaster),
+            "CM" => Ok(Self::CandidateMaster),
+            "WGM" => Ok(Self::WomanGrandMaster),
+            "WIM" => Ok(Self::WomanInternationalMaster),
+            "WFM" => Ok(Self::WomanFideMaster),
+            "WCM" => Ok(Self::WomanCandidateMaster),
+            _ => Err("Unknown title"),
+        }
+    }
 }
 
 impl Player {
@@ -27,9 +90,27 @@ impl Player {
             surname: surname.as_ref().to_string(),
             nationality,
             elo,
+            title: None,
+            fide_id: None,
         }
     }
 
+    /// ### with_title
+    ///
+    /// Set player's title
+    pub fn with_title(mut self, title: Title) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// ### with_fide_id
+    ///
+    /// Set player's FIDE id
+    pub fn with_fide_id(mut self, fide_id: u32) -> Self {
+        self.fide_id = Some(fide_id);
+        self
+    }
+
     /// ### name
     ///
     /// Get reference to name
@@ -57,6 +138,20 @@ impl Player {
     pub fn elo(&self) -> u16 {
         self.elo
     }
+
+    /// ### title
+    ///
+    /// Get player's title
+    pub fn title(&self) -> Option<Title> {
+        self.title
+    }
+
+    /// ### fide_id
+    ///
+    /// Get player's FIDE id
+    pub fn fide_id(&self) -> Option<u32> {
+        self.fide_id
+    }
 }
 
 #[cfg(test)]
@@ -73,5 +168,36 @@ mod test {
         assert_eq!(player.name(), "magnus");
         assert_eq!(player.nationality(), Country::Norway);
         assert_eq!(player.surname(), "carlsen");
+        assert_eq!(player.title(), None);
+        assert_eq!(player.fide_id(), None);
+        let player: Player = player.with_title(Title::GrandMaster).with_fide_id(1503014);
+        assert_eq!(player.title(), Some(Title::GrandMaster));
+        assert_eq!(player.fide_id(), Some(1503014));
+    }
+
+    #[test]
+    fn title() {
+        assert_eq!(Title::GrandMaster.to_string().as_str(), "GM");
+        assert_eq!(Title::WomanInternationalMaster.to_string().as_str(), "WIM");
+        assert_eq!(
+            Title::from_str("IM").ok().unwrap(),
+            Title::InternationalMaster
+        );
+        assert_eq!(Title::from_str("wfm").ok().unwrap(), Title::WomanFideMaster);
+        assert!(Title::from_str("BOT").is_err());
+        for title in [
+            Title::GrandMaster,
+            Title::InternationalMaster,
+            Title::FideMaster,
+            Title::CandidateMaster,
+            Title::WomanGrandMaster,
+            Title::WomanInternationalMaster,
+            Title::WomanFideMaster,
+            Title::WomanCandidateMaster,
+        ]
+        .iter()
+        {
+            assert_eq!(Title::from_str(&title.to_string()).ok().unwrap(), *title);
+        }
     }
 }
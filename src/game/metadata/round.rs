@@ -0,0 +1,111 @@
+//! # Round
+//!
+//! Describes the round of a game, according to the PGN standard
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// ## Round
+///
+/// The round of the game in the event.
+/// Multi-stage events are described by a sequence of numbers, which are separated by a dot in the PGN notation
+/// (e.g. `3.1` is the first game of the third round)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Round {
+    /// The round is unknown (`?`)
+    Unknown,
+    /// The round, as a sequence of stages
+    Stages(Vec<u16>),
+}
+
+impl Round {
+    /// ### stages
+    ///
+    /// Get the stages of the round. If the round is unknown, the slice is empty
+    pub fn stages(&self) -> &[u16] {
+        match self {
+            Self::Unknown => &[],
+            Self::Stages(stages) => stages.as_slice(),
+        }
+    }
+}
+
+impl From<u8> for Round {
+    fn from(round: u8) -> Self {
+        Self::Stages(vec![round as u16])
+    }
+}
+
+impl core::fmt::Display for Round {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "?"),
+            Self::Stages(stages) => {
+                let stages: Vec<String> = stages.iter().map(|x| format!("{}", x)).collect();
+                write!(f, "{}", stages.join("."))
+            }
+        }
+    }
+}
+
+impl FromStr for Round {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "?" {
+            return Ok(Self::Unknown);
+        }
+        let stages: Vec<u16> = s
+            .split('.')
+            .map(|x| match x.chars().all(|x| x.is_ascii_digit()) {
+                true => x.parse::<u16>().map_err(|_| "Invalid round"),
+                false => Err("Invalid round"),
+            })
+            .collect::<Result<Vec<u16>, &'static str>>()?;
+        Ok(Self::Stages(stages))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_from_u8() {
+        let round: Round = Round::from(4);
+        assert_eq!(round.stages(), &[4]);
+        assert_eq!(round.to_string().as_str(), "4");
+        assert_eq!(Round::Unknown.stages(), &[] as &[u16]);
+    }
+
+    #[test]
+    fn round_round_trip() {
+        let round: Round = Round::from_str("3.1").ok().unwrap();
+        assert_eq!(round, Round::Stages(vec![3, 1]));
+        assert_eq!(round.to_string().as_str(), "3.1");
+        let round: Round = Round::from_str("?").ok().unwrap();
+        assert_eq!(round, Round::Unknown);
+        assert_eq!(round.to_string().as_str(), "?");
+        let round: Round = Round::from_str("4.2.1").ok().unwrap();
+        assert_eq!(round.to_string().as_str(), "4.2.1");
+    }
+
+    #[test]
+    fn bad_round() {
+        assert!(Round::from_str("").is_err());
+        assert!(Round::from_str("3.").is_err());
+        assert!(Round::from_str(".1").is_err());
+        assert!(Round::from_str("3..1").is_err());
+        assert!(Round::from_str("+3").is_err());
+        assert!(Round::from_str("a").is_err());
+        assert!(Round::from_str("70000").is_err());
+        assert!(Round::from_str("-").is_err());
+    }
+}
@@ -4,27 +4,52 @@
 //! the metadata of a match and the played moves. In addition to this, it also adds more sophisticated game logics to complete
 //! the chess game (e.g. the threefold repetition stallmate).
 
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::time::Duration;
 
 // -- modules
 mod builder;
 mod clock;
+mod cursor;
+mod json;
 pub mod metadata;
+mod observers;
+mod openings;
 mod options;
-mod result;
+mod pgn;
+pub mod result;
+mod statistics;
 mod types;
+mod uci;
+mod variant;
 
 // -- imports
-use crate::{Board, Color, Move, MoveResult, Piece, Position, Promotion};
-use metadata::{Metadata, Result as MetadataResult};
+use crate::{
+    Board, CastlingRights, Color, GameStatus, Move, MoveResult, Piece, Position, PositionKey,
+    Promotion,
+};
+use metadata::{Metadata, Result as MetadataResult, Round};
+use observers::Observers;
 
 // -- export
-pub use builder::GameBuilder;
-pub use clock::Clock;
+pub use builder::{GameBuildError, GameBuilder};
+#[cfg(feature = "std")]
+pub use clock::StdTimeSource;
+pub use clock::{Clock, TimeSource, TurnTimer};
+pub use cursor::GameCursor;
+pub use json::JsonError;
+pub use observers::EventCallback;
+pub use openings::EcoCode;
 pub use options::Options;
-pub use result::{EndGame, GameError, GameEvent, GameResult, GameState, VictoryReason};
-pub use types::GameMove;
+pub use result::{
+    DrawClaim, DrawReason, EndGame, GameError, GameEvent, GameResult, GameState, VictoryReason,
+};
+pub use statistics::{GameStatistics, PlayerStatistics};
+pub use types::{GameMove, MoveFlags};
+pub use variant::Variant;
 
 /// ## Game
 ///
@@ -35,24 +60,47 @@ pub use types::GameMove;
 pub struct Game {
     /// Current board state
     board: Board,
+    /// Board state at the beginning of the game
+    initial_board: Board,
     /// Game clocks
     clock: Clock,
     /// Game metadata
     metadata: Metadata,
     /// Game moves
     moves: Vec<GameMove>,
+    /// Occurrences of the positions reached during the game
+    positions: BTreeMap<PositionKey, usize>,
+    /// Half-moves played since the last pawn move or capture
+    halfmove_clock: usize,
     /// Game options
     options: Options,
+    /// Chess variant played
+    variant: Variant,
+    /// Reason of the victory, recorded when the game is won
+    victory_reason: Option<VictoryReason>,
+    /// Player who has offered a draw, if the offer is still pending
+    draw_offer: Option<Color>,
+    /// Callbacks notified of the game events
+    observers: Observers,
 }
 
 impl Default for Game {
     fn default() -> Self {
+        let mut positions: BTreeMap<PositionKey, usize> = BTreeMap::new();
+        positions.insert(Board::default().position_key(), 1);
         Self {
             board: Board::default(),
+            initial_board: Board::default(),
             clock: Clock::new(Duration::MAX, Duration::MAX),
             metadata: Metadata::default(),
             moves: Vec::default(),
+            positions,
+            halfmove_clock: 0,
             options: Options::default(),
+            variant: Variant::default(),
+            victory_reason: None,
+            draw_offer: None,
+            observers: Observers::default(),
         }
     }
 }
@@ -68,6 +116,13 @@ impl Game {
         &self.board
     }
 
+    /// ### initial_board
+    ///
+    /// Get a reference to the board as it was at the beginning of the game
+    pub fn initial_board(&self) -> &Board {
+        &self.initial_board
+    }
+
     /// ### remaining_time
     ///
     /// Get remaining time on the clock.
@@ -91,6 +146,13 @@ impl Game {
         self.moves.as_slice()
     }
 
+    /// ### variant
+    ///
+    /// Get the chess variant played in the game
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
     // -- board getters
 
     /// ### turn
@@ -100,18 +162,128 @@ impl Game {
         (self.board().get_turn(), self.get_turn())
     }
 
+    /// ### captured_pieces
+    ///
+    /// Get the pieces taken by the provided player, in capture order
+    pub fn captured_pieces(&self, by: Color) -> Vec<Piece> {
+        self.moves
+            .iter()
+            .filter(|x| x.player == by)
+            .filter_map(|x| x.piece_taken)
+            .collect()
+    }
+
+    /// ### material_balance
+    ///
+    /// Get the material balance from the white player point of view (positive if white is ahead).
+    /// The balance is computed from the initial board, the captured pieces and the performed promotions.
+    pub fn material_balance(&self) -> i32 {
+        self.initial_board.get_material_advantage(Color::White)
+            + self.moves.iter().map(Self::material_delta).sum::<i32>()
+    }
+
+    /// ### material_history
+    ///
+    /// Get the material balance (white point of view) for each position of the game.
+    /// The first element is the balance of the initial board, while the element at index `n`
+    /// is the balance after `n` half-moves.
+    pub fn material_history(&self) -> Vec<i32> {
+        let mut balance: i32 = self.initial_board.get_material_advantage(Color::White);
+        let mut history: Vec<i32> = Vec::with_capacity(self.moves.len() + 1);
+        history.push(balance);
+        for m in self.moves.iter() {
+            balance += Self::material_delta(m);
+            history.push(balance);
+        }
+        history
+    }
+
+    /// ### moves_san
+    ///
+    /// Get the list of the played moves in SAN (standard algebraic notation).
+    /// Moves are replayed from the initial board, so that each move is formatted against its position.
+    /// Resign is not part of the notation, so it's not reported.
+    pub fn moves_san(&self) -> Vec<String> {
+        let mut board: Board = self.initial_board;
+        let mut san: Vec<String> = Vec::with_capacity(self.moves.len());
+        for m in self.moves.iter().filter(|x| x.itself != Move::Resign) {
+            san.push(m.to_san(&board));
+            board = board.replay_move(m.itself, m.promotion);
+        }
+        san
+    }
+
+    /// ### movetext
+    ///
+    /// Get the PGN movetext for the game, with numbered moves in SAN and the result token at the end
+    /// (e.g. `1. e4 e5 2. Nf3 Nc6 *`)
+    pub fn movetext(&self) -> String {
+        let mut movetext: String = String::new();
+        let moves = self.moves.iter().filter(|x| x.itself != Move::Resign);
+        for (i, (m, san)) in moves.zip(self.moves_san()).enumerate() {
+            match m.player {
+                Color::White => movetext.push_str(&format!("{}. {} ", m.turn, san)),
+                Color::Black if i == 0 => movetext.push_str(&format!("{}... {} ", m.turn, san)),
+                Color::Black => movetext.push_str(&format!("{} ", san)),
+            }
+        }
+        movetext.push_str(self.result_token());
+        movetext
+    }
+
+    /// ### board_at
+    ///
+    /// Get the board as it was after `ply` half-moves, replaying the moves from the initial board.
+    /// `board_at(0)` returns the initial board.
+    /// Returns `None` if `ply` exceeds the amount of played moves.
+    pub fn board_at(&self, ply: usize) -> Option<Board> {
+        if ply > self.moves.len() {
+            return None;
+        }
+        Some(
+            self.moves
+                .iter()
+                .take(ply)
+                .fold(self.initial_board, |board, m| {
+                    board.replay_move(m.itself, m.promotion)
+                }),
+        )
+    }
+
+    /// ### cursor
+    ///
+    /// Get a cursor to walk through the positions of the game, starting from the initial board
+    pub fn cursor(&self) -> GameCursor<'_> {
+        GameCursor::new(self)
+    }
+
     /// ### get_legal_moves
     ///
     /// Get legal moves for current player
     pub fn get_legal_moves(&self) -> Vec<Move> {
-        self.board().get_legal_moves(self.board().get_turn())
+        let board: Board = self.playable_board();
+        board.get_legal_moves(board.get_turn())
     }
 
     /// ### get_piece_legal_moves
     ///
     /// Get legal moves for piece at `pos` position
     pub fn get_piece_legal_moves(&self, pos: Position) -> Vec<Move> {
-        self.board().get_piece_legal_moves(pos)
+        self.playable_board().get_piece_legal_moves(pos)
+    }
+
+    /// ### is_in_check
+    ///
+    /// Returns whether the player to move is in check
+    pub fn is_in_check(&self) -> bool {
+        self.board.is_check()
+    }
+
+    /// ### checkers
+    ///
+    /// Get the pieces giving check to the player to move
+    pub fn checkers(&self) -> Vec<Piece> {
+        self.board.checkers()
     }
 
     /// ### in_progress
@@ -128,36 +300,143 @@ impl Game {
         !self.in_progress()
     }
 
+    /// ### game_state
+    ///
+    /// Get the current game state, recomputed from the board, the clock and the metadata.
+    /// This is the same state reported by the last played move, so it can be used to get the
+    /// status of a game restored from a storage.
+    pub fn game_state(&self) -> GameState {
+        match self.metadata().result() {
+            MetadataResult::InProgress => GameState::Continuing,
+            MetadataResult::WhiteWins => {
+                GameState::Ended(EndGame::Victory(Color::White, self.victory_reason()))
+            }
+            MetadataResult::BlackWins => {
+                GameState::Ended(EndGame::Victory(Color::Black, self.victory_reason()))
+            }
+            MetadataResult::DrawnGame => GameState::Ended(EndGame::Draw(self.draw_reason())),
+            MetadataResult::Abandoned => GameState::Ended(EndGame::Abandoned),
+            MetadataResult::Unknown => {
+                // Result is not trustable; get state from board
+                let loser: Color = self.board().get_turn();
+                if self.board().has_three_checks(!loser) {
+                    GameState::Ended(EndGame::Victory(!loser, VictoryReason::ThreeChecks))
+                } else if self.board().is_checkmate() {
+                    GameState::Ended(EndGame::Victory(!loser, VictoryReason::Checkmate))
+                } else if self.board().is_horde_wiped_out() {
+                    GameState::Ended(EndGame::Victory(
+                        Color::Black,
+                        VictoryReason::AllPiecesCaptured,
+                    ))
+                } else if self.board().is_stalemate() {
+                    GameState::Ended(EndGame::Draw(self.draw_reason()))
+                } else {
+                    GameState::Continuing
+                }
+            }
+        }
+    }
+
+    /// ### winner
+    ///
+    /// Get the winner of the game, if any
+    pub fn winner(&self) -> Option<Color> {
+        match self.metadata().result() {
+            MetadataResult::WhiteWins => Some(Color::White),
+            MetadataResult::BlackWins => Some(Color::Black),
+            _ => None,
+        }
+    }
+
     // -- game
 
     /// ### play_move
     ///
     /// play a move.
     /// You must also provide the time taken to move the piece.
+    /// If `Options::auto_promote` is set, a pawn reaching the last rank is promoted straight away.
+    /// If the game has already ended, returns `Err(GameError::GameEnded)`
     pub fn play_move(&mut self, m: Move, time: Duration) -> GameResult {
+        let result: GameResult = self.move_piece(m, time);
+        self.notify(&result);
+        result
+    }
+
+    /// ### on_event
+    ///
+    /// Register a callback, invoked with the game and the raised events each time a move is played
+    /// with `play_move` or a pawn is promoted with `promote`.
+    /// Callbacks are not invoked if the move is rejected (e.g. illegal move) and they're not cloned along with the game
+    pub fn on_event(&mut self, callback: EventCallback) {
+        self.observers.push(callback);
+    }
+
+    /// ### move_piece
+    ///
+    /// Play a move (see `play_move`), without notifying the observers
+    fn move_piece(&mut self, m: Move, time: Duration) -> GameResult {
+        if self.has_terminated() {
+            return Err(GameError::GameEnded);
+        }
+        if m.is_castle() && !self.options.allow_castling {
+            return Err(GameError::IllegalMove(m));
+        }
         let (player, turn): (Color, u16) = self.turn();
         // sub time and check timeout
         self.sub_time(player, time);
         if self.clock.timeout(player) {
-            self.set_result_win(!player);
+            self.set_result_win(!player, VictoryReason::Timeout);
             return GameResult::Ok((
                 GameState::Ended(EndGame::Victory(!player, VictoryReason::Timeout)),
                 GameEvent::NONE,
             ));
         }
+        // Playing a move declines the draw offered by the opponent
+        if self.draw_offer == Some(!player) {
+            self.draw_offer = None;
+        }
         // Play move
+        let board: Board = self.board;
         let result: MoveResult = self.board.play_move(m);
+        // If the game has ended, keep the final position
+        if matches!(result, MoveResult::Victory(_) | MoveResult::Stalemate) && m != Move::Resign {
+            self.board = self.board.replay_move(m, None);
+        }
         // Handle game result
-        let result: GameResult = self.handle_move_result(result, None);
+        let mut result: GameResult = self.handle_move_result(result, None);
         // Push move, unless illegal
         if !result::was_illegal_move(&result) {
-            self.push_move(m, player, turn, time, self.board().get_taken_piece());
+            let flags: MoveFlags = MoveFlags::for_move(&board, &self.board, m);
+            let piece_taken: Option<Piece> = match m {
+                Move::Resign => None,
+                _ => self.board().get_taken_piece(),
+            };
+            self.push_move(m, player, turn, time, piece_taken, flags);
+            self.track_move(&board, m, piece_taken);
+            // Capture events
+            if piece_taken.is_some() {
+                result = result::set_result_event(result, GameEvent::CAPTURE);
+            }
+            if flags.contains(MoveFlags::EN_PASSANT) {
+                result = result::set_result_event(result, GameEvent::EN_PASSANT);
+            }
         }
         // Check events
         let result: GameResult = self.check_events(result);
+        // Auto promotion
+        let result: GameResult = match (self.options.auto_promote, result) {
+            (Some(promotion), Ok((GameState::Continuing, event)))
+                if event.contains(GameEvent::PROMOTION_AVAILABLE) =>
+            {
+                let promoted: GameResult = self.promote_piece(promotion, Duration::ZERO);
+                let promoted: GameResult = self.check_events(promoted);
+                result::set_result_event(promoted, event - GameEvent::PROMOTION_AVAILABLE)
+            }
+            (_, result) => result,
+        };
         // If is checkmate, set win result
-        if let Ok((GameState::Ended(EndGame::Victory(player, _)), _)) = result {
-            self.set_result_win(player);
+        if let Ok((GameState::Ended(EndGame::Victory(player, reason)), _)) = result {
+            self.set_result_win(player, reason);
         }
         // Return result
         result
@@ -165,33 +444,150 @@ impl Game {
 
     /// ### resign
     ///
-    /// Resign match for current player
+    /// Resign match for current player.
+    /// If the game has already ended, returns `Err(GameError::GameEnded)`
     pub fn resign(&mut self) -> GameResult {
+        if self.has_terminated() {
+            return Err(GameError::GameEnded);
+        }
         self.handle_move_result(self.board.play_move(Move::Resign), None)
     }
 
     /// ### draw
     ///
-    /// Draw game
+    /// Draw game.
+    /// If the game has already ended, returns `Err(GameError::GameEnded)`
     pub fn draw(&mut self) -> GameResult {
+        if self.has_terminated() {
+            return Err(GameError::GameEnded);
+        }
         self.set_result_drawn();
-        Ok((GameState::Ended(EndGame::Draw), GameEvent::NONE))
+        Ok((
+            GameState::Ended(EndGame::Draw(DrawReason::Agreement)),
+            GameEvent::NONE,
+        ))
+    }
+
+    /// ### offer_draw
+    ///
+    /// Offer a draw to the opponent on behalf of the current player.
+    /// The offer stands until the opponent plays a move, which declines it; the opponent accepts it with `draw`.
+    /// Since draws are usually offered after moving, the next move of the offering player reports `GameEvent::DRAW_OFFERED` too.
+    /// If the game has already ended, returns `Err(GameError::GameEnded)`
+    pub fn offer_draw(&mut self) -> GameResult {
+        if self.has_terminated() {
+            return Err(GameError::GameEnded);
+        }
+        self.draw_offer = Some(self.board.get_turn());
+        Ok((self.game_state(), GameEvent::DRAW_OFFERED))
+    }
+
+    /// ### draw_offer
+    ///
+    /// Get the player who has offered a draw, if the offer is still pending
+    pub fn draw_offer(&self) -> Option<Color> {
+        self.draw_offer
+    }
+
+    /// ### abandon
+    ///
+    /// Abandon the game (e.g. a player disconnected).
+    /// If `in_favor_of` is `None`, no winner is awarded and the result is set to `Abandoned`;
+    /// otherwise the provided player wins by forfeit.
+    /// If the game has already ended, returns `Err(GameError::GameEnded)`
+    pub fn abandon(&mut self, in_favor_of: Option<Color>) -> GameResult {
+        if self.has_terminated() {
+            return Err(GameError::GameEnded);
+        }
+        match in_favor_of {
+            Some(winner) => self.set_result_win(winner, VictoryReason::Forfeit),
+            None => self.metadata.set_result(MetadataResult::Abandoned),
+        }
+        Ok((self.game_state(), GameEvent::NONE))
     }
 
     /// ### promote
     ///
     /// Promote the pawn on the last line.
+    /// You must also provide the time taken to choose the promotion, which is charged to the promoting player
+    /// and added to the time of the last move; if the player runs out of time while choosing, the game is lost by timeout.
     /// Returns the GameState.
     /// If there's no pawn to promote, returns `Err(GameError::CantPromote)`
-    pub fn promote(&mut self, promotion: Promotion) -> GameResult {
+    pub fn promote(&mut self, promotion: Promotion, time: Duration) -> GameResult {
+        let result: GameResult = self.promote_piece(promotion, time);
+        self.notify(&result);
+        result
+    }
+
+    /// ### promote_piece
+    ///
+    /// Promote the pawn on the last line (see `promote`), without notifying the observers
+    fn promote_piece(&mut self, promotion: Promotion, time: Duration) -> GameResult {
         if self.board.get_promoting_pawn().is_some() {
+            let player: Color = self.board.get_turn();
+            // sub time and check timeout
+            self.sub_time(player, time);
+            if let Some(m) = self.last_move() {
+                m.time += time;
+            }
+            if self.clock.timeout(player) {
+                self.set_result_win(!player, VictoryReason::Timeout);
+                return GameResult::Ok((
+                    GameState::Ended(EndGame::Victory(!player, VictoryReason::Timeout)),
+                    GameEvent::NONE,
+                ));
+            }
             // Promote piece and return
-            self.handle_move_result(self.board.promote(promotion), Some(promotion))
+            let result: MoveResult = self.board.promote(promotion);
+            // If the game has ended, keep the final position
+            if matches!(result, MoveResult::Victory(_) | MoveResult::Stalemate) {
+                self.board = self.board.promote_pawn(promotion).change_turn();
+            }
+            let result: GameResult = self.handle_move_result(result, Some(promotion));
+            self.track_position();
+            // Check is known only after promotion
+            let flags: MoveFlags = MoveFlags::for_check(&self.board);
+            if let Some(m) = self.last_move() {
+                m.flags.insert(flags);
+            }
+            result
         } else {
             Err(GameError::CantPromote)
         }
     }
 
+    /// ### promote_untimed
+    ///
+    /// Promote the pawn on the last line, without charging any time to the player.
+    /// This is the same as calling `promote` with a zero duration and it's meant for engines
+    pub fn promote_untimed(&mut self, promotion: Promotion) -> GameResult {
+        self.promote(promotion, Duration::ZERO)
+    }
+
+    /// ### play_move_timed
+    ///
+    /// Play a move, charging to the player the time measured by `timer` since the turn started
+    /// (see `play_move`). The next turn starts as soon as the move is played
+    pub fn play_move_timed<T: TimeSource>(
+        &mut self,
+        m: Move,
+        timer: &mut TurnTimer<T>,
+    ) -> GameResult {
+        self.play_move(m, timer.lap())
+    }
+
+    /// ### promote_timed
+    ///
+    /// Promote the pawn on the last line, charging to the player the time measured by `timer` since the move
+    /// was played (see `promote`). The next turn starts as soon as the pawn is promoted
+    pub fn promote_timed<T: TimeSource>(
+        &mut self,
+        promotion: Promotion,
+        timer: &mut TurnTimer<T>,
+    ) -> GameResult {
+        self.promote(promotion, timer.lap())
+    }
+
     // -- clocks
 
     /// ### add_time
@@ -221,14 +617,109 @@ impl Game {
     /// ### is_threefold_repetition
     ///
     /// checks whether in threefold repetition condition.
-    /// Basically checks whether last 3 turns are the same.
+    /// Basically checks whether the current position has occurred at least `Options::repetition_threshold` times
+    /// (3 by default).
     /// This function is public, in case you want to allow player to claim for draw on threefold repetition
     pub fn is_threefold_repetition(&self) -> bool {
-        self.is_n_repetition(3)
+        self.is_n_repetition(self.options.get_repetition_threshold())
+    }
+
+    /// ### can_claim_draw
+    ///
+    /// Returns whether the current player can claim a draw and the reason of the claim.
+    /// A draw can be claimed on threefold repetition (if the game is not automatically drawn on it)
+    /// or on the fifty-move rule.
+    /// Returns `None` if the game has terminated or if there's nothing to claim
+    pub fn can_claim_draw(&self) -> Option<DrawClaim> {
+        if self.has_terminated() {
+            None
+        } else if !self.options.threefold_repetition && self.is_threefold_repetition() {
+            Some(DrawClaim::ThreefoldRepetition)
+        } else if self.halfmove_clock() >= 100 {
+            Some(DrawClaim::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// ### status
+    ///
+    /// Get the most specific status of the game for the player to move.
+    /// The status of the board (see `Board::game_status()`) is returned if the game has ended on the board,
+    /// otherwise repetitions (once the position has occurred `Options::repetition_threshold` times)
+    /// and the fifty-move rule are taken into account, before falling back to check or in progress
+    pub fn status(&self) -> GameStatus {
+        let status: GameStatus = self.board.game_status();
+        if status.is_over() {
+            return status;
+        }
+        let repetitions: usize = self.count_repetitions();
+        if repetitions >= self.options.get_repetition_threshold() {
+            GameStatus::Repetition(repetitions)
+        } else if self.halfmove_clock() >= 100 {
+            GameStatus::FiftyMove
+        } else {
+            status
+        }
+    }
+
+    // -- engine
+
+    /// ### suggest_move
+    ///
+    /// Ask the engine for the best move of the current player, searching up to `Options::max_search_depth`.
+    /// Returns `Move::Resign` if the player has no legal moves.
+    /// Since the crate doesn't own a clock, the time budget (`Options::search_time`) is enforced only by `suggest_move_within`
+    pub fn suggest_move(&self) -> Move {
+        self.playable_board()
+            .get_best_next_move(self.options.max_search_depth)
+            .0
+    }
+
+    /// ### suggest_move_within
+    ///
+    /// Ask the engine for the best move of the current player, using `elapsed` to measure the time spent searching.
+    /// If `Options::search_time` is set, the search is deepened one ply at a time, starting from depth 0, and stops
+    /// once the time budget is spent or `Options::max_search_depth` is reached; the move found by the deepest
+    /// completed search is returned. The budget is checked between searches, so the last search may exceed it.
+    /// Without a time budget, this is the same as `suggest_move`
+    pub fn suggest_move_within<F>(&self, mut elapsed: F) -> Move
+    where
+        F: FnMut() -> Duration,
+    {
+        let budget: Duration = match self.options.search_time {
+            Some(budget) => budget,
+            None => return self.suggest_move(),
+        };
+        let board: Board = self.playable_board();
+        let mut best: Move = board.get_best_next_move(0).0;
+        for depth in 1..=self.options.max_search_depth {
+            if elapsed() >= budget {
+                break;
+            }
+            best = board.get_best_next_move(depth).0;
+        }
+        best
     }
 
     // -- private
 
+    /// ### playable_board
+    ///
+    /// Get the board with the game options applied: if castling is not allowed, the castling rights are removed
+    fn playable_board(&self) -> Board {
+        match self.options.allow_castling {
+            true => self.board,
+            false => {
+                let mut no_castling: CastlingRights = CastlingRights::default();
+                no_castling.disable_all();
+                self.board
+                    .with_castling_rights(Color::White, no_castling)
+                    .with_castling_rights(Color::Black, no_castling)
+            }
+        }
+    }
+
     // -- result
 
     /// ### check_events
@@ -254,6 +745,7 @@ impl Game {
             if self.options.threefold_repetition {
                 // Draw game
                 self.set_result_drawn();
+                result = result::set_result_state(result, self.game_state());
             }
         }
         // Check fivefold repetition
@@ -261,10 +753,37 @@ impl Game {
             // Draw game
             self.set_result_drawn();
             result = result::set_result_event(result, GameEvent::FIVEFOLD_REPETITION);
+            result = result::set_result_state(result, self.game_state());
+        }
+        // Check stalemate
+        if matches!(
+            result,
+            Ok((GameState::Ended(EndGame::Draw(DrawReason::Stalemate)), _))
+        ) {
+            result = result::set_result_event(result, GameEvent::STALEMATE);
+        }
+        // Check fifty-move rule
+        if self.halfmove_clock() >= 100 {
+            result = result::set_result_event(result, GameEvent::FIFTY_MOVE);
+        }
+        // Check draw offer, made by the player who has just moved
+        if self.draw_offer.is_some() && self.draw_offer != Some(self.board().get_turn()) {
+            result = result::set_result_event(result, GameEvent::DRAW_OFFERED);
         }
         result
     }
 
+    /// ### notify
+    ///
+    /// Notify the observers of the events raised by a move, unless the move was rejected
+    fn notify(&mut self, result: &GameResult) {
+        if let Ok((_, event)) = result {
+            let mut observers: Observers = core::mem::take(&mut self.observers);
+            observers.notify(self, *event);
+            self.observers = observers;
+        }
+    }
+
     /// ### handle_move_result
     ///
     /// Given a move result, returns a `GameResult` after updating the board
@@ -286,51 +805,166 @@ impl Game {
                 Ok((GameState::Continuing, GameEvent::NONE))
             }
             MoveResult::Victory(color) => {
-                // Set result and return game ended
-                self.set_result_win(color);
-                Ok((
-                    GameState::Ended(EndGame::Victory(color, VictoryReason::Checkmate)),
-                    GameEvent::NONE,
-                ))
+                // Set result and return game ended; the board ends the game also on resign
+                let reason: VictoryReason = self
+                    .board_victory_reason(color)
+                    .unwrap_or(VictoryReason::Resign);
+                self.set_result_win(color, reason);
+                Ok((self.game_state(), GameEvent::NONE))
             }
             MoveResult::Stalemate => {
                 // Set result and return game ended
                 self.set_result_drawn();
-                Ok((GameState::Ended(EndGame::Draw), GameEvent::NONE))
+                Ok((self.game_state(), GameEvent::NONE))
             }
             MoveResult::IllegalMove(m) => Err(GameError::IllegalMove(m)),
         }
     }
 
+    // -- state
+
+    /// ### victory_reason
+    ///
+    /// Get the reason which brought the winner to victory, as recorded when the game was won
+    fn victory_reason(&self) -> VictoryReason {
+        self.victory_reason.unwrap_or(VictoryReason::Unknown)
+    }
+
+    /// ### board_victory_reason
+    ///
+    /// Get the reason of a victory of `winner` which can be told from the board: checkmate or the winning
+    /// conditions of the variant. Returns `None` if the board doesn't explain the victory
+    fn board_victory_reason(&self, winner: Color) -> Option<VictoryReason> {
+        if self.board().has_three_checks(winner) {
+            Some(VictoryReason::ThreeChecks)
+        } else if winner == Color::Black && self.board().is_horde_wiped_out() {
+            Some(VictoryReason::AllPiecesCaptured)
+        } else if self.board().get_turn() == !winner && self.board().is_checkmate() {
+            Some(VictoryReason::Checkmate)
+        } else {
+            None
+        }
+    }
+
+    /// ### restored_victory_reason
+    ///
+    /// Get the reason of the victory of `winner` for a restored game, whose reason has not been recorded,
+    /// from the board, the moves and the clock. If nothing explains the victory, the reason is `Unknown`
+    fn restored_victory_reason(&self, winner: Color) -> VictoryReason {
+        let resigned: bool = self
+            .moves
+            .last()
+            .map(|x| x.itself == Move::Resign && x.player == !winner)
+            .unwrap_or(false);
+        if let Some(reason) = self.board_victory_reason(winner) {
+            reason
+        } else if resigned {
+            VictoryReason::Resign
+        } else if self.timeout(!winner) {
+            VictoryReason::Timeout
+        } else {
+            VictoryReason::Unknown
+        }
+    }
+
+    /// ### draw_reason
+    ///
+    /// Get the reason which brought the game to a draw
+    fn draw_reason(&self) -> DrawReason {
+        if self.board().is_dead_position() {
+            DrawReason::InsufficientMaterial
+        } else if self.board().is_stalemate() {
+            DrawReason::Stalemate
+        } else if self.is_fivefold_repetition() && self.options.fivefold_repetition {
+            DrawReason::FivefoldRepetition
+        } else if self.is_threefold_repetition() && self.options.threefold_repetition {
+            DrawReason::ThreefoldRepetition
+        } else {
+            DrawReason::Agreement
+        }
+    }
+
     // -- repetitions
 
-    /// ### is_threefold_repetition
+    /// ### is_fivefold_repetition
     ///
     /// checks whether in fivefold repetition condition.
-    /// Basically checks whether last 5 turns are the same
+    /// Basically checks whether the current position has occurred at least 5 times
     fn is_fivefold_repetition(&self) -> bool {
         self.is_n_repetition(5)
     }
 
     /// ### is_n_repetition
     ///
-    /// checks whether the last `repetitions` turns are the equal to each other
+    /// checks whether the current position has occurred at least `repetitions` times
     fn is_n_repetition(&self, repetitions: usize) -> bool {
-        let repetitions: usize = repetitions * 2; // Multiply by 2, since we calculate turns, not moves
-        if self.moves().len() < repetitions || self.moves().is_empty() {
-            false
+        self.count_repetitions() >= repetitions
+    }
+
+    /// ### count_repetitions
+    ///
+    /// Count the times the current position has occurred during the game
+    fn count_repetitions(&self) -> usize {
+        self.positions
+            .get(&self.board.position_key())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// ### halfmove_clock
+    ///
+    /// Get the amount of half-moves played since the last pawn move or capture
+    fn halfmove_clock(&self) -> usize {
+        self.halfmove_clock
+    }
+
+    /// ### track_move
+    ///
+    /// Update the half-move clock after `m` has been played on `board` and count the reached position.
+    /// If a promotion is pending, the position is counted once the pawn has been promoted
+    fn track_move(&mut self, board: &Board, m: Move, piece_taken: Option<Piece>) {
+        if m == Move::Resign {
+            return;
+        }
+        let pawn_move: bool = match m {
+            Move::Piece(from, _) => board.get_piece(from).map(|x| x.is_pawn()) == Some(true),
+            _ => false,
+        };
+        if pawn_move || piece_taken.is_some() {
+            self.halfmove_clock = 0;
         } else {
-            // Get last `repetitions` moves
-            let moves: Vec<&Move> = self
-                .moves()
-                .iter()
-                .rev()
-                .take(repetitions)
-                .map(|x| &x.itself)
-                .collect();
-            let first: &Move = moves[0];
-            moves.iter().all(|x| **x == *first)
+            self.halfmove_clock += 1;
+        }
+        if self.board.get_promoting_pawn().is_none() {
+            self.track_position();
+        }
+    }
+
+    /// ### track_position
+    ///
+    /// Count an occurrence of the current position
+    fn track_position(&mut self) {
+        *self.positions.entry(self.board.position_key()).or_insert(0) += 1;
+    }
+
+    /// ### track_history
+    ///
+    /// Recount the positions and the half-move clock replaying the moves from the initial board.
+    /// Used when the game is restored; then they're updated as moves are played
+    fn track_history(&mut self) {
+        let board: Board = self.board;
+        let moves: Vec<GameMove> = core::mem::take(&mut self.moves);
+        self.positions.clear();
+        self.halfmove_clock = 0;
+        self.board = self.initial_board;
+        self.track_position();
+        for m in moves.iter() {
+            let before: Board = self.board;
+            self.board = before.replay_move(m.itself, m.promotion);
+            self.track_move(&before, m.itself, m.piece_taken);
         }
+        self.board = board;
+        self.moves = moves;
     }
 
     // -- moves
@@ -345,17 +979,49 @@ impl Game {
         turn: u16,
         time: Duration,
         piece_taken: Option<Piece>,
+        flags: MoveFlags,
     ) {
         self.moves
-            .push(GameMove::new(m, player, turn, time, piece_taken, None));
+            .push(GameMove::new(m, player, turn, time, piece_taken, None).with_flags(flags));
+    }
+
+    /// ### material_delta
+    ///
+    /// Get the change in material balance (white point of view) caused by a move
+    fn material_delta(m: &GameMove) -> i32 {
+        let sign: i32 = match m.player {
+            Color::White => 1,
+            Color::Black => -1,
+        };
+        let mut delta: i32 = 0;
+        // Taken pieces
+        if let Some(piece) = m.piece_taken {
+            delta += piece.get_material_value();
+        }
+        // Dropped pieces are added to the board
+        if let Move::Drop(kind, to) = m.itself {
+            delta += Piece::new(kind, m.player, to).get_material_value();
+        }
+        // Promotion replaces a pawn with another piece
+        if let (Some(promotion), Some(to)) = (m.promotion, m.itself.to()) {
+            let promoted: Piece = Piece::from_promotion(promotion, m.player, to);
+            delta += promoted.get_material_value() - Piece::Pawn(m.player, to).get_material_value();
+        }
+        sign * delta
     }
 
     /// ### get_turn
     ///
     /// Get turn number.
-    /// Turn number is `moves.len() / 2) + 1`
+    /// As the fullmove number in FEN, the turn number starts from 1 and it's incremented after every move of the black player;
+    /// so it's correct even for games where black moves first (e.g. Dunsany's chess)
     fn get_turn(&self) -> u16 {
-        ((self.moves.len() / 2) + 1) as u16
+        (self
+            .moves
+            .iter()
+            .filter(|x| x.player == Color::Black)
+            .count()
+            + 1) as u16
     }
 
     /// ### patch_last_move_promotion
@@ -378,8 +1044,9 @@ impl Game {
 
     /// ### set_result_win
     ///
-    /// Set result to win for provided player
-    fn set_result_win(&mut self, color: Color) {
+    /// Set result to win for provided player, recording the reason of the victory
+    fn set_result_win(&mut self, color: Color, reason: VictoryReason) {
+        self.victory_reason = Some(reason);
         self.metadata.set_result(match color {
             Color::Black => MetadataResult::BlackWins,
             Color::White => MetadataResult::WhiteWins,
@@ -394,10 +1061,1310 @@ impl Game {
     }
 }
 
+// -- game fmt
+
+impl core::fmt::Display for Game {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        // Write metadata header
+        let metadata: &Metadata = self.metadata();
+        if let Some(event) = metadata.event() {
+            writeln!(f, "Event: {}", event)?;
+        }
+        if let Some(site) = metadata.site() {
+            writeln!(
+                f,
+                "Site: {}, {} {}",
+                site.city(),
+                site.region(),
+                site.country()
+            )?;
+        }
+        if let Some(date) = metadata.date() {
+            writeln!(
+                f,
+                "Date: {:04}.{:02}.{:02}",
+                date.year(),
+                date.month(),
+                date.day()
+            )?;
+        }
+        if let Some(round) = metadata.round().filter(|x| **x != Round::Unknown) {
+            writeln!(f, "Round: {}", round)?;
+        }
+        if let Some(player) = metadata.white_player() {
+            writeln!(
+                f,
+                "White: {} {} ({})",
+                player.name(),
+                player.surname(),
+                player.elo()
+            )?;
+        }
+        if let Some(player) = metadata.black_player() {
+            writeln!(
+                f,
+                "Black: {} {} ({})",
+                player.name(),
+                player.surname(),
+                player.elo()
+            )?;
+        }
+        // Write board
+        write!(f, "{}", self.board())?;
+        // Write clock
+        let (white_time, black_time): (Duration, Duration) = self.remaining_time();
+        writeln!(f, "White time: {}", fmt_duration(white_time))?;
+        writeln!(f, "Black time: {}", fmt_duration(black_time))?;
+        // Write moves
+        for (i, m) in self.moves().iter().enumerate() {
+            match m.player {
+                Color::White => write!(f, "{}. {}", m.turn, m.itself)?,
+                Color::Black if i == 0 => write!(f, "{}. ... {}", m.turn, m.itself)?,
+                Color::Black => write!(f, " {}", m.itself)?,
+            }
+            if m.player == Color::Black || i + 1 == self.moves().len() {
+                writeln!(f)?;
+            }
+        }
+        // Write result
+        write!(f, "Result: {}", metadata.result())
+    }
+}
+
+/// ### fmt_duration
+///
+/// Format duration as `h:mm:ss`
+fn fmt_duration(time: Duration) -> String {
+    let secs: u64 = time.as_secs();
+    format!("{}:{:02}:{:02}", secs / 3600, (secs / 60) % 60, secs % 60)
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use crate::position::*;
+    use crate::BoardBuilder;
+    use alloc::string::ToString;
+    use metadata::{Country, Player};
 
     use pretty_assertions::assert_eq;
+
+    #[test]
+    fn game_state_checkmate() {
+        let mut game: Game = Game::default();
+        assert_eq!(game.game_state(), GameState::Continuing);
+        assert_eq!(game.winner(), None);
+        // Fool's mate
+        let moves: [Move; 3] = [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let (state, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(D8, H4), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Checkmate))
+        );
+        assert!(event.contains(GameEvent::CHECKMATE));
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.winner(), Some(Color::Black));
+        // Board keeps final position
+        assert!(game.board().is_checkmate());
+        // Restore game
+        let restored: Game = GameBuilder::default()
+            .board(*game.board())
+            .metadata(game.metadata().clone())
+            .build();
+        assert_eq!(restored.game_state(), state);
+        assert_eq!(restored.winner(), Some(Color::Black));
+    }
+
+    #[test]
+    fn game_state_resign() {
+        let mut game: Game = Game::default();
+        let (state, _): (GameState, GameEvent) = game.resign().ok().unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Resign))
+        );
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.winner(), Some(Color::Black));
+    }
+
+    #[test]
+    fn resign_and_draw_game_ended() {
+        // Draw after checkmate (fool's mate)
+        let mut game: Game = Game::default();
+        for m in [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+            Move::Piece(D8, H4),
+        ]
+        .iter()
+        {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let state: GameState =
+            GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Checkmate));
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.draw(), Err(GameError::GameEnded));
+        assert_eq!(game.resign(), Err(GameError::GameEnded));
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.metadata().result(), MetadataResult::BlackWins);
+        // Resign after abandon
+        let mut game: Game = Game::default();
+        assert!(game.abandon(None).is_ok());
+        assert_eq!(game.resign(), Err(GameError::GameEnded));
+        assert_eq!(game.draw(), Err(GameError::GameEnded));
+        assert_eq!(game.game_state(), GameState::Ended(EndGame::Abandoned));
+        assert_eq!(game.metadata().result(), MetadataResult::Abandoned);
+        assert_eq!(game.winner(), None);
+        // Draw after resign
+        let mut game: Game = Game::default();
+        assert!(game.resign().is_ok());
+        assert_eq!(game.draw(), Err(GameError::GameEnded));
+        assert_eq!(game.winner(), Some(Color::Black));
+    }
+
+    #[test]
+    fn game_state_timeout() {
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(10), Duration::from_secs(10))
+            .build();
+        let (state, _): (GameState, GameEvent) = game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(11))
+            .ok()
+            .unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Timeout))
+        );
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.winner(), Some(Color::Black));
+    }
+
+    #[test]
+    fn game_state_unknown_victory_reason() {
+        // Win set through metadata: nothing explains it
+        let mut game: Game = Game::default();
+        game.metadata.set_result(MetadataResult::WhiteWins);
+        assert_eq!(
+            game.game_state(),
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::Unknown))
+        );
+        // Recorded reason is kept
+        let game: Game = GameBuilder::default()
+            .metadata(Metadata::default().with_result(MetadataResult::WhiteWins))
+            .victory_reason(Some(VictoryReason::Forfeit))
+            .build();
+        assert_eq!(
+            game.game_state(),
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::Forfeit))
+        );
+    }
+
+    #[test]
+    fn fmt_game() {
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(3600), Duration::from_secs(3600))
+            .metadata(
+                Metadata::default()
+                    .with_event("Oslo Chess Open")
+                    .with_round(4)
+                    .with_date(2021, 6, 15)
+                    .with_white_player(Player::new("magnus", "carlsen", Country::Norway, 2882)),
+            )
+            .build();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(5))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(65))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(G1, F3), Duration::from_secs(10))
+            .is_ok());
+        let output: String = game.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines.contains(&"Event: Oslo Chess Open"));
+        assert!(lines.contains(&"Date: 2021.06.15"));
+        assert!(lines.contains(&"Round: 4"));
+        assert!(lines.contains(&"White: magnus carlsen (2882)"));
+        assert!(lines.contains(&"White time: 0:59:45"));
+        assert!(lines.contains(&"Black time: 0:58:55"));
+        assert!(lines.contains(&"1. e2 to e4 e7 to e5"));
+        assert!(lines.contains(&"2. g1 to f3"));
+        assert_eq!(lines.last(), Some(&"Result: *"));
+        // Missing fields are skipped
+        assert!(!output.contains("Site:"));
+        assert!(!output.contains("Black:"));
+    }
+
+    #[test]
+    fn turn_black_moves_first() {
+        let mut game: Game = GameBuilder::dunsany().build();
+        assert_eq!(game.turn(), (Color::Black, 1));
+        let moves: [Move; 4] = [
+            Move::Piece(E7, E6),
+            Move::Piece(A4, A5),
+            Move::Piece(D7, D6),
+            Move::Piece(B4, B5),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let turns: Vec<(Color, u16)> = game.moves().iter().map(|x| (x.player, x.turn)).collect();
+        assert_eq!(
+            turns,
+            vec![
+                (Color::Black, 1),
+                (Color::White, 2),
+                (Color::Black, 2),
+                (Color::White, 3)
+            ]
+        );
+        assert_eq!(game.turn(), (Color::Black, 3));
+        assert_eq!(game.movetext().as_str(), "1... e6 2. a5 d6 3. b5 *");
+    }
+
+    #[test]
+    fn abandon() {
+        let mut game: Game = Game::default();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(
+            game.abandon(None),
+            Ok((GameState::Ended(EndGame::Abandoned), GameEvent::NONE))
+        );
+        assert_eq!(game.metadata().result(), MetadataResult::Abandoned);
+        assert_eq!(game.has_terminated(), true);
+        assert_eq!(game.winner(), None);
+        assert_eq!(game.game_state(), GameState::Ended(EndGame::Abandoned));
+        // Further moves are rejected
+        assert_eq!(
+            game.play_move(Move::Piece(E7, E5), Duration::from_secs(1)),
+            Err(GameError::GameEnded)
+        );
+        assert_eq!(game.abandon(None), Err(GameError::GameEnded));
+        assert_eq!(game.moves().len(), 1);
+    }
+
+    #[test]
+    fn abandon_forfeit() {
+        let mut game: Game = Game::default();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        let state: GameState =
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::Forfeit));
+        assert_eq!(
+            game.abandon(Some(Color::White)),
+            Ok((state, GameEvent::NONE))
+        );
+        assert_eq!(game.metadata().result(), MetadataResult::WhiteWins);
+        assert_eq!(game.winner(), Some(Color::White));
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.termination_string(), Some("Abandoned"));
+        assert_eq!(
+            game.play_move(Move::Piece(E7, E5), Duration::from_secs(1)),
+            Err(GameError::GameEnded)
+        );
+        // Forfeit is restored from JSON
+        let restored: Game = Game::from_json(&game.to_json()).ok().unwrap();
+        assert_eq!(restored.game_state(), state);
+    }
+
+    #[test]
+    fn horde_white_wiped_out() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::Black, E8))
+            .piece(Piece::Rook(Color::Black, A8))
+            .piece(Piece::Pawn(Color::White, A4))
+            .player_moving(Color::Black)
+            .build();
+        let mut game: Game = GameBuilder::default()
+            .board(board)
+            .variant(Variant::Horde)
+            .build();
+        assert_eq!(
+            game.play_move(Move::Piece(A8, A4), Duration::from_secs(1))
+                .ok()
+                .unwrap()
+                .0,
+            GameState::Ended(EndGame::Victory(
+                Color::Black,
+                VictoryReason::AllPiecesCaptured
+            ))
+        );
+        assert_eq!(game.metadata().result(), MetadataResult::BlackWins);
+        assert_eq!(game.winner(), Some(Color::Black));
+        // In standard chess, the same position is a stalemate
+        let mut game: Game = GameBuilder::default().board(board).build();
+        let (state, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(A8, A4), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Draw(DrawReason::Stalemate))
+        );
+        assert_eq!(event, GameEvent::CAPTURE | GameEvent::STALEMATE);
+    }
+
+    #[test]
+    fn horde_black_checkmate() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::Black, H8))
+            .piece(Piece::Pawn(Color::Black, G7))
+            .piece(Piece::Pawn(Color::Black, H7))
+            .piece(Piece::Rook(Color::White, A1))
+            .piece(Piece::Pawn(Color::White, B2))
+            .build();
+        let mut game: Game = GameBuilder::default()
+            .board(board)
+            .variant(Variant::Horde)
+            .build();
+        assert_eq!(
+            game.play_move(Move::Piece(A1, A8), Duration::from_secs(1))
+                .ok()
+                .unwrap()
+                .0,
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::Checkmate))
+        );
+        assert_eq!(game.metadata().result(), MetadataResult::WhiteWins);
+    }
+
+    #[test]
+    fn movetext() {
+        let mut game: Game = Game::default();
+        // Scholar's mate
+        let moves: [Move; 7] = [
+            Move::Piece(E2, E4),
+            Move::Piece(E7, E5),
+            Move::Piece(F1, C4),
+            Move::Piece(B8, C6),
+            Move::Piece(D1, H5),
+            Move::Piece(G8, F6),
+            Move::Piece(H5, F7),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(
+            game.moves_san(),
+            vec!["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"]
+        );
+        assert_eq!(
+            game.movetext().as_str(),
+            "1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0"
+        );
+        // Black moves first
+        let mut game: Game = GameBuilder::default()
+            .board(Board::default().with_turn(Color::Black))
+            .build();
+        assert!(game
+            .play_move(Move::Piece(D7, D5), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.movetext().as_str(), "1... d5 *");
+    }
+
+    #[test]
+    fn board_at() {
+        let mut game: Game = Game::default();
+        let mut boards: Vec<Board> = vec![*game.board()];
+        let moves: [Move; 9] = [
+            Move::Piece(A2, A4),
+            Move::Piece(B7, B5),
+            Move::Piece(A4, B5),
+            Move::Piece(A7, A6),
+            Move::Piece(B5, A6),
+            Move::Piece(C8, B7),
+            Move::Piece(A6, B7),
+            Move::Piece(B8, C6),
+            Move::Piece(B7, A8),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+            if game.board().get_promoting_pawn().is_none() {
+                boards.push(*game.board());
+            }
+        }
+        // Promote
+        assert!(game.promote_untimed(Promotion::Queen).is_ok());
+        boards.push(*game.board());
+        assert_eq!(
+            game.board().get_piece(A8),
+            Some(Piece::Queen(Color::White, A8))
+        );
+        for (ply, board) in boards.iter().enumerate() {
+            assert_eq!(game.board_at(ply).as_ref(), Some(board));
+        }
+        assert_eq!(game.board_at(0).unwrap(), *game.initial_board());
+        assert_eq!(game.board_at(game.moves().len()).unwrap(), *game.board());
+        assert_eq!(game.board_at(10), None);
+    }
+
+    /// A time source advancing by `step` every time it's read
+    struct MockTimeSource {
+        time: core::cell::Cell<Duration>,
+        step: Duration,
+    }
+
+    impl TimeSource for MockTimeSource {
+        fn now(&self) -> Duration {
+            let now: Duration = self.time.get();
+            self.time.set(now + self.step);
+            now
+        }
+    }
+
+    #[test]
+    fn play_move_timed() {
+        let mut timer: TurnTimer<MockTimeSource> = TurnTimer::new(MockTimeSource {
+            time: core::cell::Cell::new(Duration::ZERO),
+            step: Duration::from_secs(2),
+        });
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(60), Duration::from_secs(60))
+            .build();
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(E7, E5),
+            Move::Piece(G1, F3),
+        ]
+        .iter()
+        {
+            assert!(game.play_move_timed(*m, &mut timer).is_ok());
+        }
+        assert_eq!(
+            game.remaining_time(),
+            (Duration::from_secs(56), Duration::from_secs(58))
+        );
+        assert!(game
+            .moves()
+            .iter()
+            .all(|m| m.time == Duration::from_secs(2)));
+        // Promotion
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::Pawn(Color::White, A7))
+            .piece(Piece::King(Color::Black, H6))
+            .build();
+        let mut game: Game = GameBuilder::default()
+            .board(board)
+            .timeout(Duration::from_secs(10), Duration::from_secs(10))
+            .build();
+        assert!(game
+            .play_move_timed(Move::Piece(A7, A8), &mut timer)
+            .is_ok());
+        assert!(game.promote_timed(Promotion::Queen, &mut timer).is_ok());
+        assert_eq!(game.moves()[0].time, Duration::from_secs(4));
+    }
+
+    #[test]
+    fn promote_time() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::Pawn(Color::White, A7))
+            .piece(Piece::King(Color::Black, H6))
+            .build();
+        let builder = || {
+            GameBuilder::default()
+                .board(board)
+                .timeout(Duration::from_secs(10), Duration::from_secs(10))
+        };
+        // Time is charged and added to the move
+        let mut game: Game = builder().build();
+        assert!(game
+            .play_move(Move::Piece(A7, A8), Duration::from_secs(2))
+            .is_ok());
+        assert_eq!(
+            game.promote(Promotion::Rook, Duration::from_secs(3)),
+            Ok((GameState::Continuing, GameEvent::NONE))
+        );
+        assert_eq!(
+            game.remaining_time(),
+            (Duration::from_secs(5), Duration::from_secs(10))
+        );
+        assert_eq!(game.moves()[0].time, Duration::from_secs(5));
+        assert_eq!(game.moves()[0].promotion, Some(Promotion::Rook));
+        // Timeout while choosing promotion
+        let mut game: Game = builder().build();
+        assert!(game
+            .play_move(Move::Piece(A7, A8), Duration::from_secs(2))
+            .is_ok());
+        assert_eq!(
+            game.promote(Promotion::Queen, Duration::from_secs(9)),
+            Ok((
+                GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Timeout)),
+                GameEvent::NONE
+            ))
+        );
+        assert_eq!(game.metadata().result(), MetadataResult::BlackWins);
+        assert_eq!(game.moves()[0].promotion, None);
+    }
+
+    #[test]
+    fn auto_promote() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::Pawn(Color::White, A7))
+            .piece(Piece::King(Color::Black, H6))
+            .build();
+        let mut game: Game = GameBuilder::default()
+            .board(board)
+            .options(Options::default().auto_promote(Some(Promotion::Queen)))
+            .build();
+        assert_eq!(
+            game.play_move(Move::Piece(A7, A8), Duration::from_secs(1)),
+            Ok((GameState::Continuing, GameEvent::NONE))
+        );
+        assert_eq!(
+            game.board().get_piece(A8),
+            Some(Piece::Queen(Color::White, A8))
+        );
+        assert_eq!(game.moves()[0].promotion, Some(Promotion::Queen));
+        assert_eq!(game.turn(), (Color::Black, 1));
+        assert_eq!(
+            game.promote_untimed(Promotion::Queen),
+            Err(GameError::CantPromote)
+        );
+    }
+
+    #[test]
+    fn auto_promote_checkmate() {
+        let board: Board = BoardBuilder::default()
+            .piece(Piece::King(Color::White, E1))
+            .piece(Piece::Pawn(Color::White, B7))
+            .piece(Piece::King(Color::Black, H8))
+            .piece(Piece::Pawn(Color::Black, G7))
+            .piece(Piece::Pawn(Color::Black, H7))
+            .build();
+        let mut game: Game = GameBuilder::default()
+            .board(board)
+            .options(Options::default().auto_promote(Some(Promotion::Queen)))
+            .build();
+        let (state, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(B7, B8), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::Checkmate))
+        );
+        assert!(event.contains(GameEvent::CHECKMATE));
+        assert!(!event.contains(GameEvent::PROMOTION_AVAILABLE));
+        assert_eq!(game.metadata().result(), MetadataResult::WhiteWins);
+        assert_eq!(game.moves()[0].promotion, Some(Promotion::Queen));
+        assert!(game.moves()[0].flags.contains(MoveFlags::CHECKMATE));
+    }
+
+    #[test]
+    fn can_claim_draw() {
+        let mut game: Game = GameBuilder::default()
+            .options(Options::default().threefold_repetition(false))
+            .build();
+        assert_eq!(game.can_claim_draw(), None);
+        let moves: [Move; 4] = [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ];
+        // Position repeated twice
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_threefold_repetition(), false);
+        assert_eq!(game.can_claim_draw(), None);
+        // Position repeated three times
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_threefold_repetition(), true);
+        assert_eq!(game.can_claim_draw(), Some(DrawClaim::ThreefoldRepetition));
+        assert_eq!(game.in_progress(), true);
+        // With auto-draw, game is drawn and there's nothing to claim
+        let mut game: Game = Game::default();
+        for m in moves.iter().chain(moves.iter()) {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(
+            game.game_state(),
+            GameState::Ended(EndGame::Draw(DrawReason::ThreefoldRepetition))
+        );
+        assert_eq!(game.can_claim_draw(), None);
+    }
+
+    #[test]
+    fn repetitions_restored() {
+        let mut game: Game = GameBuilder::default()
+            .options(Options::default().threefold_repetition(false))
+            .build();
+        let moves: [Move; 5] = [
+            Move::Piece(E2, E4),
+            Move::Piece(G8, F6),
+            Move::Piece(G1, F3),
+            Move::Piece(F6, G8),
+            Move::Piece(F3, G1),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.count_repetitions(), 2);
+        assert_eq!(game.halfmove_clock(), 4);
+        // Counters are rebuilt from the moves history
+        let restored: Game = GameBuilder::default()
+            .board(*game.board())
+            .initial_board(*game.initial_board())
+            .moves(game.moves().to_vec())
+            .options(Options::default().threefold_repetition(false))
+            .build();
+        assert_eq!(restored.count_repetitions(), 2);
+        assert_eq!(restored.halfmove_clock(), 4);
+    }
+
+    #[test]
+    fn repetition_threshold() {
+        let moves: [Move; 4] = [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ];
+        // Claim after the second occurrence
+        let mut game: Game = GameBuilder::default()
+            .options(
+                Options::default()
+                    .threefold_repetition(false)
+                    .repetition_threshold(2),
+            )
+            .build();
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_threefold_repetition(), true);
+        assert_eq!(game.can_claim_draw(), Some(DrawClaim::ThreefoldRepetition));
+        // Automatic draw on the fourth occurrence
+        let mut game: Game = GameBuilder::default()
+            .options(Options::default().repetition_threshold(4))
+            .build();
+        for m in moves.iter().chain(moves.iter()) {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_threefold_repetition(), false);
+        assert_eq!(game.in_progress(), true);
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_threefold_repetition(), true);
+        assert_eq!(
+            game.game_state(),
+            GameState::Ended(EndGame::Draw(DrawReason::ThreefoldRepetition))
+        );
+        // Fivefold repetition is still the automatic cap
+        let mut game: Game = GameBuilder::default()
+            .options(
+                Options::default()
+                    .threefold_repetition(false)
+                    .repetition_threshold(6),
+            )
+            .build();
+        for _ in 0..4 {
+            for m in moves.iter() {
+                assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+            }
+        }
+        assert_eq!(
+            game.game_state(),
+            GameState::Ended(EndGame::Draw(DrawReason::FivefoldRepetition))
+        );
+        // Thresholds below 2 are raised to 2
+        for threshold in 0..2 {
+            let options: Options = Options {
+                repetition_threshold: threshold,
+                ..Options::default()
+            };
+            let mut game: Game = GameBuilder::default().options(options.clone()).build();
+            assert_eq!(game.is_threefold_repetition(), false);
+            assert!(game
+                .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+                .is_ok());
+            assert_eq!(game.in_progress(), true);
+            let mut game: Game = GameBuilder::default()
+                .options(options.repetition_threshold(threshold))
+                .build();
+            assert!(game
+                .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+                .is_ok());
+            assert_eq!(game.in_progress(), true);
+        }
+    }
+
+    #[test]
+    fn can_claim_draw_fifty_move_rule() {
+        let mut game: Game = GameBuilder::default()
+            .board(
+                BoardBuilder::default()
+                    .piece(Piece::King(Color::White, A1))
+                    .piece(Piece::Rook(Color::White, H2))
+                    .piece(Piece::King(Color::Black, H8))
+                    .piece(Piece::Rook(Color::Black, A7))
+                    .build(),
+            )
+            .build();
+        // Play moves without taking pieces and without repeating positions
+        let mut positions: Vec<Board> = vec![*game.board()];
+        for i in 0..100 {
+            assert_eq!(game.can_claim_draw(), None);
+            let m: Move = game
+                .get_legal_moves()
+                .into_iter()
+                .find(|m| {
+                    let board: Board = game.board().replay_move(*m, None);
+                    board.get_taken_piece().is_none()
+                        && !board.is_checkmate()
+                        && !board.is_stalemate()
+                        && !positions.iter().any(|x| x.same_position(&board))
+                })
+                .unwrap();
+            let (_, event): (GameState, GameEvent) =
+                game.play_move(m, Duration::from_secs(1)).ok().unwrap();
+            assert_eq!(event.is_fifty_move(), i == 99);
+            positions.push(*game.board());
+        }
+        assert_eq!(game.can_claim_draw(), Some(DrawClaim::FiftyMoveRule));
+        assert_eq!(game.status(), GameStatus::FiftyMove);
+    }
+
+    #[test]
+    fn on_event() {
+        use alloc::boxed::Box;
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut game: Game = Game::default();
+        let moves: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let checks: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let (moves_counter, checks_counter) = (moves.clone(), checks.clone());
+        game.on_event(Box::new(move |game, event| {
+            moves_counter.fetch_add(1, Ordering::Relaxed);
+            if event.contains(GameEvent::CHECK) {
+                assert_eq!(game.board().is_check(), true);
+                checks_counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(F7, F6), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(
+            (
+                moves.load(Ordering::Relaxed),
+                checks.load(Ordering::Relaxed)
+            ),
+            (2, 0)
+        );
+        // Illegal moves are not notified
+        assert!(game
+            .play_move(Move::Piece(D1, D3), Duration::from_secs(1))
+            .is_err());
+        assert_eq!(moves.load(Ordering::Relaxed), 2);
+        assert!(game
+            .play_move(Move::Piece(D1, H5), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(
+            (
+                moves.load(Ordering::Relaxed),
+                checks.load(Ordering::Relaxed)
+            ),
+            (3, 1)
+        );
+        // Callbacks are not cloned
+        let mut cloned: Game = game.clone();
+        assert!(cloned
+            .play_move(Move::Piece(G7, G6), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(moves.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn game_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Game>();
+    }
+
+    #[test]
+    fn on_event_promotion() {
+        use alloc::boxed::Box;
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let board = || {
+            BoardBuilder::default()
+                .piece(Piece::King(Color::White, A1))
+                .piece(Piece::Pawn(Color::White, B7))
+                .piece(Piece::King(Color::Black, H8))
+                .build()
+        };
+        let counter = |game: &mut Game| -> Arc<AtomicUsize> {
+            let events: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+            let cloned: Arc<AtomicUsize> = events.clone();
+            game.on_event(Box::new(move |_, _| {
+                cloned.fetch_add(1, Ordering::Relaxed);
+            }));
+            events
+        };
+        // Promotion chosen by the player
+        let mut game: Game = GameBuilder::default().board(board()).build();
+        let events: Arc<AtomicUsize> = counter(&mut game);
+        assert!(game
+            .play_move(Move::Piece(B7, B8), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .promote(Promotion::Queen, Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(events.load(Ordering::Relaxed), 2);
+        // Automatic promotion is notified along with the move
+        let mut game: Game = GameBuilder::default()
+            .board(board())
+            .options(Options::default().auto_promote(Some(Promotion::Queen)))
+            .build();
+        let events: Arc<AtomicUsize> = counter(&mut game);
+        assert!(game
+            .play_move(Move::Piece(B7, B8), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(events.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn status() {
+        let mut game: Game = GameBuilder::default()
+            .options(Options::default().threefold_repetition(false))
+            .build();
+        assert_eq!(game.status(), GameStatus::InProgress);
+        let moves: [Move; 4] = [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ];
+        for m in moves.iter().chain(moves.iter()) {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.status(), GameStatus::Repetition(3));
+        // Fool's mate
+        let mut game: Game = Game::default();
+        for m in [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+        ]
+        .iter()
+        {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert!(game
+            .play_move(Move::Piece(D8, H4), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.status(), GameStatus::Checkmate(Color::Black));
+    }
+
+    #[test]
+    fn move_flags() {
+        let mut game: Game = Game::default();
+        let moves: [Move; 9] = [
+            Move::Piece(E2, E4),
+            Move::Piece(A7, A6),
+            Move::Piece(E4, E5),
+            Move::Piece(D7, D5),
+            Move::Piece(E5, D6), // En passant
+            Move::Piece(A6, A5),
+            Move::Piece(G1, F3),
+            Move::Piece(A5, A4),
+            Move::Piece(F1, E2),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.moves()[3].flags, MoveFlags::NONE);
+        assert_eq!(game.moves()[4].flags, MoveFlags::EN_PASSANT);
+        assert_eq!(
+            game.moves()[4].piece_taken,
+            Some(Piece::Pawn(Color::Black, D5))
+        );
+        assert!(game
+            .play_move(Move::Piece(A4, A3), Duration::from_secs(1))
+            .is_ok());
+        // Castle
+        assert!(game
+            .play_move(Move::KingSideCastle, Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.moves().last().unwrap().flags, MoveFlags::CASTLE);
+        // Check
+        assert!(game
+            .play_move(Move::Piece(A3, B2), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(D6, C7), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.moves().last().unwrap().flags, MoveFlags::NONE);
+        assert!(game
+            .play_move(Move::Piece(B2, A1), Duration::from_secs(1))
+            .is_ok());
+        assert!(game.promote_untimed(Promotion::Queen).is_ok());
+        assert_eq!(game.moves().last().unwrap().flags, MoveFlags::NONE);
+        assert!(game
+            .play_move(Move::Piece(C7, D8), Duration::from_secs(1))
+            .is_ok());
+        assert!(game.promote_untimed(Promotion::Queen).is_ok());
+        assert_eq!(game.moves().last().unwrap().flags, MoveFlags::CHECK);
+        // Checkmate (fool's mate)
+        let mut game: Game = Game::default();
+        let moves: [Move; 4] = [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+            Move::Piece(D8, H4),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(
+            game.moves().last().unwrap().flags,
+            MoveFlags::CHECK | MoveFlags::CHECKMATE
+        );
+    }
+
+    #[test]
+    fn capture_events() {
+        let mut game: Game = Game::default();
+        // Quiet move
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::NONE);
+        assert!(game
+            .play_move(Move::Piece(D7, D5), Duration::from_secs(1))
+            .is_ok());
+        // Capture
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(E4, D5), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::CAPTURE);
+        assert!(game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(1))
+            .is_ok());
+        // En passant
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(D5, E6), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::CAPTURE | GameEvent::EN_PASSANT);
+        assert!(game
+            .play_move(Move::Piece(A7, A6), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(E6, F7), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(E8, E7), Duration::from_secs(1))
+            .is_ok());
+        // Capture with promotion
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(F7, G8), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::CAPTURE | GameEvent::PROMOTION_AVAILABLE);
+        let (_, event): (GameState, GameEvent) =
+            game.promote_untimed(Promotion::Queen).ok().unwrap();
+        assert_eq!(event, GameEvent::NONE);
+    }
+
+    #[test]
+    fn captured_pieces() {
+        let mut game: Game = Game::default();
+        let moves: [Move; 10] = [
+            Move::Piece(A2, A4),
+            Move::Piece(B7, B5),
+            Move::Piece(A4, B5),
+            Move::Piece(A7, A6),
+            Move::Piece(B5, A6),
+            Move::Piece(C8, B7),
+            Move::Piece(A6, B7),
+            Move::Piece(B8, C6),
+            Move::Piece(B7, A8),
+            Move::Piece(D8, A8),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+            if game.board().get_promoting_pawn().is_some() {
+                assert!(game.promote_untimed(Promotion::Queen).is_ok());
+            }
+            assert_eq!(
+                game.material_balance(),
+                game.board().get_material_advantage(Color::White)
+            );
+        }
+        assert_eq!(
+            game.captured_pieces(Color::White),
+            vec![
+                Piece::Pawn(Color::Black, B5),
+                Piece::Pawn(Color::Black, A6),
+                Piece::Bishop(Color::Black, B7),
+                Piece::Rook(Color::Black, A8),
+            ]
+        );
+        // Promoted queen is worth 9
+        assert_eq!(
+            game.captured_pieces(Color::Black),
+            vec![Piece::Queen(Color::White, A8)]
+        );
+        assert_eq!(game.material_balance(), 9);
+    }
+
+    #[test]
+    fn material_history() {
+        let mut game: Game = Game::default();
+        assert_eq!(game.material_history(), vec![0]);
+        let moves: [Move; 6] = [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(D8, D5),
+            Move::Piece(B1, C3),
+            Move::Piece(D5, A2),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.material_history(), vec![0, 0, 0, 1, 0, 0, -1]);
+        assert_eq!(
+            game.captured_pieces(Color::White),
+            vec![Piece::Pawn(Color::Black, D5)]
+        );
+        assert_eq!(
+            game.captured_pieces(Color::Black),
+            vec![Piece::Pawn(Color::White, D5), Piece::Pawn(Color::White, A2)]
+        );
+        assert_eq!(game.material_balance(), -1);
+    }
+
+    #[test]
+    fn captured_pieces_en_passant() {
+        let mut game: Game = Game::default();
+        let moves: [Move; 5] = [
+            Move::Piece(E2, E4),
+            Move::Piece(A7, A6),
+            Move::Piece(E4, E5),
+            Move::Piece(D7, D5),
+            Move::Piece(E5, D6),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(
+            game.captured_pieces(Color::White),
+            vec![Piece::Pawn(Color::Black, D5)]
+        );
+        assert_eq!(game.captured_pieces(Color::Black), vec![]);
+        assert_eq!(game.material_balance(), 1);
+        assert_eq!(
+            game.material_balance(),
+            game.board().get_material_advantage(Color::White)
+        );
+    }
+
+    #[test]
+    fn allow_castling() {
+        let board: Board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1")
+            .ok()
+            .unwrap();
+        let mut game: Game = GameBuilder::default().board(board).build();
+        assert!(game.get_legal_moves().contains(&Move::KingSideCastle));
+        assert!(game
+            .get_piece_legal_moves(E1)
+            .contains(&Move::QueenSideCastle));
+        // Disable castling
+        game.options = Options::default().allow_castling(false);
+        assert!(!game.get_legal_moves().iter().any(|x| x.is_castle()));
+        assert!(!game.get_piece_legal_moves(E1).iter().any(|x| x.is_castle()));
+        assert!(!game.suggest_move().is_castle());
+        assert_eq!(
+            game.play_move(Move::KingSideCastle, Duration::from_secs(1))
+                .err()
+                .unwrap(),
+            GameError::IllegalMove(Move::KingSideCastle)
+        );
+        assert_eq!(game.moves().len(), 0);
+        // The castling rights of the board are kept
+        assert_eq!(game.board().can_kingside_castle(Color::White), true);
+        assert!(game
+            .play_move(Move::Piece(E1, E2), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(
+            game.play_move(Move::QueenSideCastle, Duration::from_secs(1))
+                .err()
+                .unwrap(),
+            GameError::IllegalMove(Move::QueenSideCastle)
+        );
+        // No castling preset
+        let game: Game = GameBuilder::default()
+            .board(Board::no_castling())
+            .options(Options::default().allow_castling(false))
+            .build();
+        assert!(!game.get_legal_moves().iter().any(|x| x.is_castle()));
+    }
+
+    #[test]
+    fn is_in_check() {
+        let mut game: Game = Game::default();
+        assert_eq!(game.is_in_check(), false);
+        assert_eq!(game.checkers(), vec![]);
+        // 1. e4 f5 2. Qh5+
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(F7, F5),
+            Move::Piece(D1, H5),
+        ] {
+            assert!(game.play_move(m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.is_in_check(), true);
+        assert_eq!(game.checkers(), vec![Piece::Queen(Color::White, H5)]);
+        // 2... g6 blocks the check
+        assert!(game
+            .play_move(Move::Piece(G7, G6), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.is_in_check(), false);
+        assert_eq!(game.checkers(), vec![]);
+    }
+
+    #[test]
+    fn offer_draw() {
+        let mut game: Game = Game::default();
+        assert_eq!(game.draw_offer(), None);
+        // White offers a draw and moves
+        let (state, event): (GameState, GameEvent) = game.offer_draw().ok().unwrap();
+        assert_eq!(state, GameState::Continuing);
+        assert_eq!(event, GameEvent::DRAW_OFFERED);
+        assert_eq!(game.draw_offer(), Some(Color::White));
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::DRAW_OFFERED);
+        // Black declines playing a move
+        let (_, event): (GameState, GameEvent) = game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(1))
+            .ok()
+            .unwrap();
+        assert_eq!(event, GameEvent::NONE);
+        assert_eq!(game.draw_offer(), None);
+        // White offers again and the offer is accepted
+        assert!(game.offer_draw().is_ok());
+        assert_eq!(
+            game.draw().ok().unwrap().0,
+            GameState::Ended(EndGame::Draw(DrawReason::Agreement))
+        );
+        assert_eq!(game.offer_draw().err().unwrap(), GameError::GameEnded);
+    }
+
+    #[test]
+    fn game_state_draw() {
+        let mut game: Game = Game::default();
+        let (state, _): (GameState, GameEvent) = game.draw().ok().unwrap();
+        assert_eq!(
+            state,
+            GameState::Ended(EndGame::Draw(DrawReason::Agreement))
+        );
+        assert_eq!(game.game_state(), state);
+        assert_eq!(game.winner(), None);
+    }
+
+    #[test]
+    fn three_check() {
+        let mut game: Game = GameBuilder::three_check().build();
+        assert_eq!(game.variant(), Variant::ThreeCheck);
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D6),
+            Move::Piece(F1, B5),
+            Move::Piece(C7, C6),
+            Move::Piece(B5, C6),
+            Move::Piece(B8, C6),
+            Move::Piece(D1, H5),
+            Move::Piece(E7, E6),
+        ] {
+            assert_eq!(
+                game.play_move(m, Duration::from_secs(1)).ok().unwrap().0,
+                GameState::Continuing
+            );
+        }
+        assert_eq!(game.board().get_checks(Color::White), 2);
+        // Black could take the queen, but the third check ends the game
+        assert_eq!(
+            game.play_move(Move::Piece(H5, F7), Duration::from_secs(1))
+                .ok()
+                .unwrap()
+                .0,
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::ThreeChecks))
+        );
+        assert_eq!(game.board().is_checkmate(), false);
+        assert_eq!(game.metadata().result(), MetadataResult::WhiteWins);
+        // Counters are restored with the game
+        let restored: Game = GameBuilder::default()
+            .board(*game.board())
+            .initial_board(*game.initial_board())
+            .moves(game.moves().to_vec())
+            .metadata(game.metadata().clone())
+            .variant(Variant::ThreeCheck)
+            .build();
+        assert_eq!(
+            restored.game_state(),
+            GameState::Ended(EndGame::Victory(Color::White, VictoryReason::ThreeChecks))
+        );
+    }
+
+    #[test]
+    fn suggest_move() {
+        let game: Game = GameBuilder::default()
+            .options(Options::default().max_search_depth(1))
+            .build();
+        let m: Move = game.suggest_move();
+        assert!(game.get_legal_moves().contains(&m));
+        assert_eq!(m, game.board().get_best_next_move(1).0);
+        // Without time budget, the time source is ignored
+        assert_eq!(game.suggest_move_within(|| Duration::MAX), m);
+        // Time budget: the search deepens until the budget is spent
+        let game: Game = GameBuilder::default()
+            .options(
+                Options::default()
+                    .max_search_depth(1)
+                    .search_time(Some(Duration::from_secs(1))),
+            )
+            .build();
+        assert_eq!(game.suggest_move_within(|| Duration::ZERO), m);
+        let mut searches: usize = 0;
+        let quick: Move = game.suggest_move_within(|| {
+            searches += 1;
+            Duration::from_secs(2)
+        });
+        assert_eq!(searches, 1);
+        assert_eq!(quick, game.board().get_best_next_move(0).0);
+        assert!(game.get_legal_moves().contains(&quick));
+    }
 }
@@ -0,0 +1,55 @@
+//! # Observers
+//!
+//! This module exposes the callbacks which can be registered on a `Game` to be notified of the game events
+
+use super::{Game, GameEvent};
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// ## EventCallback
+///
+/// A callback invoked with the game and the events raised after a move has been played.
+/// Callbacks must be `Send` and `Sync`, so that `Game` can still be moved and shared across threads
+pub type EventCallback = Box<dyn FnMut(&Game, GameEvent) + Send + Sync>;
+
+/// ## Observers
+///
+/// The callbacks registered on a game.
+/// Since callbacks can't be cloned, a cloned game has no callbacks registered
+#[derive(Default)]
+pub(super) struct Observers {
+    callbacks: Vec<EventCallback>,
+}
+
+impl Observers {
+    /// ### push
+    ///
+    /// Register a new callback
+    pub fn push(&mut self, callback: EventCallback) {
+        self.callbacks.push(callback);
+    }
+
+    /// ### notify
+    ///
+    /// Invoke all the callbacks, in the order they were registered
+    pub fn notify(&mut self, game: &Game, event: GameEvent) {
+        for callback in self.callbacks.iter_mut() {
+            callback(game, event);
+        }
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl core::fmt::Debug for Observers {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Observers")
+            .field("callbacks", &self.callbacks.len())
+            .finish()
+    }
+}
@@ -0,0 +1,2665 @@
+//! # Openings
+//!
+//! This module exposes the ECO (encyclopaedia of chess openings) classification for `Game`.
+//! Openings are detected using a built-in table of the main lines (at least one for each ECO code),
+//! so no external database is required.
+//! <https://en.wikipedia.org/wiki/Encyclopaedia_of_Chess_Openings>
+
+use super::Game;
+use crate::{Board, Color, Move};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// ## EcoCode
+///
+/// An ECO code, made up of the volume (`A` to `E`) and of the number (`00` to `99`) (e.g. `B20`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EcoCode {
+    volume: char,
+    number: u8,
+}
+
+impl EcoCode {
+    /// ### new
+    ///
+    /// Instantiates a new `EcoCode`
+    pub const fn new(volume: char, number: u8) -> Self {
+        Self { volume, number }
+    }
+
+    /// ### volume
+    ///
+    /// Get ECO volume (`A` to `E`)
+    pub fn volume(&self) -> char {
+        self.volume
+    }
+
+    /// ### number
+    ///
+    /// Get ECO number inside of the volume (0 to 99)
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+}
+
+impl core::fmt::Display for EcoCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}{:02}", self.volume, self.number)
+    }
+}
+
+/// Openings table: ECO code, name and moves in coordinate notation.
+/// Castling is written as the king move (e.g. `e1g1`)
+const OPENINGS: &[(EcoCode, &str, &str)] = &[
+    // -- A
+    (EcoCode::new('A', 0), "Polish Opening", "b2b4"),
+    (EcoCode::new('A', 0), "Grob Opening", "g2g4"),
+    (EcoCode::new('A', 0), "Hungarian Opening", "g2g3"),
+    (EcoCode::new('A', 0), "Van Geet Opening", "b1c3"),
+    (EcoCode::new('A', 0), "Mieses Opening", "d2d3"),
+    (EcoCode::new('A', 0), "Van 't Kruijs Opening", "e2e3"),
+    (EcoCode::new('A', 1), "Nimzo-Larsen Attack", "b2b3"),
+    (EcoCode::new('A', 2), "Bird's Opening", "f2f4"),
+    (EcoCode::new('A', 3), "Bird's Opening", "f2f4 d7d5"),
+    (EcoCode::new('A', 4), "Réti Opening", "g1f3"),
+    (EcoCode::new('A', 5), "Réti Opening", "g1f3 g8f6"),
+    (EcoCode::new('A', 6), "Réti Opening", "g1f3 d7d5"),
+    (
+        EcoCode::new('A', 7),
+        "King's Indian Attack",
+        "g1f3 d7d5 g2g3",
+    ),
+    (
+        EcoCode::new('A', 8),
+        "King's Indian Attack",
+        "g1f3 d7d5 g2g3 c7c5 f1g2",
+    ),
+    (EcoCode::new('A', 9), "Réti Opening", "g1f3 d7d5 c2c4"),
+    (EcoCode::new('A', 10), "English Opening", "c2c4"),
+    (
+        EcoCode::new('A', 11),
+        "English Opening: Caro-Kann Defensive System",
+        "c2c4 c7c6",
+    ),
+    (
+        EcoCode::new('A', 12),
+        "English Opening: Caro-Kann Defensive System",
+        "c2c4 c7c6 g1f3 d7d5 b2b3",
+    ),
+    (EcoCode::new('A', 13), "English Opening", "c2c4 e7e6"),
+    (
+        EcoCode::new('A', 14),
+        "English Opening: Agincourt Defense, Neo-Catalan Declined",
+        "c2c4 e7e6 g1f3 d7d5 g2g3 g8f6 f1g2 f8e7 e1g1",
+    ),
+    (EcoCode::new('A', 15), "English Opening", "c2c4 g8f6"),
+    (
+        EcoCode::new('A', 16),
+        "English Opening: Anglo-Indian Defense",
+        "c2c4 g8f6 b1c3",
+    ),
+    (
+        EcoCode::new('A', 17),
+        "English Opening: Anglo-Indian Defense, Hedgehog System",
+        "c2c4 g8f6 b1c3 e7e6",
+    ),
+    (
+        EcoCode::new('A', 18),
+        "English Opening: Mikenas-Carls Variation",
+        "c2c4 g8f6 b1c3 e7e6 e2e4",
+    ),
+    (
+        EcoCode::new('A', 19),
+        "English Opening: Mikenas-Carls Variation, Sicilian",
+        "c2c4 g8f6 b1c3 e7e6 e2e4 c7c5",
+    ),
+    (EcoCode::new('A', 20), "English Opening", "c2c4 e7e5"),
+    (
+        EcoCode::new('A', 21),
+        "English Opening: King's English Variation",
+        "c2c4 e7e5 b1c3",
+    ),
+    (
+        EcoCode::new('A', 22),
+        "English Opening: King's English Variation, Two Knights",
+        "c2c4 e7e5 b1c3 g8f6",
+    ),
+    (
+        EcoCode::new('A', 23),
+        "English Opening: Bremen System, Keres Variation",
+        "c2c4 e7e5 b1c3 g8f6 g2g3 c7c6",
+    ),
+    (
+        EcoCode::new('A', 24),
+        "English Opening: Bremen System with ...g6",
+        "c2c4 e7e5 b1c3 g8f6 g2g3 g7g6",
+    ),
+    (
+        EcoCode::new('A', 25),
+        "English Opening: King's English Variation, Reversed Closed Sicilian",
+        "c2c4 e7e5 b1c3 b8c6",
+    ),
+    (
+        EcoCode::new('A', 26),
+        "English Opening: Closed, Botvinnik System",
+        "c2c4 e7e5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 d2d3 d7d6 e2e4",
+    ),
+    (
+        EcoCode::new('A', 27),
+        "English Opening: Three Knights System",
+        "c2c4 e7e5 b1c3 b8c6 g1f3",
+    ),
+    (
+        EcoCode::new('A', 28),
+        "English Opening: Four Knights System",
+        "c2c4 e7e5 b1c3 b8c6 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('A', 29),
+        "English Opening: Four Knights, Kingside Fianchetto",
+        "c2c4 e7e5 b1c3 b8c6 g1f3 g8f6 g2g3",
+    ),
+    (
+        EcoCode::new('A', 30),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5",
+    ),
+    (
+        EcoCode::new('A', 31),
+        "English Opening: Symmetrical Variation, Benoni Formation",
+        "c2c4 c7c5 g1f3 g8f6 d2d4",
+    ),
+    (
+        EcoCode::new('A', 32),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 g1f3 g8f6 d2d4 c5d4 f3d4 e7e6",
+    ),
+    (
+        EcoCode::new('A', 33),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 g1f3 g8f6 d2d4 c5d4 f3d4 e7e6 b1c3 b8c6",
+    ),
+    (
+        EcoCode::new('A', 34),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 b1c3",
+    ),
+    (
+        EcoCode::new('A', 35),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 b1c3 b8c6",
+    ),
+    (
+        EcoCode::new('A', 36),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 b1c3 b8c6 g2g3",
+    ),
+    (
+        EcoCode::new('A', 37),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 g1f3",
+    ),
+    (
+        EcoCode::new('A', 38),
+        "English Opening: Symmetrical Variation",
+        "c2c4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('A', 39),
+        "English Opening: Symmetrical Variation, Main Line with d4",
+        "c2c4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 g1f3 g8f6 e1g1 e8g8 d2d4",
+    ),
+    (EcoCode::new('A', 40), "Queen's Pawn Game", "d2d4"),
+    (EcoCode::new('A', 40), "Englund Gambit", "d2d4 e7e5"),
+    (EcoCode::new('A', 40), "Horwitz Defense", "d2d4 e7e6"),
+    (EcoCode::new('A', 40), "Modern Defense", "d2d4 g7g6"),
+    (EcoCode::new('A', 41), "Queen's Pawn Game", "d2d4 d7d6"),
+    (
+        EcoCode::new('A', 42),
+        "Modern Defense: Averbakh System",
+        "d2d4 d7d6 c2c4 g7g6 b1c3 f8g7 e2e4",
+    ),
+    (EcoCode::new('A', 43), "Old Benoni Defense", "d2d4 c7c5"),
+    (
+        EcoCode::new('A', 44),
+        "Old Benoni Defense",
+        "d2d4 c7c5 d4d5 e7e5",
+    ),
+    (EcoCode::new('A', 45), "Indian Defense", "d2d4 g8f6"),
+    (EcoCode::new('A', 45), "Trompowsky Attack", "d2d4 g8f6 c1g5"),
+    (EcoCode::new('A', 46), "Indian Defense", "d2d4 g8f6 g1f3"),
+    (
+        EcoCode::new('A', 47),
+        "Queen's Indian Defense",
+        "d2d4 g8f6 g1f3 b7b6",
+    ),
+    (
+        EcoCode::new('A', 48),
+        "East Indian Defense",
+        "d2d4 g8f6 g1f3 g7g6",
+    ),
+    (
+        EcoCode::new('A', 49),
+        "East Indian Defense: Fianchetto Variation",
+        "d2d4 g8f6 g1f3 g7g6 g2g3",
+    ),
+    (EcoCode::new('A', 50), "Indian Defense", "d2d4 g8f6 c2c4"),
+    (
+        EcoCode::new('A', 51),
+        "Budapest Gambit",
+        "d2d4 g8f6 c2c4 e7e5",
+    ),
+    (
+        EcoCode::new('A', 52),
+        "Budapest Gambit",
+        "d2d4 g8f6 c2c4 e7e5 d4e5 f6g4",
+    ),
+    (
+        EcoCode::new('A', 53),
+        "Old Indian Defense",
+        "d2d4 g8f6 c2c4 d7d6",
+    ),
+    (
+        EcoCode::new('A', 54),
+        "Old Indian Defense",
+        "d2d4 g8f6 c2c4 d7d6 b1c3 e7e5 g1f3",
+    ),
+    (
+        EcoCode::new('A', 55),
+        "Old Indian Defense: Normal Variation",
+        "d2d4 g8f6 c2c4 d7d6 b1c3 e7e5 g1f3 b8d7 e2e4",
+    ),
+    (
+        EcoCode::new('A', 56),
+        "Benoni Defense",
+        "d2d4 g8f6 c2c4 c7c5",
+    ),
+    (
+        EcoCode::new('A', 57),
+        "Benko Gambit",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 b7b5",
+    ),
+    (
+        EcoCode::new('A', 58),
+        "Benko Gambit Accepted",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 b7b5 c4b5 a7a6 b5a6",
+    ),
+    (
+        EcoCode::new('A', 59),
+        "Benko Gambit Accepted: Main Line",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 b7b5 c4b5 a7a6 b5a6 c8a6 b1c3 d7d6 e2e4",
+    ),
+    (
+        EcoCode::new('A', 60),
+        "Modern Benoni",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6",
+    ),
+    (
+        EcoCode::new('A', 61),
+        "Benoni Defense",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 g1f3 g7g6",
+    ),
+    (
+        EcoCode::new('A', 62),
+        "Benoni Defense: Fianchetto Variation",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 g1f3 g7g6 g2g3 f8g7 f1g2 e8g8",
+    ),
+    (
+        EcoCode::new('A', 63),
+        "Benoni Defense: Fianchetto Variation, 9...Nbd7",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 g1f3 g7g6 g2g3 f8g7 f1g2 e8g8 e1g1 b8d7",
+    ),
+    (
+        EcoCode::new('A', 64),
+        "Benoni Defense: Fianchetto Variation, 11...Re8",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 g1f3 g7g6 g2g3 f8g7 f1g2 e8g8 e1g1 b8d7 f3d2 a7a6 a2a4 f8e8",
+    ),
+    (
+        EcoCode::new('A', 65),
+        "Benoni Defense: King's Pawn Line",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4",
+    ),
+    (
+        EcoCode::new('A', 66),
+        "Benoni Defense: Pawn Storm Variation",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 f2f4",
+    ),
+    (
+        EcoCode::new('A', 67),
+        "Benoni Defense: Taimanov Variation",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 f2f4 f8g7 f1b5",
+    ),
+    (
+        EcoCode::new('A', 68),
+        "Benoni Defense: Four Pawns Attack",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 f2f4 f8g7 g1f3 e8g8",
+    ),
+    (
+        EcoCode::new('A', 69),
+        "Benoni Defense: Four Pawns Attack, Main Line",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 f2f4 f8g7 g1f3 e8g8 f1e2 f8e8",
+    ),
+    (
+        EcoCode::new('A', 70),
+        "Benoni Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3",
+    ),
+    (
+        EcoCode::new('A', 71),
+        "Benoni Defense: Classical Variation, 8.Bg5",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 c1g5",
+    ),
+    (
+        EcoCode::new('A', 72),
+        "Benoni Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8",
+    ),
+    (
+        EcoCode::new('A', 73),
+        "Benoni Defense: Classical Variation, 9.O-O",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1",
+    ),
+    (
+        EcoCode::new('A', 74),
+        "Benoni Defense: Classical Variation, 9...a6",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 a7a6 a2a4",
+    ),
+    (
+        EcoCode::new('A', 75),
+        "Benoni Defense: Classical Variation, 10...Bg4",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 a7a6 a2a4 c8g4",
+    ),
+    (
+        EcoCode::new('A', 76),
+        "Benoni Defense: Classical Variation, 9...Re8",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 f8e8",
+    ),
+    (
+        EcoCode::new('A', 77),
+        "Benoni Defense: Classical Variation, 10.Nd2",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 f8e8 f3d2",
+    ),
+    (
+        EcoCode::new('A', 78),
+        "Benoni Defense: Classical Variation, 10...Na6",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 f8e8 f3d2 b8a6",
+    ),
+    (
+        EcoCode::new('A', 79),
+        "Benoni Defense: Classical Variation, 11.f3",
+        "d2d4 g8f6 c2c4 c7c5 d4d5 e7e6 b1c3 e6d5 c4d5 d7d6 e2e4 g7g6 g1f3 f8g7 f1e2 e8g8 e1g1 f8e8 f3d2 b8a6 f2f3",
+    ),
+    (EcoCode::new('A', 80), "Dutch Defense", "d2d4 f7f5"),
+    (
+        EcoCode::new('A', 81),
+        "Dutch Defense: Fianchetto Variation",
+        "d2d4 f7f5 g2g3",
+    ),
+    (
+        EcoCode::new('A', 82),
+        "Dutch Defense: Staunton Gambit",
+        "d2d4 f7f5 e2e4",
+    ),
+    (
+        EcoCode::new('A', 83),
+        "Dutch Defense: Staunton Gambit Accepted",
+        "d2d4 f7f5 e2e4 f5e4 b1c3 g8f6 c1g5",
+    ),
+    (EcoCode::new('A', 84), "Dutch Defense", "d2d4 f7f5 c2c4"),
+    (
+        EcoCode::new('A', 85),
+        "Dutch Defense: Queen's Knight Variation",
+        "d2d4 f7f5 c2c4 g8f6 b1c3",
+    ),
+    (
+        EcoCode::new('A', 86),
+        "Dutch Defense: Fianchetto Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3",
+    ),
+    (
+        EcoCode::new('A', 87),
+        "Dutch Defense: Leningrad Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 g7g6 f1g2 f8g7 g1f3",
+    ),
+    (
+        EcoCode::new('A', 88),
+        "Dutch Defense: Leningrad Variation, Warsaw Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 g7g6 f1g2 f8g7 g1f3 e8g8 e1g1 d7d6 b1c3 c7c6",
+    ),
+    (
+        EcoCode::new('A', 89),
+        "Dutch Defense: Leningrad Variation, Matulović Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 g7g6 f1g2 f8g7 g1f3 e8g8 e1g1 d7d6 b1c3 b8c6",
+    ),
+    (
+        EcoCode::new('A', 90),
+        "Dutch Defense: Classical Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2",
+    ),
+    (
+        EcoCode::new('A', 91),
+        "Dutch Defense: Classical Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7",
+    ),
+    (
+        EcoCode::new('A', 92),
+        "Dutch Defense: Classical Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8",
+    ),
+    (
+        EcoCode::new('A', 93),
+        "Dutch Defense: Stonewall Variation, Botvinnik Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d5 b2b3",
+    ),
+    (
+        EcoCode::new('A', 94),
+        "Dutch Defense: Stonewall Variation with Ba3",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d5 b2b3 c7c6 c1a3",
+    ),
+    (
+        EcoCode::new('A', 95),
+        "Dutch Defense: Stonewall Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d5 b1c3 c7c6",
+    ),
+    (
+        EcoCode::new('A', 96),
+        "Dutch Defense: Classical Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d6",
+    ),
+    (
+        EcoCode::new('A', 97),
+        "Dutch Defense: Ilyin-Zhenevsky Variation",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d6 b1c3 d8e8",
+    ),
+    (
+        EcoCode::new('A', 98),
+        "Dutch Defense: Ilyin-Zhenevsky Variation, Alatortsev-Lisitsyn Line",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d6 b1c3 d8e8 d1c2",
+    ),
+    (
+        EcoCode::new('A', 99),
+        "Dutch Defense: Ilyin-Zhenevsky Variation, Modern Main Line",
+        "d2d4 f7f5 c2c4 g8f6 g2g3 e7e6 f1g2 f8e7 g1f3 e8g8 e1g1 d7d6 b1c3 d8e8 b2b3",
+    ),
+    // -- B
+    (EcoCode::new('B', 0), "King's Pawn Opening", "e2e4"),
+    (EcoCode::new('B', 0), "Nimzowitsch Defense", "e2e4 b8c6"),
+    (EcoCode::new('B', 0), "Owen's Defense", "e2e4 b7b6"),
+    (EcoCode::new('B', 1), "Scandinavian Defense", "e2e4 d7d5"),
+    (EcoCode::new('B', 2), "Alekhine's Defense", "e2e4 g8f6"),
+    (
+        EcoCode::new('B', 3),
+        "Alekhine's Defense",
+        "e2e4 g8f6 e4e5 f6d5 d2d4",
+    ),
+    (
+        EcoCode::new('B', 4),
+        "Alekhine's Defense: Modern Variation",
+        "e2e4 g8f6 e4e5 f6d5 d2d4 d7d6 g1f3",
+    ),
+    (
+        EcoCode::new('B', 5),
+        "Alekhine's Defense: Modern Variation, Main Line",
+        "e2e4 g8f6 e4e5 f6d5 d2d4 d7d6 g1f3 c8g4",
+    ),
+    (EcoCode::new('B', 6), "Modern Defense", "e2e4 g7g6"),
+    (EcoCode::new('B', 7), "Pirc Defense", "e2e4 d7d6 d2d4 g8f6"),
+    (
+        EcoCode::new('B', 8),
+        "Pirc Defense: Classical Variation",
+        "e2e4 d7d6 d2d4 g8f6 b1c3 g7g6 g1f3",
+    ),
+    (
+        EcoCode::new('B', 9),
+        "Pirc Defense: Austrian Attack",
+        "e2e4 d7d6 d2d4 g8f6 b1c3 g7g6 f2f4",
+    ),
+    (EcoCode::new('B', 10), "Caro-Kann Defense", "e2e4 c7c6"),
+    (
+        EcoCode::new('B', 11),
+        "Caro-Kann Defense: Two Knights Attack",
+        "e2e4 c7c6 b1c3 d7d5 g1f3 c8g4",
+    ),
+    (
+        EcoCode::new('B', 12),
+        "Caro-Kann Defense: Advance Variation",
+        "e2e4 c7c6 d2d4 d7d5 e4e5",
+    ),
+    (
+        EcoCode::new('B', 13),
+        "Caro-Kann Defense: Exchange Variation",
+        "e2e4 c7c6 d2d4 d7d5 e4d5",
+    ),
+    (
+        EcoCode::new('B', 14),
+        "Caro-Kann Defense: Panov-Botvinnik Attack",
+        "e2e4 c7c6 d2d4 d7d5 e4d5 c6d5 c2c4 g8f6 b1c3 e7e6",
+    ),
+    (
+        EcoCode::new('B', 15),
+        "Caro-Kann Defense",
+        "e2e4 c7c6 d2d4 d7d5 b1c3",
+    ),
+    (
+        EcoCode::new('B', 16),
+        "Caro-Kann Defense: Bronstein-Larsen Variation",
+        "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 g8f6 e4f6 g7f6",
+    ),
+    (
+        EcoCode::new('B', 17),
+        "Caro-Kann Defense: Karpov Variation",
+        "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 b8d7",
+    ),
+    (
+        EcoCode::new('B', 18),
+        "Caro-Kann Defense: Classical Variation",
+        "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 c8f5",
+    ),
+    (
+        EcoCode::new('B', 19),
+        "Caro-Kann Defense: Classical Variation, Spassky Variation",
+        "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 c8f5 e4g3 f5g6 h2h4 h7h6 g1f3 b8d7",
+    ),
+    (EcoCode::new('B', 20), "Sicilian Defense", "e2e4 c7c5"),
+    (
+        EcoCode::new('B', 21),
+        "Sicilian Defense: Smith-Morra Gambit",
+        "e2e4 c7c5 d2d4",
+    ),
+    (
+        EcoCode::new('B', 21),
+        "Sicilian Defense: Grand Prix Attack",
+        "e2e4 c7c5 f2f4",
+    ),
+    (
+        EcoCode::new('B', 22),
+        "Sicilian Defense: Alapin Variation",
+        "e2e4 c7c5 c2c3",
+    ),
+    (
+        EcoCode::new('B', 23),
+        "Sicilian Defense: Closed",
+        "e2e4 c7c5 b1c3",
+    ),
+    (
+        EcoCode::new('B', 24),
+        "Sicilian Defense: Closed",
+        "e2e4 c7c5 b1c3 b8c6 g2g3",
+    ),
+    (
+        EcoCode::new('B', 25),
+        "Sicilian Defense: Closed",
+        "e2e4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 d2d3 d7d6",
+    ),
+    (
+        EcoCode::new('B', 26),
+        "Sicilian Defense: Closed, 6.Be3",
+        "e2e4 c7c5 b1c3 b8c6 g2g3 g7g6 f1g2 f8g7 d2d3 d7d6 c1e3",
+    ),
+    (EcoCode::new('B', 27), "Sicilian Defense", "e2e4 c7c5 g1f3"),
+    (
+        EcoCode::new('B', 28),
+        "Sicilian Defense: O'Kelly Variation",
+        "e2e4 c7c5 g1f3 a7a6",
+    ),
+    (
+        EcoCode::new('B', 29),
+        "Sicilian Defense: Nimzowitsch Variation",
+        "e2e4 c7c5 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('B', 30),
+        "Sicilian Defense",
+        "e2e4 c7c5 g1f3 b8c6",
+    ),
+    (
+        EcoCode::new('B', 31),
+        "Sicilian Defense: Rossolimo Variation",
+        "e2e4 c7c5 g1f3 b8c6 f1b5 g7g6",
+    ),
+    (
+        EcoCode::new('B', 32),
+        "Sicilian Defense: Open",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4",
+    ),
+    (
+        EcoCode::new('B', 33),
+        "Sicilian Defense: Lasker-Pelikan Variation",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e5",
+    ),
+    (
+        EcoCode::new('B', 34),
+        "Sicilian Defense: Accelerated Dragon, Exchange Variation",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 d4c6",
+    ),
+    (
+        EcoCode::new('B', 35),
+        "Sicilian Defense: Accelerated Dragon, Modern Bc4 Variation",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 b1c3 f8g7 c1e3 g8f6 f1c4",
+    ),
+    (
+        EcoCode::new('B', 36),
+        "Sicilian Defense: Accelerated Dragon, Maróczy Bind",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 c2c4",
+    ),
+    (
+        EcoCode::new('B', 37),
+        "Sicilian Defense: Accelerated Dragon, Maróczy Bind",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 c2c4 f8g7",
+    ),
+    (
+        EcoCode::new('B', 38),
+        "Sicilian Defense: Accelerated Dragon, Maróczy Bind, 6.Be3",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 c2c4 f8g7 c1e3",
+    ),
+    (
+        EcoCode::new('B', 39),
+        "Sicilian Defense: Accelerated Dragon, Maróczy Bind, Breyer Variation",
+        "e2e4 c7c5 g1f3 b8c6 d2d4 c5d4 f3d4 g7g6 c2c4 f8g7 c1e3 g8f6 b1c3 f6g4",
+    ),
+    (
+        EcoCode::new('B', 40),
+        "Sicilian Defense",
+        "e2e4 c7c5 g1f3 e7e6",
+    ),
+    (
+        EcoCode::new('B', 41),
+        "Sicilian Defense: Kan Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 a7a6",
+    ),
+    (
+        EcoCode::new('B', 42),
+        "Sicilian Defense: Kan Variation, Modern Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 a7a6 f1d3",
+    ),
+    (
+        EcoCode::new('B', 43),
+        "Sicilian Defense: Kan Variation, Wing Attack",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 a7a6 b1c3",
+    ),
+    (
+        EcoCode::new('B', 44),
+        "Sicilian Defense: Taimanov Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6",
+    ),
+    (
+        EcoCode::new('B', 45),
+        "Sicilian Defense: Taimanov Variation, Normal Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6 b1c3",
+    ),
+    (
+        EcoCode::new('B', 46),
+        "Sicilian Defense: Taimanov Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6 b1c3 a7a6",
+    ),
+    (
+        EcoCode::new('B', 47),
+        "Sicilian Defense: Taimanov Variation, Bastrikov Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6 b1c3 d8c7",
+    ),
+    (
+        EcoCode::new('B', 48),
+        "Sicilian Defense: Taimanov Variation, English Attack",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6 b1c3 d8c7 c1e3",
+    ),
+    (
+        EcoCode::new('B', 49),
+        "Sicilian Defense: Taimanov Variation",
+        "e2e4 c7c5 g1f3 e7e6 d2d4 c5d4 f3d4 b8c6 b1c3 d8c7 c1e3 a7a6 f1e2",
+    ),
+    (
+        EcoCode::new('B', 50),
+        "Sicilian Defense",
+        "e2e4 c7c5 g1f3 d7d6",
+    ),
+    (
+        EcoCode::new('B', 51),
+        "Sicilian Defense: Moscow Variation",
+        "e2e4 c7c5 g1f3 d7d6 f1b5",
+    ),
+    (
+        EcoCode::new('B', 52),
+        "Sicilian Defense: Moscow Variation, Main Line",
+        "e2e4 c7c5 g1f3 d7d6 f1b5 c8d7",
+    ),
+    (
+        EcoCode::new('B', 53),
+        "Sicilian Defense: Chekhover Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 d1d4",
+    ),
+    (
+        EcoCode::new('B', 54),
+        "Sicilian Defense",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4",
+    ),
+    (
+        EcoCode::new('B', 55),
+        "Sicilian Defense: Prins Variation, Venice Attack",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 f2f3 e7e5 f1b5",
+    ),
+    (
+        EcoCode::new('B', 56),
+        "Sicilian Defense",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3",
+    ),
+    (
+        EcoCode::new('B', 57),
+        "Sicilian Defense: Sozin Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 f1c4",
+    ),
+    (
+        EcoCode::new('B', 58),
+        "Sicilian Defense: Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 f1e2",
+    ),
+    (
+        EcoCode::new('B', 59),
+        "Sicilian Defense: Boleslavsky Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 f1e2 e7e5 d4b3",
+    ),
+    (
+        EcoCode::new('B', 60),
+        "Sicilian Defense: Richter-Rauzer Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5",
+    ),
+    (
+        EcoCode::new('B', 61),
+        "Sicilian Defense: Richter-Rauzer Variation, Larsen Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 c8d7 d1d2",
+    ),
+    (
+        EcoCode::new('B', 62),
+        "Sicilian Defense: Richter-Rauzer Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6",
+    ),
+    (
+        EcoCode::new('B', 63),
+        "Sicilian Defense: Richter-Rauzer Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2",
+    ),
+    (
+        EcoCode::new('B', 64),
+        "Sicilian Defense: Richter-Rauzer Variation, Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 f8e7 e1c1 e8g8 f2f4",
+    ),
+    (
+        EcoCode::new('B', 65),
+        "Sicilian Defense: Richter-Rauzer Variation, Classical Variation, 9...Nxd4",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 f8e7 e1c1 e8g8 f2f4 c6d4 d2d4",
+    ),
+    (
+        EcoCode::new('B', 66),
+        "Sicilian Defense: Richter-Rauzer Variation, Neo-Modern Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 a7a6",
+    ),
+    (
+        EcoCode::new('B', 67),
+        "Sicilian Defense: Richter-Rauzer Variation, Neo-Modern Variation, 8...Bd7",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 a7a6 e1c1 c8d7",
+    ),
+    (
+        EcoCode::new('B', 68),
+        "Sicilian Defense: Richter-Rauzer Variation, Neo-Modern Variation, 9...Be7",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 a7a6 e1c1 c8d7 f2f4 f8e7",
+    ),
+    (
+        EcoCode::new('B', 69),
+        "Sicilian Defense: Richter-Rauzer Variation, Neo-Modern Variation, 11.Bxf6",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 b8c6 c1g5 e7e6 d1d2 a7a6 e1c1 c8d7 f2f4 f8e7 d4f3 b7b5 g5f6",
+    ),
+    (
+        EcoCode::new('B', 70),
+        "Sicilian Defense: Dragon Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6",
+    ),
+    (
+        EcoCode::new('B', 71),
+        "Sicilian Defense: Dragon Variation, Levenfish Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 f2f4",
+    ),
+    (
+        EcoCode::new('B', 72),
+        "Sicilian Defense: Dragon Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3",
+    ),
+    (
+        EcoCode::new('B', 73),
+        "Sicilian Defense: Dragon Variation, Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f1e2 b8c6 e1g1",
+    ),
+    (
+        EcoCode::new('B', 74),
+        "Sicilian Defense: Dragon Variation, Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f1e2 b8c6 e1g1 e8g8 d4b3",
+    ),
+    (
+        EcoCode::new('B', 75),
+        "Sicilian Defense: Dragon Variation, Yugoslav Attack",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f2f3",
+    ),
+    (
+        EcoCode::new('B', 76),
+        "Sicilian Defense: Dragon Variation, Yugoslav Attack",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f2f3 e8g8",
+    ),
+    (
+        EcoCode::new('B', 77),
+        "Sicilian Defense: Dragon Variation, Yugoslav Attack, 9.Bc4",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f2f3 e8g8 d1d2 b8c6 f1c4",
+    ),
+    (
+        EcoCode::new('B', 78),
+        "Sicilian Defense: Dragon Variation, Yugoslav Attack, 10.O-O-O",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f2f3 e8g8 d1d2 b8c6 f1c4 c8d7 e1c1",
+    ),
+    (
+        EcoCode::new('B', 79),
+        "Sicilian Defense: Dragon Variation, Yugoslav Attack, 12.h4",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 g7g6 c1e3 f8g7 f2f3 e8g8 d1d2 b8c6 f1c4 c8d7 e1c1 d8a5 c4b3 f8c8 h2h4",
+    ),
+    (
+        EcoCode::new('B', 80),
+        "Sicilian Defense: Scheveningen Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6",
+    ),
+    (
+        EcoCode::new('B', 81),
+        "Sicilian Defense: Scheveningen Variation, Keres Attack",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 g2g4",
+    ),
+    (
+        EcoCode::new('B', 82),
+        "Sicilian Defense: Scheveningen Variation, 6.f4",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f2f4",
+    ),
+    (
+        EcoCode::new('B', 83),
+        "Sicilian Defense: Scheveningen Variation, 6.Be2",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1e2",
+    ),
+    (
+        EcoCode::new('B', 84),
+        "Sicilian Defense: Scheveningen Variation, Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1e2 a7a6",
+    ),
+    (
+        EcoCode::new('B', 85),
+        "Sicilian Defense: Scheveningen Variation, Classical Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1e2 a7a6 e1g1 d8c7 f2f4 b8c6",
+    ),
+    (
+        EcoCode::new('B', 86),
+        "Sicilian Defense: Sozin Attack",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1c4",
+    ),
+    (
+        EcoCode::new('B', 87),
+        "Sicilian Defense: Sozin Attack, Flank Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1c4 a7a6 c4b3 b7b5",
+    ),
+    (
+        EcoCode::new('B', 88),
+        "Sicilian Defense: Sozin Attack, Leonhardt Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1c4 b8c6",
+    ),
+    (
+        EcoCode::new('B', 89),
+        "Sicilian Defense: Sozin Attack, 7.Be3",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 e7e6 f1c4 b8c6 c1e3",
+    ),
+    (
+        EcoCode::new('B', 90),
+        "Sicilian Defense: Najdorf Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6",
+    ),
+    (
+        EcoCode::new('B', 91),
+        "Sicilian Defense: Najdorf Variation, Zagreb Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 g2g3",
+    ),
+    (
+        EcoCode::new('B', 92),
+        "Sicilian Defense: Najdorf Variation, Opocensky Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 f1e2",
+    ),
+    (
+        EcoCode::new('B', 93),
+        "Sicilian Defense: Najdorf Variation, Amsterdam Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 f2f4",
+    ),
+    (
+        EcoCode::new('B', 94),
+        "Sicilian Defense: Najdorf Variation, 6.Bg5",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5",
+    ),
+    (
+        EcoCode::new('B', 95),
+        "Sicilian Defense: Najdorf Variation, 6...e6",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5 e7e6",
+    ),
+    (
+        EcoCode::new('B', 96),
+        "Sicilian Defense: Najdorf Variation, 7.f4",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5 e7e6 f2f4",
+    ),
+    (
+        EcoCode::new('B', 97),
+        "Sicilian Defense: Najdorf Variation, Poisoned Pawn Variation",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5 e7e6 f2f4 d8b6",
+    ),
+    (
+        EcoCode::new('B', 98),
+        "Sicilian Defense: Najdorf Variation, 7...Be7",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5 e7e6 f2f4 f8e7",
+    ),
+    (
+        EcoCode::new('B', 99),
+        "Sicilian Defense: Najdorf Variation, Main Line",
+        "e2e4 c7c5 g1f3 d7d6 d2d4 c5d4 f3d4 g8f6 b1c3 a7a6 c1g5 e7e6 f2f4 f8e7 d1f3 d8c7 e1c1 b8d7",
+    ),
+    // -- C
+    (EcoCode::new('C', 0), "French Defense", "e2e4 e7e6"),
+    (
+        EcoCode::new('C', 1),
+        "French Defense: Exchange Variation",
+        "e2e4 e7e6 d2d4 d7d5 e4d5",
+    ),
+    (
+        EcoCode::new('C', 2),
+        "French Defense: Advance Variation",
+        "e2e4 e7e6 d2d4 d7d5 e4e5",
+    ),
+    (
+        EcoCode::new('C', 3),
+        "French Defense: Tarrasch Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1d2",
+    ),
+    (
+        EcoCode::new('C', 4),
+        "French Defense: Tarrasch Variation, Guimard Defense",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 b8c6 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 5),
+        "French Defense: Tarrasch Variation, Closed Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 g8f6",
+    ),
+    (
+        EcoCode::new('C', 6),
+        "French Defense: Tarrasch Variation, Closed Variation, Main Line",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 g8f6 e4e5 f6d7 f1d3 c7c5 c2c3 b8c6 g1e2 c5d4 c3d4",
+    ),
+    (
+        EcoCode::new('C', 7),
+        "French Defense: Tarrasch Variation, Open System",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 c7c5",
+    ),
+    (
+        EcoCode::new('C', 8),
+        "French Defense: Tarrasch Variation, Open System",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 c7c5 e4d5 e6d5",
+    ),
+    (
+        EcoCode::new('C', 9),
+        "French Defense: Tarrasch Variation, Open System, Main Line",
+        "e2e4 e7e6 d2d4 d7d5 b1d2 c7c5 e4d5 e6d5 g1f3 b8c6",
+    ),
+    (
+        EcoCode::new('C', 10),
+        "French Defense",
+        "e2e4 e7e6 d2d4 d7d5 b1c3",
+    ),
+    (
+        EcoCode::new('C', 11),
+        "French Defense: Classical Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 12),
+        "French Defense: MacCutcheon Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 g8f6 c1g5 f8b4",
+    ),
+    (
+        EcoCode::new('C', 13),
+        "French Defense: Classical Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 g8f6 c1g5 f8e7",
+    ),
+    (
+        EcoCode::new('C', 14),
+        "French Defense: Classical Variation, Main Line",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 g8f6 c1g5 f8e7 e4e5 f6d7 g5e7 d8e7",
+    ),
+    (
+        EcoCode::new('C', 15),
+        "French Defense: Winawer Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 f8b4",
+    ),
+    (
+        EcoCode::new('C', 16),
+        "French Defense: Winawer Variation, Advance Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 f8b4 e4e5",
+    ),
+    (
+        EcoCode::new('C', 17),
+        "French Defense: Winawer Variation, Advance Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 f8b4 e4e5 c7c5",
+    ),
+    (
+        EcoCode::new('C', 18),
+        "French Defense: Winawer Variation, Advance Variation",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 f8b4 e4e5 c7c5 a2a3 b4c3 b2c3",
+    ),
+    (
+        EcoCode::new('C', 19),
+        "French Defense: Winawer Variation, Advance Variation, Main Line",
+        "e2e4 e7e6 d2d4 d7d5 b1c3 f8b4 e4e5 c7c5 a2a3 b4c3 b2c3 g8e7 g1f3",
+    ),
+    (EcoCode::new('C', 20), "King's Pawn Game", "e2e4 e7e5"),
+    (EcoCode::new('C', 21), "Center Game", "e2e4 e7e5 d2d4 e5d4"),
+    (
+        EcoCode::new('C', 22),
+        "Center Game",
+        "e2e4 e7e5 d2d4 e5d4 d1d4 b8c6",
+    ),
+    (EcoCode::new('C', 23), "Bishop's Opening", "e2e4 e7e5 f1c4"),
+    (
+        EcoCode::new('C', 24),
+        "Bishop's Opening: Berlin Defense",
+        "e2e4 e7e5 f1c4 g8f6",
+    ),
+    (EcoCode::new('C', 25), "Vienna Game", "e2e4 e7e5 b1c3"),
+    (
+        EcoCode::new('C', 26),
+        "Vienna Game: Falkbeer Variation",
+        "e2e4 e7e5 b1c3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 27),
+        "Vienna Game: Frankenstein-Dracula Variation",
+        "e2e4 e7e5 b1c3 g8f6 f1c4 f6e4",
+    ),
+    (
+        EcoCode::new('C', 28),
+        "Vienna Game: Stanley Variation, Two Knights Variation",
+        "e2e4 e7e5 b1c3 g8f6 f1c4 b8c6",
+    ),
+    (
+        EcoCode::new('C', 29),
+        "Vienna Game: Vienna Gambit",
+        "e2e4 e7e5 b1c3 g8f6 f2f4",
+    ),
+    (EcoCode::new('C', 30), "King's Gambit", "e2e4 e7e5 f2f4"),
+    (
+        EcoCode::new('C', 31),
+        "King's Gambit Declined: Falkbeer Countergambit",
+        "e2e4 e7e5 f2f4 d7d5",
+    ),
+    (
+        EcoCode::new('C', 32),
+        "King's Gambit Declined: Falkbeer Countergambit, Modern Variation",
+        "e2e4 e7e5 f2f4 d7d5 e4d5 e5e4 d2d3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 33),
+        "King's Gambit Accepted",
+        "e2e4 e7e5 f2f4 e5f4",
+    ),
+    (
+        EcoCode::new('C', 34),
+        "King's Gambit Accepted: King's Knight's Gambit",
+        "e2e4 e7e5 f2f4 e5f4 g1f3",
+    ),
+    (
+        EcoCode::new('C', 35),
+        "King's Gambit Accepted: Cunningham Defense",
+        "e2e4 e7e5 f2f4 e5f4 g1f3 f8e7",
+    ),
+    (
+        EcoCode::new('C', 36),
+        "King's Gambit Accepted: Modern Defense",
+        "e2e4 e7e5 f2f4 e5f4 g1f3 d7d5",
+    ),
+    (
+        EcoCode::new('C', 37),
+        "King's Gambit Accepted: King's Knight's Gambit",
+        "e2e4 e7e5 f2f4 e5f4 g1f3 g7g5 f1c4",
+    ),
+    (
+        EcoCode::new('C', 38),
+        "King's Gambit Accepted: Traditional Variation",
+        "e2e4 e7e5 f2f4 e5f4 g1f3 g7g5 f1c4 f8g7",
+    ),
+    (
+        EcoCode::new('C', 39),
+        "King's Gambit Accepted: Kieseritzky Gambit",
+        "e2e4 e7e5 f2f4 e5f4 g1f3 g7g5 h2h4",
+    ),
+    (
+        EcoCode::new('C', 40),
+        "King's Knight Opening",
+        "e2e4 e7e5 g1f3",
+    ),
+    (
+        EcoCode::new('C', 40),
+        "Latvian Gambit",
+        "e2e4 e7e5 g1f3 f7f5",
+    ),
+    (
+        EcoCode::new('C', 41),
+        "Philidor Defense",
+        "e2e4 e7e5 g1f3 d7d6",
+    ),
+    (
+        EcoCode::new('C', 42),
+        "Petrov's Defense",
+        "e2e4 e7e5 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 43),
+        "Petrov's Defense: Modern Attack",
+        "e2e4 e7e5 g1f3 g8f6 d2d4",
+    ),
+    (
+        EcoCode::new('C', 44),
+        "King's Pawn Game",
+        "e2e4 e7e5 g1f3 b8c6",
+    ),
+    (
+        EcoCode::new('C', 44),
+        "Scotch Game",
+        "e2e4 e7e5 g1f3 b8c6 d2d4",
+    ),
+    (
+        EcoCode::new('C', 45),
+        "Scotch Game",
+        "e2e4 e7e5 g1f3 b8c6 d2d4 e5d4 f3d4",
+    ),
+    (
+        EcoCode::new('C', 46),
+        "Three Knights Opening",
+        "e2e4 e7e5 g1f3 b8c6 b1c3",
+    ),
+    (
+        EcoCode::new('C', 47),
+        "Four Knights Game",
+        "e2e4 e7e5 g1f3 b8c6 b1c3 g8f6",
+    ),
+    (
+        EcoCode::new('C', 48),
+        "Four Knights Game: Spanish Variation",
+        "e2e4 e7e5 g1f3 b8c6 b1c3 g8f6 f1b5",
+    ),
+    (
+        EcoCode::new('C', 49),
+        "Four Knights Game: Double Spanish",
+        "e2e4 e7e5 g1f3 b8c6 b1c3 g8f6 f1b5 f8b4",
+    ),
+    (
+        EcoCode::new('C', 50),
+        "Italian Game",
+        "e2e4 e7e5 g1f3 b8c6 f1c4",
+    ),
+    (
+        EcoCode::new('C', 50),
+        "Italian Game: Giuoco Piano",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5",
+    ),
+    (
+        EcoCode::new('C', 51),
+        "Italian Game: Evans Gambit",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5 b2b4",
+    ),
+    (
+        EcoCode::new('C', 52),
+        "Italian Game: Evans Gambit Accepted",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5 b2b4 c5b4 c2c3 b4a5",
+    ),
+    (
+        EcoCode::new('C', 53),
+        "Italian Game: Giuoco Piano",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5 c2c3",
+    ),
+    (
+        EcoCode::new('C', 54),
+        "Italian Game: Giuoco Piano, Main Line",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 f8c5 c2c3 g8f6 d2d4",
+    ),
+    (
+        EcoCode::new('C', 55),
+        "Italian Game: Two Knights Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6",
+    ),
+    (
+        EcoCode::new('C', 56),
+        "Italian Game: Two Knights Defense, Scotch Gambit",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 d2d4 e5d4 e1g1 f6e4",
+    ),
+    (
+        EcoCode::new('C', 57),
+        "Italian Game: Two Knights Defense, Knight Attack",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 f3g5",
+    ),
+    (
+        EcoCode::new('C', 58),
+        "Italian Game: Two Knights Defense, Knight Attack, Normal Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 f3g5 d7d5 e4d5 c6a5",
+    ),
+    (
+        EcoCode::new('C', 59),
+        "Italian Game: Two Knights Defense, Knight Attack, Main Line",
+        "e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 f3g5 d7d5 e4d5 c6a5 c4b5 c7c6 d5c6 b7c6 b5e2 h7h6",
+    ),
+    (
+        EcoCode::new('C', 60),
+        "Ruy Lopez",
+        "e2e4 e7e5 g1f3 b8c6 f1b5",
+    ),
+    (
+        EcoCode::new('C', 61),
+        "Ruy Lopez: Bird Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 c6d4",
+    ),
+    (
+        EcoCode::new('C', 62),
+        "Ruy Lopez: Steinitz Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 d7d6",
+    ),
+    (
+        EcoCode::new('C', 63),
+        "Ruy Lopez: Schliemann Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 f7f5",
+    ),
+    (
+        EcoCode::new('C', 64),
+        "Ruy Lopez: Classical Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 f8c5",
+    ),
+    (
+        EcoCode::new('C', 65),
+        "Ruy Lopez: Berlin Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 g8f6",
+    ),
+    (
+        EcoCode::new('C', 66),
+        "Ruy Lopez: Berlin Defense, Improved Steinitz Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 g8f6 e1g1 d7d6",
+    ),
+    (
+        EcoCode::new('C', 67),
+        "Ruy Lopez: Berlin Defense, Rio Gambit Accepted",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 g8f6 e1g1 f6e4",
+    ),
+    (
+        EcoCode::new('C', 68),
+        "Ruy Lopez: Exchange Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5c6",
+    ),
+    (
+        EcoCode::new('C', 69),
+        "Ruy Lopez: Exchange Variation, Normal Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5c6 d7c6 e1g1",
+    ),
+    (
+        EcoCode::new('C', 70),
+        "Ruy Lopez",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4",
+    ),
+    (
+        EcoCode::new('C', 71),
+        "Ruy Lopez: Modern Steinitz Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6",
+    ),
+    (
+        EcoCode::new('C', 72),
+        "Ruy Lopez: Modern Steinitz Defense, 5.O-O",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6 e1g1",
+    ),
+    (
+        EcoCode::new('C', 73),
+        "Ruy Lopez: Modern Steinitz Defense, Richter Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6 a4c6 b7c6 d2d4",
+    ),
+    (
+        EcoCode::new('C', 74),
+        "Ruy Lopez: Modern Steinitz Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6 c2c3",
+    ),
+    (
+        EcoCode::new('C', 75),
+        "Ruy Lopez: Modern Steinitz Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6 c2c3 c8d7",
+    ),
+    (
+        EcoCode::new('C', 76),
+        "Ruy Lopez: Modern Steinitz Defense, Fianchetto Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 d7d6 c2c3 c8d7 d2d4 g7g6",
+    ),
+    (
+        EcoCode::new('C', 77),
+        "Ruy Lopez: Morphy Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6",
+    ),
+    (
+        EcoCode::new('C', 78),
+        "Ruy Lopez",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1",
+    ),
+    (
+        EcoCode::new('C', 79),
+        "Ruy Lopez: Steinitz Defense Deferred",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 d7d6",
+    ),
+    (
+        EcoCode::new('C', 80),
+        "Ruy Lopez: Open",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f6e4",
+    ),
+    (
+        EcoCode::new('C', 81),
+        "Ruy Lopez: Open, Howell Attack",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f6e4 d2d4 b7b5 a4b3 d7d5 d4e5 c8e6 d1e2",
+    ),
+    (
+        EcoCode::new('C', 82),
+        "Ruy Lopez: Open",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f6e4 d2d4 b7b5 a4b3 d7d5 d4e5 c8e6 c2c3",
+    ),
+    (
+        EcoCode::new('C', 83),
+        "Ruy Lopez: Open, Classical Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f6e4 d2d4 b7b5 a4b3 d7d5 d4e5 c8e6 c2c3 f8e7",
+    ),
+    (
+        EcoCode::new('C', 84),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7",
+    ),
+    (
+        EcoCode::new('C', 85),
+        "Ruy Lopez: Closed, Delayed Exchange",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 a4c6",
+    ),
+    (
+        EcoCode::new('C', 86),
+        "Ruy Lopez: Worrall Attack",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 d1e2",
+    ),
+    (
+        EcoCode::new('C', 87),
+        "Ruy Lopez: Closed, Averbakh Variation",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 d7d6",
+    ),
+    (
+        EcoCode::new('C', 88),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3",
+    ),
+    (
+        EcoCode::new('C', 89),
+        "Ruy Lopez: Marshall Attack",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 e8g8 c2c3 d7d5",
+    ),
+    (
+        EcoCode::new('C', 90),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6",
+    ),
+    (
+        EcoCode::new('C', 91),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 d2d4",
+    ),
+    (
+        EcoCode::new('C', 92),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3",
+    ),
+    (
+        EcoCode::new('C', 93),
+        "Ruy Lopez: Closed, Smyslov Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 h7h6",
+    ),
+    (
+        EcoCode::new('C', 94),
+        "Ruy Lopez: Closed, Breyer Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6b8",
+    ),
+    (
+        EcoCode::new('C', 95),
+        "Ruy Lopez: Closed, Breyer Defense, 10.d4",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6b8 d2d4",
+    ),
+    (
+        EcoCode::new('C', 96),
+        "Ruy Lopez: Closed",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6a5 b3c2",
+    ),
+    (
+        EcoCode::new('C', 97),
+        "Ruy Lopez: Closed, Chigorin Defense",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6a5 b3c2 c7c5 d2d4 d8c7",
+    ),
+    (
+        EcoCode::new('C', 98),
+        "Ruy Lopez: Closed, Chigorin Defense, 12...Nc6",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6a5 b3c2 c7c5 d2d4 d8c7 b1d2 a5c6",
+    ),
+    (
+        EcoCode::new('C', 99),
+        "Ruy Lopez: Closed, Chigorin Defense, 12...cxd4",
+        "e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4 g8f6 e1g1 f8e7 f1e1 b7b5 a4b3 d7d6 c2c3 e8g8 h2h3 c6a5 b3c2 c7c5 d2d4 d8c7 b1d2 c5d4 c3d4",
+    ),
+    // -- D
+    (EcoCode::new('D', 0), "Queen's Pawn Game", "d2d4 d7d5"),
+    (
+        EcoCode::new('D', 0),
+        "Queen's Pawn Game: London System",
+        "d2d4 d7d5 c1f4",
+    ),
+    (
+        EcoCode::new('D', 1),
+        "Richter-Veresov Attack",
+        "d2d4 d7d5 b1c3 g8f6 c1g5",
+    ),
+    (EcoCode::new('D', 2), "Queen's Pawn Game", "d2d4 d7d5 g1f3"),
+    (
+        EcoCode::new('D', 3),
+        "Queen's Pawn Game: Torre Attack",
+        "d2d4 d7d5 g1f3 g8f6 c1g5",
+    ),
+    (
+        EcoCode::new('D', 4),
+        "Queen's Pawn Game: Colle System",
+        "d2d4 d7d5 g1f3 g8f6 e2e3",
+    ),
+    (
+        EcoCode::new('D', 5),
+        "Queen's Pawn Game: Colle System",
+        "d2d4 d7d5 g1f3 g8f6 e2e3 e7e6",
+    ),
+    (EcoCode::new('D', 6), "Queen's Gambit", "d2d4 d7d5 c2c4"),
+    (
+        EcoCode::new('D', 7),
+        "Queen's Gambit Declined: Chigorin Defense",
+        "d2d4 d7d5 c2c4 b8c6",
+    ),
+    (
+        EcoCode::new('D', 8),
+        "Queen's Gambit Declined: Albin Countergambit",
+        "d2d4 d7d5 c2c4 e7e5",
+    ),
+    (
+        EcoCode::new('D', 9),
+        "Queen's Gambit Declined: Albin Countergambit, Fianchetto Variation",
+        "d2d4 d7d5 c2c4 e7e5 d4e5 d5d4 g1f3 b8c6 g2g3",
+    ),
+    (EcoCode::new('D', 10), "Slav Defense", "d2d4 d7d5 c2c4 c7c6"),
+    (
+        EcoCode::new('D', 11),
+        "Slav Defense: Modern Line",
+        "d2d4 d7d5 c2c4 c7c6 g1f3",
+    ),
+    (
+        EcoCode::new('D', 12),
+        "Slav Defense: Quiet Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 e2e3 c8f5",
+    ),
+    (
+        EcoCode::new('D', 13),
+        "Slav Defense: Exchange Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 c4d5 c6d5",
+    ),
+    (
+        EcoCode::new('D', 14),
+        "Slav Defense: Exchange Variation, Symmetrical Line",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 c4d5 c6d5 b1c3 b8c6 c1f4 c8f5",
+    ),
+    (
+        EcoCode::new('D', 15),
+        "Slav Defense: Three Knights Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3",
+    ),
+    (
+        EcoCode::new('D', 16),
+        "Slav Defense: Alapin Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 d5c4 a2a4",
+    ),
+    (
+        EcoCode::new('D', 17),
+        "Slav Defense: Czech Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 d5c4 a2a4 c8f5",
+    ),
+    (
+        EcoCode::new('D', 18),
+        "Slav Defense: Czech Variation, Dutch Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 d5c4 a2a4 c8f5 e2e3",
+    ),
+    (
+        EcoCode::new('D', 19),
+        "Slav Defense: Czech Variation, Dutch Variation, Main Line",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 d5c4 a2a4 c8f5 e2e3 e7e6 f1c4 f8b4 e1g1",
+    ),
+    (
+        EcoCode::new('D', 20),
+        "Queen's Gambit Accepted",
+        "d2d4 d7d5 c2c4 d5c4",
+    ),
+    (
+        EcoCode::new('D', 21),
+        "Queen's Gambit Accepted",
+        "d2d4 d7d5 c2c4 d5c4 g1f3",
+    ),
+    (
+        EcoCode::new('D', 22),
+        "Queen's Gambit Accepted: Alekhine Defense",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 a7a6",
+    ),
+    (
+        EcoCode::new('D', 23),
+        "Queen's Gambit Accepted",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6",
+    ),
+    (
+        EcoCode::new('D', 24),
+        "Queen's Gambit Accepted",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 b1c3",
+    ),
+    (
+        EcoCode::new('D', 25),
+        "Queen's Gambit Accepted",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3",
+    ),
+    (
+        EcoCode::new('D', 26),
+        "Queen's Gambit Accepted: Normal Variation",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3 e7e6",
+    ),
+    (
+        EcoCode::new('D', 27),
+        "Queen's Gambit Accepted: Classical Defense",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3 e7e6 f1c4 c7c5 e1g1 a7a6",
+    ),
+    (
+        EcoCode::new('D', 28),
+        "Queen's Gambit Accepted: Classical Defense, 7.Qe2",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3 e7e6 f1c4 c7c5 e1g1 a7a6 d1e2",
+    ),
+    (
+        EcoCode::new('D', 29),
+        "Queen's Gambit Accepted: Classical Defense, 8...Bb7",
+        "d2d4 d7d5 c2c4 d5c4 g1f3 g8f6 e2e3 e7e6 f1c4 c7c5 e1g1 a7a6 d1e2 b7b5 c4b3 c8b7",
+    ),
+    (
+        EcoCode::new('D', 30),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6",
+    ),
+    (
+        EcoCode::new('D', 31),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6 b1c3",
+    ),
+    (
+        EcoCode::new('D', 32),
+        "Tarrasch Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 c7c5",
+    ),
+    (
+        EcoCode::new('D', 33),
+        "Tarrasch Defense: Schlechter-Rubinstein System",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 c7c5 c4d5 e6d5 g1f3 b8c6 g2g3",
+    ),
+    (
+        EcoCode::new('D', 34),
+        "Tarrasch Defense: Prague Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 c7c5 c4d5 e6d5 g1f3 b8c6 g2g3 g8f6 f1g2 f8e7",
+    ),
+    (
+        EcoCode::new('D', 35),
+        "Queen's Gambit Declined: Exchange Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c4d5",
+    ),
+    (
+        EcoCode::new('D', 36),
+        "Queen's Gambit Declined: Exchange Variation, Positional Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c4d5 e6d5 c1g5 c7c6 d1c2",
+    ),
+    (
+        EcoCode::new('D', 37),
+        "Queen's Gambit Declined: Three Knights Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3",
+    ),
+    (
+        EcoCode::new('D', 38),
+        "Queen's Gambit Declined: Ragozin Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3 f8b4",
+    ),
+    (
+        EcoCode::new('D', 39),
+        "Queen's Gambit Declined: Ragozin Defense, Vienna Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3 f8b4 c1g5 d5c4",
+    ),
+    (
+        EcoCode::new('D', 40),
+        "Queen's Gambit Declined: Semi-Tarrasch Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3 c7c5",
+    ),
+    (
+        EcoCode::new('D', 41),
+        "Queen's Gambit Declined: Semi-Tarrasch Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3 c7c5 c4d5",
+    ),
+    (
+        EcoCode::new('D', 42),
+        "Queen's Gambit Declined: Semi-Tarrasch Defense, 7.Bd3",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 g1f3 c7c5 c4d5 f6d5 e2e3 b8c6 f1d3",
+    ),
+    (
+        EcoCode::new('D', 43),
+        "Semi-Slav Defense",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6",
+    ),
+    (
+        EcoCode::new('D', 44),
+        "Semi-Slav Defense: Botvinnik System",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 c1g5 d5c4",
+    ),
+    (
+        EcoCode::new('D', 45),
+        "Semi-Slav Defense",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 e2e3",
+    ),
+    (
+        EcoCode::new('D', 46),
+        "Semi-Slav Defense",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 e2e3 b8d7 f1d3",
+    ),
+    (
+        EcoCode::new('D', 47),
+        "Semi-Slav Defense: Meran Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 e2e3 b8d7 f1d3 d5c4 d3c4",
+    ),
+    (
+        EcoCode::new('D', 48),
+        "Semi-Slav Defense: Meran Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 e2e3 b8d7 f1d3 d5c4 d3c4 b7b5 c4d3 a7a6",
+    ),
+    (
+        EcoCode::new('D', 49),
+        "Semi-Slav Defense: Meran Variation, Blumenfeld Variation",
+        "d2d4 d7d5 c2c4 c7c6 g1f3 g8f6 b1c3 e7e6 e2e3 b8d7 f1d3 d5c4 d3c4 b7b5 c4d3 a7a6 e3e4 c6c5 e4e5 c5d4 c3b5",
+    ),
+    (
+        EcoCode::new('D', 50),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5",
+    ),
+    (
+        EcoCode::new('D', 51),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 b8d7",
+    ),
+    (
+        EcoCode::new('D', 52),
+        "Queen's Gambit Declined: Cambridge Springs Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 b8d7 e2e3 c7c6 g1f3 d8a5",
+    ),
+    (
+        EcoCode::new('D', 53),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7",
+    ),
+    (
+        EcoCode::new('D', 54),
+        "Queen's Gambit Declined: Anti-Neo-Orthodox Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 a1c1",
+    ),
+    (
+        EcoCode::new('D', 55),
+        "Queen's Gambit Declined",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3",
+    ),
+    (
+        EcoCode::new('D', 56),
+        "Queen's Gambit Declined: Lasker Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 h7h6 g5h4 f6e4",
+    ),
+    (
+        EcoCode::new('D', 57),
+        "Queen's Gambit Declined: Lasker Defense, Main Line",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 h7h6 g5h4 f6e4 h4e7 d8e7 c4d5 e4c3 b2c3",
+    ),
+    (
+        EcoCode::new('D', 58),
+        "Queen's Gambit Declined: Tartakower Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 h7h6 g5h4 b7b6",
+    ),
+    (
+        EcoCode::new('D', 59),
+        "Queen's Gambit Declined: Tartakower Defense, 8.cxd5 Nxd5",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 h7h6 g5h4 b7b6 c4d5 f6d5",
+    ),
+    (
+        EcoCode::new('D', 60),
+        "Queen's Gambit Declined: Orthodox Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7",
+    ),
+    (
+        EcoCode::new('D', 61),
+        "Queen's Gambit Declined: Orthodox Defense, Rubinstein Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 d1c2",
+    ),
+    (
+        EcoCode::new('D', 62),
+        "Queen's Gambit Declined: Orthodox Defense, Rubinstein Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 d1c2 c7c5 c4d5",
+    ),
+    (
+        EcoCode::new('D', 63),
+        "Queen's Gambit Declined: Orthodox Defense",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1",
+    ),
+    (
+        EcoCode::new('D', 64),
+        "Queen's Gambit Declined: Orthodox Defense, Rubinstein Attack",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 d1c2",
+    ),
+    (
+        EcoCode::new('D', 65),
+        "Queen's Gambit Declined: Orthodox Defense, Rubinstein Attack, Main Line",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 d1c2 a7a6 c4d5",
+    ),
+    (
+        EcoCode::new('D', 66),
+        "Queen's Gambit Declined: Orthodox Defense, Main Line",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 f1d3",
+    ),
+    (
+        EcoCode::new('D', 67),
+        "Queen's Gambit Declined: Orthodox Defense, Capablanca Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 f1d3 d5c4 d3c4 f6d5",
+    ),
+    (
+        EcoCode::new('D', 68),
+        "Queen's Gambit Declined: Orthodox Defense, Classical Variation",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 f1d3 d5c4 d3c4 f6d5 g5e7 d8e7 e1g1 d5c3 c1c3 e6e5",
+    ),
+    (
+        EcoCode::new('D', 69),
+        "Queen's Gambit Declined: Orthodox Defense, Classical Variation, 13.dxe5",
+        "d2d4 d7d5 c2c4 e7e6 b1c3 g8f6 c1g5 f8e7 e2e3 e8g8 g1f3 b8d7 a1c1 c7c6 f1d3 d5c4 d3c4 f6d5 g5e7 d8e7 e1g1 d5c3 c1c3 e6e5 d4e5 d7e5 f3e5 e7e5",
+    ),
+    (
+        EcoCode::new('D', 70),
+        "Neo-Grünfeld Defense",
+        "d2d4 g8f6 c2c4 g7g6 f2f3 d7d5",
+    ),
+    (
+        EcoCode::new('D', 71),
+        "Neo-Grünfeld Defense",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5",
+    ),
+    (
+        EcoCode::new('D', 72),
+        "Neo-Grünfeld Defense: Exchange Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 c4d5 f6d5 e2e4 d5b6 g1e2",
+    ),
+    (
+        EcoCode::new('D', 73),
+        "Neo-Grünfeld Defense",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3",
+    ),
+    (
+        EcoCode::new('D', 74),
+        "Neo-Grünfeld Defense: Exchange Variation",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 c4d5 f6d5 e1g1",
+    ),
+    (
+        EcoCode::new('D', 75),
+        "Neo-Grünfeld Defense: Exchange Variation, 7...c5",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 c4d5 f6d5 e1g1 c7c5 b1c3",
+    ),
+    (
+        EcoCode::new('D', 76),
+        "Neo-Grünfeld Defense: Exchange Variation, 7...Nb6",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 c4d5 f6d5 e1g1 d5b6",
+    ),
+    (
+        EcoCode::new('D', 77),
+        "Neo-Grünfeld Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 e1g1",
+    ),
+    (
+        EcoCode::new('D', 78),
+        "Neo-Grünfeld Defense: Classical Variation, 6...c6",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 e1g1 c7c6",
+    ),
+    (
+        EcoCode::new('D', 79),
+        "Neo-Grünfeld Defense: Classical Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 g2g3 d7d5 f1g2 f8g7 g1f3 e8g8 e1g1 c7c6 c4d5 c6d5",
+    ),
+    (
+        EcoCode::new('D', 80),
+        "Grünfeld Defense",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5",
+    ),
+    (
+        EcoCode::new('D', 81),
+        "Grünfeld Defense: Russian Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 d1b3",
+    ),
+    (
+        EcoCode::new('D', 82),
+        "Grünfeld Defense: Brinckmann Attack",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c1f4",
+    ),
+    (
+        EcoCode::new('D', 83),
+        "Grünfeld Defense: Grünfeld Gambit",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c1f4 f8g7 e2e3 e8g8",
+    ),
+    (
+        EcoCode::new('D', 84),
+        "Grünfeld Defense: Grünfeld Gambit Accepted",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c1f4 f8g7 e2e3 e8g8 c4d5 f6d5 c3d5 d8d5 f4c7",
+    ),
+    (
+        EcoCode::new('D', 85),
+        "Grünfeld Defense: Exchange Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5",
+    ),
+    (
+        EcoCode::new('D', 86),
+        "Grünfeld Defense: Exchange Variation, Classical Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5 e2e4 d5c3 b2c3 f8g7 f1c4",
+    ),
+    (
+        EcoCode::new('D', 87),
+        "Grünfeld Defense: Exchange Variation, Spassky Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5 e2e4 d5c3 b2c3 f8g7 f1c4 e8g8 g1e2 c7c5",
+    ),
+    (
+        EcoCode::new('D', 88),
+        "Grünfeld Defense: Exchange Variation, Spassky Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5 e2e4 d5c3 b2c3 f8g7 f1c4 e8g8 g1e2 c7c5 e1g1 b8c6 c1e3 c5d4 c3d4",
+    ),
+    (
+        EcoCode::new('D', 89),
+        "Grünfeld Defense: Exchange Variation, Spassky Variation, 13.Bd3",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 c4d5 f6d5 e2e4 d5c3 b2c3 f8g7 f1c4 e8g8 g1e2 c7c5 e1g1 b8c6 c1e3 c5d4 c3d4 c8g4 f2f3 c6a5 c4d3 g4e6",
+    ),
+    (
+        EcoCode::new('D', 90),
+        "Grünfeld Defense: Three Knights Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3",
+    ),
+    (
+        EcoCode::new('D', 91),
+        "Grünfeld Defense: Three Knights Variation, 5.Bg5",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 c1g5",
+    ),
+    (
+        EcoCode::new('D', 92),
+        "Grünfeld Defense: Three Knights Variation, 5.Bf4",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 c1f4",
+    ),
+    (
+        EcoCode::new('D', 93),
+        "Grünfeld Defense: Three Knights Variation, 5.Bf4 O-O 6.e3",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 c1f4 e8g8 e2e3",
+    ),
+    (
+        EcoCode::new('D', 94),
+        "Grünfeld Defense: Three Knights Variation, 5.e3",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 e2e3",
+    ),
+    (
+        EcoCode::new('D', 95),
+        "Grünfeld Defense: Three Knights Variation, 6.Qb3",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 e2e3 e8g8 d1b3",
+    ),
+    (
+        EcoCode::new('D', 96),
+        "Grünfeld Defense: Russian Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 d1b3",
+    ),
+    (
+        EcoCode::new('D', 97),
+        "Grünfeld Defense: Russian Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 d1b3 d5c4 b3c4 e8g8 e2e4",
+    ),
+    (
+        EcoCode::new('D', 98),
+        "Grünfeld Defense: Russian Variation, Smyslov Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 d1b3 d5c4 b3c4 e8g8 e2e4 c8g4",
+    ),
+    (
+        EcoCode::new('D', 99),
+        "Grünfeld Defense: Russian Variation, Smyslov Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 d7d5 g1f3 f8g7 d1b3 d5c4 b3c4 e8g8 e2e4 c8g4 c1e3 f6d7 c4b3",
+    ),
+    // -- E
+    (
+        EcoCode::new('E', 0),
+        "Indian Defense",
+        "d2d4 g8f6 c2c4 e7e6",
+    ),
+    (
+        EcoCode::new('E', 1),
+        "Catalan Opening",
+        "d2d4 g8f6 c2c4 e7e6 g2g3",
+    ),
+    (
+        EcoCode::new('E', 2),
+        "Catalan Opening: Open Defense, 5.Qa4",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 d5c4 d1a4",
+    ),
+    (
+        EcoCode::new('E', 3),
+        "Catalan Opening: Open Defense",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 d5c4 d1a4 b8d7 a4c4",
+    ),
+    (
+        EcoCode::new('E', 4),
+        "Catalan Opening: Open Defense, 5.Nf3",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 d5c4 g1f3",
+    ),
+    (
+        EcoCode::new('E', 5),
+        "Catalan Opening: Open Defense, Classical Line",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 d5c4 g1f3 f8e7",
+    ),
+    (
+        EcoCode::new('E', 6),
+        "Catalan Opening: Closed Variation",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 f8e7 g1f3",
+    ),
+    (
+        EcoCode::new('E', 7),
+        "Catalan Opening: Closed Variation",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 f8e7 g1f3 e8g8 e1g1 b8d7",
+    ),
+    (
+        EcoCode::new('E', 8),
+        "Catalan Opening: Closed Variation",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 f8e7 g1f3 e8g8 e1g1 b8d7 d1c2",
+    ),
+    (
+        EcoCode::new('E', 9),
+        "Catalan Opening: Closed Variation, Main Line",
+        "d2d4 g8f6 c2c4 e7e6 g2g3 d7d5 f1g2 f8e7 g1f3 e8g8 e1g1 b8d7 d1c2 c7c6 b1d2",
+    ),
+    (
+        EcoCode::new('E', 10),
+        "Indian Defense",
+        "d2d4 g8f6 c2c4 e7e6 g1f3",
+    ),
+    (
+        EcoCode::new('E', 11),
+        "Bogo-Indian Defense",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 f8b4",
+    ),
+    (
+        EcoCode::new('E', 12),
+        "Queen's Indian Defense",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6",
+    ),
+    (
+        EcoCode::new('E', 13),
+        "Queen's Indian Defense: Kasparov Variation",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 b1c3 c8b7 c1g5",
+    ),
+    (
+        EcoCode::new('E', 14),
+        "Queen's Indian Defense: Spassky System",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 e2e3",
+    ),
+    (
+        EcoCode::new('E', 15),
+        "Queen's Indian Defense: Fianchetto Variation",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3",
+    ),
+    (
+        EcoCode::new('E', 16),
+        "Queen's Indian Defense: Capablanca Variation",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3 c8b7 f1g2 f8b4",
+    ),
+    (
+        EcoCode::new('E', 17),
+        "Queen's Indian Defense: Fianchetto Variation, Traditional Variation",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3 c8b7 f1g2 f8e7",
+    ),
+    (
+        EcoCode::new('E', 18),
+        "Queen's Indian Defense: Fianchetto Variation, Old Main Line",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3 c8b7 f1g2 f8e7 e1g1 e8g8 b1c3",
+    ),
+    (
+        EcoCode::new('E', 19),
+        "Queen's Indian Defense: Fianchetto Variation, Old Main Line, 9.Qxc3",
+        "d2d4 g8f6 c2c4 e7e6 g1f3 b7b6 g2g3 c8b7 f1g2 f8e7 e1g1 e8g8 b1c3 f6e4 d1c2 e4c3 c2c3",
+    ),
+    (
+        EcoCode::new('E', 20),
+        "Nimzo-Indian Defense",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4",
+    ),
+    (
+        EcoCode::new('E', 21),
+        "Nimzo-Indian Defense: Three Knights Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 g1f3",
+    ),
+    (
+        EcoCode::new('E', 22),
+        "Nimzo-Indian Defense: Spielmann Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1b3",
+    ),
+    (
+        EcoCode::new('E', 23),
+        "Nimzo-Indian Defense: Spielmann Variation, 4...c5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1b3 c7c5 d4c5 b8c6",
+    ),
+    (
+        EcoCode::new('E', 24),
+        "Nimzo-Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3",
+    ),
+    (
+        EcoCode::new('E', 25),
+        "Nimzo-Indian Defense: Sämisch Variation, Keres Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3 c7c5 f2f3 d7d5 c4d5",
+    ),
+    (
+        EcoCode::new('E', 26),
+        "Nimzo-Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3 c7c5 e2e3",
+    ),
+    (
+        EcoCode::new('E', 27),
+        "Nimzo-Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3 e8g8",
+    ),
+    (
+        EcoCode::new('E', 28),
+        "Nimzo-Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3 e8g8 e2e3",
+    ),
+    (
+        EcoCode::new('E', 29),
+        "Nimzo-Indian Defense: Sämisch Variation, Main Line",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 a2a3 b4c3 b2c3 e8g8 e2e3 c7c5 f1d3 b8c6",
+    ),
+    (
+        EcoCode::new('E', 30),
+        "Nimzo-Indian Defense: Leningrad Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 c1g5",
+    ),
+    (
+        EcoCode::new('E', 31),
+        "Nimzo-Indian Defense: Leningrad Variation, Main Line",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 c1g5 h7h6 g5h4 c7c5 d4d5 d7d6",
+    ),
+    (
+        EcoCode::new('E', 32),
+        "Nimzo-Indian Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2",
+    ),
+    (
+        EcoCode::new('E', 33),
+        "Nimzo-Indian Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 b8c6",
+    ),
+    (
+        EcoCode::new('E', 34),
+        "Nimzo-Indian Defense: Classical Variation, Noa Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 d7d5",
+    ),
+    (
+        EcoCode::new('E', 35),
+        "Nimzo-Indian Defense: Classical Variation, Noa Variation, 5.cxd5 exd5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 d7d5 c4d5 e6d5",
+    ),
+    (
+        EcoCode::new('E', 36),
+        "Nimzo-Indian Defense: Classical Variation, Noa Variation, 5.a3",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 d7d5 a2a3",
+    ),
+    (
+        EcoCode::new('E', 37),
+        "Nimzo-Indian Defense: Classical Variation, Noa Variation, Main Line",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 d7d5 a2a3 b4c3 c2c3 f6e4 c3c2",
+    ),
+    (
+        EcoCode::new('E', 38),
+        "Nimzo-Indian Defense: Classical Variation, 4...c5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 c7c5",
+    ),
+    (
+        EcoCode::new('E', 39),
+        "Nimzo-Indian Defense: Classical Variation, Pirc Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 d1c2 c7c5 d4c5 e8g8",
+    ),
+    (
+        EcoCode::new('E', 40),
+        "Nimzo-Indian Defense: Normal Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3",
+    ),
+    (
+        EcoCode::new('E', 41),
+        "Nimzo-Indian Defense: Hübner Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 c7c5",
+    ),
+    (
+        EcoCode::new('E', 42),
+        "Nimzo-Indian Defense: Hübner Variation, Rubinstein Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 c7c5 g1e2",
+    ),
+    (
+        EcoCode::new('E', 43),
+        "Nimzo-Indian Defense: St. Petersburg Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 b7b6",
+    ),
+    (
+        EcoCode::new('E', 44),
+        "Nimzo-Indian Defense: Fischer Variation, 5.Ne2",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 b7b6 g1e2",
+    ),
+    (
+        EcoCode::new('E', 45),
+        "Nimzo-Indian Defense: Normal Variation, Bronstein Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 b7b6 g1e2 c8a6",
+    ),
+    (
+        EcoCode::new('E', 46),
+        "Nimzo-Indian Defense: Normal Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8",
+    ),
+    (
+        EcoCode::new('E', 47),
+        "Nimzo-Indian Defense: Normal Variation, 5.Bd3",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 f1d3",
+    ),
+    (
+        EcoCode::new('E', 48),
+        "Nimzo-Indian Defense: Normal Variation, 5.Bd3 d5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 f1d3 d7d5",
+    ),
+    (
+        EcoCode::new('E', 49),
+        "Nimzo-Indian Defense: Normal Variation, Botvinnik System",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 f1d3 d7d5 a2a3 b4c3 b2c3",
+    ),
+    (
+        EcoCode::new('E', 50),
+        "Nimzo-Indian Defense: Normal Variation, 5.Nf3",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3",
+    ),
+    (
+        EcoCode::new('E', 51),
+        "Nimzo-Indian Defense: Normal Variation, 5...d5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5",
+    ),
+    (
+        EcoCode::new('E', 52),
+        "Nimzo-Indian Defense: Normal Variation, Main Line with ...b6",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 b7b6",
+    ),
+    (
+        EcoCode::new('E', 53),
+        "Nimzo-Indian Defense: Normal Variation, Main Line with ...c5",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5",
+    ),
+    (
+        EcoCode::new('E', 54),
+        "Nimzo-Indian Defense: Normal Variation, Gligoric System",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 d5c4 d3c4",
+    ),
+    (
+        EcoCode::new('E', 55),
+        "Nimzo-Indian Defense: Normal Variation, Gligoric System, Bronstein Variation",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 d5c4 d3c4 b8d7",
+    ),
+    (
+        EcoCode::new('E', 56),
+        "Nimzo-Indian Defense: Normal Variation, Main Line",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 b8c6",
+    ),
+    (
+        EcoCode::new('E', 57),
+        "Nimzo-Indian Defense: Normal Variation, Main Line, 9...cxd4",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 b8c6 a2a3 d5c4 d3c4 c5d4",
+    ),
+    (
+        EcoCode::new('E', 58),
+        "Nimzo-Indian Defense: Normal Variation, Main Line, 8...Bxc3",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 b8c6 a2a3 b4c3 b2c3",
+    ),
+    (
+        EcoCode::new('E', 59),
+        "Nimzo-Indian Defense: Normal Variation, Main Line, 9...dxc4",
+        "d2d4 g8f6 c2c4 e7e6 b1c3 f8b4 e2e3 e8g8 g1f3 d7d5 f1d3 c7c5 e1g1 b8c6 a2a3 b4c3 b2c3 d5c4 d3c4",
+    ),
+    (
+        EcoCode::new('E', 60),
+        "King's Indian Defense",
+        "d2d4 g8f6 c2c4 g7g6",
+    ),
+    (
+        EcoCode::new('E', 61),
+        "King's Indian Defense",
+        "d2d4 g8f6 c2c4 g7g6 b1c3",
+    ),
+    (
+        EcoCode::new('E', 62),
+        "King's Indian Defense: Fianchetto Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3",
+    ),
+    (
+        EcoCode::new('E', 63),
+        "King's Indian Defense: Fianchetto Variation, Panno Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 b8c6 e1g1 a7a6",
+    ),
+    (
+        EcoCode::new('E', 64),
+        "King's Indian Defense: Fianchetto Variation, Yugoslav System",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 c7c5",
+    ),
+    (
+        EcoCode::new('E', 65),
+        "King's Indian Defense: Fianchetto Variation, Yugoslav System, 7.O-O",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 c7c5 e1g1",
+    ),
+    (
+        EcoCode::new('E', 66),
+        "King's Indian Defense: Fianchetto Variation, Yugoslav Panno",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 c7c5 e1g1 b8c6 d4d5",
+    ),
+    (
+        EcoCode::new('E', 67),
+        "King's Indian Defense: Fianchetto Variation, Classical Fianchetto",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 b8d7",
+    ),
+    (
+        EcoCode::new('E', 68),
+        "King's Indian Defense: Fianchetto Variation, Classical Variation, 8.e4",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 b8d7 e1g1 e7e5 e2e4",
+    ),
+    (
+        EcoCode::new('E', 69),
+        "King's Indian Defense: Fianchetto Variation, Classical Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 g1f3 d7d6 g2g3 e8g8 f1g2 b8d7 e1g1 e7e5 e2e4 c7c6 h2h3",
+    ),
+    (
+        EcoCode::new('E', 70),
+        "King's Indian Defense",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4",
+    ),
+    (
+        EcoCode::new('E', 71),
+        "King's Indian Defense: Makogonov Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 h2h3",
+    ),
+    (
+        EcoCode::new('E', 72),
+        "King's Indian Defense: Normal Variation, Deferred Fianchetto",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g2g3",
+    ),
+    (
+        EcoCode::new('E', 73),
+        "King's Indian Defense: Normal Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f1e2",
+    ),
+    (
+        EcoCode::new('E', 74),
+        "King's Indian Defense: Averbakh Variation, 6...c5",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f1e2 e8g8 c1g5 c7c5",
+    ),
+    (
+        EcoCode::new('E', 75),
+        "King's Indian Defense: Averbakh Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f1e2 e8g8 c1g5 c7c5 d4d5 e7e6",
+    ),
+    (
+        EcoCode::new('E', 76),
+        "King's Indian Defense: Four Pawns Attack",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f4",
+    ),
+    (
+        EcoCode::new('E', 77),
+        "King's Indian Defense: Four Pawns Attack",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f4 e8g8 f1e2",
+    ),
+    (
+        EcoCode::new('E', 78),
+        "King's Indian Defense: Four Pawns Attack, with Be2 and Nf3",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f4 e8g8 f1e2 c7c5 g1f3",
+    ),
+    (
+        EcoCode::new('E', 79),
+        "King's Indian Defense: Four Pawns Attack, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f4 e8g8 f1e2 c7c5 g1f3 c5d4 f3d4 b8c6 c1e3",
+    ),
+    (
+        EcoCode::new('E', 80),
+        "King's Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3",
+    ),
+    (
+        EcoCode::new('E', 81),
+        "King's Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8",
+    ),
+    (
+        EcoCode::new('E', 82),
+        "King's Indian Defense: Sämisch Variation, Double Fianchetto",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 b7b6",
+    ),
+    (
+        EcoCode::new('E', 83),
+        "King's Indian Defense: Sämisch Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 b8c6",
+    ),
+    (
+        EcoCode::new('E', 84),
+        "King's Indian Defense: Sämisch Variation, Panno Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 b8c6 g1e2 a7a6 d1d2 a8b8",
+    ),
+    (
+        EcoCode::new('E', 85),
+        "King's Indian Defense: Sämisch Variation, Orthodox Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 e7e5",
+    ),
+    (
+        EcoCode::new('E', 86),
+        "King's Indian Defense: Sämisch Variation, Orthodox Variation, 7.Nge2 c6",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 e7e5 g1e2 c7c6",
+    ),
+    (
+        EcoCode::new('E', 87),
+        "King's Indian Defense: Sämisch Variation, Closed Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 e7e5 d4d5",
+    ),
+    (
+        EcoCode::new('E', 88),
+        "King's Indian Defense: Sämisch Variation, Closed Variation, 7...c6",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 e7e5 d4d5 c7c6",
+    ),
+    (
+        EcoCode::new('E', 89),
+        "King's Indian Defense: Sämisch Variation, Closed Variation, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 f2f3 e8g8 c1e3 e7e5 d4d5 c7c6 g1e2 c6d5",
+    ),
+    (
+        EcoCode::new('E', 90),
+        "King's Indian Defense: Normal Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3",
+    ),
+    (
+        EcoCode::new('E', 91),
+        "King's Indian Defense: Normal Variation, 6.Be2",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2",
+    ),
+    (
+        EcoCode::new('E', 92),
+        "King's Indian Defense: Classical Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5",
+    ),
+    (
+        EcoCode::new('E', 93),
+        "King's Indian Defense: Petrosian Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 d4d5 b8d7",
+    ),
+    (
+        EcoCode::new('E', 94),
+        "King's Indian Defense: Orthodox Variation",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1",
+    ),
+    (
+        EcoCode::new('E', 95),
+        "King's Indian Defense: Orthodox Variation, 7...Nbd7 8.Re1",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1 b8d7 f1e1",
+    ),
+    (
+        EcoCode::new('E', 96),
+        "King's Indian Defense: Orthodox Variation, 7...Nbd7, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1 b8d7 f1e1 c7c6 e2f1 a7a5",
+    ),
+    (
+        EcoCode::new('E', 97),
+        "King's Indian Defense: Orthodox Variation, Aronin-Taimanov Defense",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1 b8c6",
+    ),
+    (
+        EcoCode::new('E', 98),
+        "King's Indian Defense: Orthodox Variation, Classical System",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1 b8c6 d4d5 c6e7 f3e1",
+    ),
+    (
+        EcoCode::new('E', 99),
+        "King's Indian Defense: Orthodox Variation, Classical System, Main Line",
+        "d2d4 g8f6 c2c4 g7g6 b1c3 f8g7 e2e4 d7d6 g1f3 e8g8 f1e2 e7e5 e1g1 b8c6 d4d5 c6e7 f3e1 f6d7 f2f3 f7f5",
+    ),
+];
+
+impl Game {
+    /// ### detect_opening
+    ///
+    /// Detect the opening played in the game, finding the longest line in the openings table
+    /// which is a prefix of the move history.
+    /// Returns `None` if no opening matches (e.g. no move has been played yet) or if the game
+    /// didn't start from the standard position (e.g. variants, odds or games started from a FEN)
+    pub fn detect_opening(&self) -> Option<(EcoCode, &'static str)> {
+        if !self.initial_board().same_position(&Board::default()) {
+            return None;
+        }
+        let history: Vec<String> = self.coordinate_moves();
+        OPENINGS
+            .iter()
+            .map(|(eco, name, line)| (*eco, *name, line.split_whitespace().collect::<Vec<&str>>()))
+            .filter(|(_, _, line)| {
+                line.len() <= history.len() && line.iter().zip(history.iter()).all(|(a, b)| a == b)
+            })
+            .fold(
+                None,
+                |best: Option<(EcoCode, &'static str, usize)>, (eco, name, line)| match best {
+                    Some((_, _, len)) if len >= line.len() => best,
+                    _ => Some((eco, name, line.len())),
+                },
+            )
+            .map(|(eco, name, _)| (eco, name))
+    }
+
+    /// ### set_opening_tags
+    ///
+    /// Detect the opening played in the game and write it to the `ECO` and `Opening` metadata tags.
+    /// Returns the detected opening
+    pub fn set_opening_tags(&mut self) -> Option<(EcoCode, &'static str)> {
+        let opening = self.detect_opening();
+        if let Some((eco, name)) = opening {
+            self.metadata.insert_tag("ECO", &eco.to_string());
+            self.metadata.insert_tag("Opening", name);
+        }
+        opening
+    }
+
+    /// ### coordinate_moves
+    ///
+    /// Get played moves in coordinate notation, stopping at resignation
+    fn coordinate_moves(&self) -> Vec<String> {
+        self.moves()
+            .iter()
+            .map_while(|x| {
+                let rank: i32 = match x.player {
+                    Color::White => 1,
+                    Color::Black => 8,
+                };
+                match x.itself {
+                    Move::Piece(from, to) => Some(format!("{}{}", from, to)),
+                    Move::KingSideCastle => Some(format!("e{}g{}", rank, rank)),
+                    Move::QueenSideCastle => Some(format!("e{}c{}", rank, rank)),
+                    Move::Drop(kind, to) => Some(format!("{}@{}", kind.to_ascii_char(), to)),
+                    Move::Resign => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::{GameBuilder, Odds};
+
+    use core::time::Duration;
+    use pretty_assertions::assert_eq;
+
+    /// ### play
+    ///
+    /// Play moves on a new game
+    fn play(moves: &[Move]) -> Game {
+        let mut game: Game = Game::default();
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        game
+    }
+
+    #[test]
+    fn eco_code() {
+        let eco: EcoCode = EcoCode::new('B', 20);
+        assert_eq!(eco.volume(), 'B');
+        assert_eq!(eco.number(), 20);
+        assert_eq!(eco.to_string().as_str(), "B20");
+        assert_eq!(EcoCode::new('C', 0).to_string().as_str(), "C00");
+    }
+
+    #[test]
+    fn detect_opening() {
+        assert_eq!(Game::default().detect_opening(), None);
+        // Sicilian
+        let game: Game = play(&[Move::Piece(E2, E4), Move::Piece(C7, C5)]);
+        assert_eq!(
+            game.detect_opening(),
+            Some((EcoCode::new('B', 20), "Sicilian Defense"))
+        );
+        // Queen's gambit
+        let game: Game = play(&[
+            Move::Piece(D2, D4),
+            Move::Piece(D7, D5),
+            Move::Piece(C2, C4),
+        ]);
+        assert_eq!(
+            game.detect_opening(),
+            Some((EcoCode::new('D', 6), "Queen's Gambit"))
+        );
+        // Longest prefix is kept after leaving the book
+        let game: Game = play(&[
+            Move::Piece(D2, D4),
+            Move::Piece(D7, D5),
+            Move::Piece(C2, C4),
+            Move::Piece(H7, H6),
+        ]);
+        assert_eq!(
+            game.detect_opening(),
+            Some((EcoCode::new('D', 6), "Queen's Gambit"))
+        );
+        // Castling
+        let game: Game = play(&[
+            Move::Piece(E2, E4),
+            Move::Piece(E7, E5),
+            Move::Piece(G1, F3),
+            Move::Piece(B8, C6),
+            Move::Piece(F1, B5),
+            Move::Piece(A7, A6),
+            Move::Piece(B5, A4),
+            Move::Piece(G8, F6),
+            Move::KingSideCastle,
+        ]);
+        assert_eq!(
+            game.detect_opening(),
+            Some((EcoCode::new('C', 78), "Ruy Lopez"))
+        );
+    }
+
+    #[test]
+    fn detect_opening_book() {
+        // Each line is legal and detected as itself
+        for (eco, name, line) in OPENINGS.iter() {
+            let mut game: Game = Game::default();
+            for token in line.split_whitespace() {
+                let (m, _) = game.board().parse_uci_move(token).unwrap();
+                assert!(game.play_move(m, Duration::from_secs(1)).is_ok());
+            }
+            assert_eq!(game.detect_opening(), Some((*eco, *name)));
+        }
+        // Every ECO code has at least a line
+        for volume in ['A', 'B', 'C', 'D', 'E'].iter() {
+            for number in 0..100 {
+                let eco: EcoCode = EcoCode::new(*volume, number);
+                assert!(OPENINGS.iter().any(|(x, _, _)| *x == eco));
+            }
+        }
+    }
+
+    #[test]
+    fn detect_opening_not_standard_position() {
+        // Odds
+        let mut game: Game = GameBuilder::default()
+            .odds(Color::White, Odds::QueenOdds)
+            .build();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(C7, C5), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.detect_opening(), None);
+        // Dunsany
+        let mut game: Game = GameBuilder::dunsany().build();
+        assert!(game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.detect_opening(), None);
+        // Game started from FEN
+        let board: Board =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").unwrap();
+        let mut game: Game = GameBuilder::default().board(board).build();
+        assert!(game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(game.detect_opening(), None);
+        // Standard position from FEN is detected
+        let board: Board =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        let mut game: Game = GameBuilder::default().board(board).build();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(
+            game.detect_opening(),
+            Some((EcoCode::new('B', 0), "King's Pawn Opening"))
+        );
+    }
+
+    #[test]
+    fn set_opening_tags() {
+        let mut game: Game = play(&[Move::Piece(E2, E4), Move::Piece(C7, C6)]);
+        assert_eq!(
+            game.set_opening_tags(),
+            Some((EcoCode::new('B', 10), "Caro-Kann Defense"))
+        );
+        assert_eq!(game.metadata().get_tag("ECO"), Some("B10"));
+        assert_eq!(
+            game.metadata().get_tag("Opening"),
+            Some("Caro-Kann Defense")
+        );
+        // Not detected
+        let mut game: Game = Game::default();
+        assert_eq!(game.set_opening_tags(), None);
+        assert_eq!(game.metadata().get_tag("ECO"), None);
+    }
+}
@@ -2,16 +2,44 @@
 //!
 //! Game options
 
+use crate::Promotion;
+
+use core::time::Duration;
+
+/// The lowest repetition threshold allowed
+pub(crate) const MIN_REPETITION_THRESHOLD: usize = 2;
+
 #[derive(Debug, Clone)]
 pub struct Options {
-    /// If enabled, the game is automatically terminated when the same three turns are played in a row
+    /// If enabled, the game is automatically terminated when the same position occurs for the third time.
     /// If you want to follow the FIDE rules, where the user can claim for it (so it's not automatic), then
     /// you must disable this option and call `Game.is_threefold_repetition()` to check it manually.
     /// Default: true
     pub threefold_repetition: bool,
-    /// If enabled, the game is automatically terminated when the same five turns are played in a row
+    /// If enabled, the game is automatically terminated when the same position occurs for the fifth time,
+    /// whatever the repetition threshold is.
     /// Default: true
     pub fivefold_repetition: bool,
+    /// The amount of times a position must occur to be drawn (or claimed) by repetition.
+    /// Reaching the threshold is reported as a threefold repetition, even if the threshold is not 3.
+    /// Values below 2 are treated as 2, since the current position has always occurred at least once.
+    /// Default: 3
+    pub repetition_threshold: usize,
+    /// If set, pawns reaching the last rank are automatically promoted to the provided piece when the move is played,
+    /// so `Game::play_move` never reports `PROMOTION_AVAILABLE` and `Game::promote` doesn't need to be called.
+    /// Default: None
+    pub auto_promote: Option<Promotion>,
+    /// The maximum depth, in plies of lookahead, searched by `Game::suggest_move`.
+    /// Default: 2
+    pub max_search_depth: usize,
+    /// If set, `Game::suggest_move_within` deepens the search one ply at a time, until the time budget is spent
+    /// or `max_search_depth` is reached.
+    /// Default: None
+    pub search_time: Option<Duration>,
+    /// If disabled, players can't castle, whatever the castling rights of the board are (e.g. for no-castling chess).
+    /// Castles are never returned by `Game::get_legal_moves` and are reported as illegal by `Game::play_move`.
+    /// Default: true
+    pub allow_castling: bool,
 }
 
 impl Default for Options {
@@ -19,6 +47,11 @@ impl Default for Options {
         Self {
             threefold_repetition: true,
             fivefold_repetition: true,
+            repetition_threshold: 3,
+            auto_promote: None,
+            max_search_depth: 2,
+            search_time: None,
+            allow_castling: true,
         }
     }
 }
@@ -39,6 +72,54 @@ impl Options {
         self.fivefold_repetition = enabled;
         self
     }
+
+    /// ### repetition_threshold
+    ///
+    /// Set the amount of times a position must occur to be drawn by repetition.
+    /// Values below 2 are raised to 2
+    pub fn repetition_threshold(mut self, repetitions: usize) -> Self {
+        self.repetition_threshold = repetitions.max(MIN_REPETITION_THRESHOLD);
+        self
+    }
+
+    /// ### auto_promote
+    ///
+    /// Set the piece pawns are automatically promoted to; `None` disables automatic promotion
+    pub fn auto_promote(mut self, promotion: Option<Promotion>) -> Self {
+        self.auto_promote = promotion;
+        self
+    }
+
+    /// ### max_search_depth
+    ///
+    /// Set the maximum depth searched by the engine
+    pub fn max_search_depth(mut self, depth: usize) -> Self {
+        self.max_search_depth = depth;
+        self
+    }
+
+    /// ### search_time
+    ///
+    /// Set the time budget of the engine search; `None` disables iterative deepening
+    pub fn search_time(mut self, time: Option<Duration>) -> Self {
+        self.search_time = time;
+        self
+    }
+
+    /// ### allow_castling
+    ///
+    /// Set whether players can castle
+    pub fn allow_castling(mut self, allowed: bool) -> Self {
+        self.allow_castling = allowed;
+        self
+    }
+
+    /// ### get_repetition_threshold
+    ///
+    /// Get the repetition threshold, raised to 2 if lower (the field is public, so it may have been set directly)
+    pub(crate) fn get_repetition_threshold(&self) -> usize {
+        self.repetition_threshold.max(MIN_REPETITION_THRESHOLD)
+    }
 }
 
 #[cfg(test)]
@@ -46,19 +127,63 @@ mod test {
 
     use super::*;
 
+    use pretty_assertions::assert_eq;
+
     #[test]
     fn options_default() {
         let options: Options = Options::default();
         assert_eq!(options.fivefold_repetition, true);
         assert_eq!(options.threefold_repetition, true);
+        assert_eq!(options.repetition_threshold, 3);
+        assert_eq!(options.auto_promote, None);
+        assert_eq!(options.max_search_depth, 2);
+        assert_eq!(options.search_time, None);
+        assert_eq!(options.allow_castling, true);
     }
 
     #[test]
     fn options_builder() {
         let options: Options = Options::default()
             .fivefold_repetition(false)
-            .threefold_repetition(false);
+            .threefold_repetition(false)
+            .repetition_threshold(4)
+            .auto_promote(Some(Promotion::Queen))
+            .max_search_depth(4)
+            .search_time(Some(Duration::from_secs(5)))
+            .allow_castling(false);
         assert_eq!(options.fivefold_repetition, false);
         assert_eq!(options.threefold_repetition, false);
+        assert_eq!(options.repetition_threshold, 4);
+        assert_eq!(options.auto_promote, Some(Promotion::Queen));
+        assert_eq!(options.max_search_depth, 4);
+        assert_eq!(options.search_time, Some(Duration::from_secs(5)));
+        assert_eq!(options.allow_castling, false);
+    }
+
+    #[test]
+    fn options_repetition_threshold() {
+        assert_eq!(
+            Options::default()
+                .repetition_threshold(0)
+                .repetition_threshold,
+            2
+        );
+        assert_eq!(
+            Options::default()
+                .repetition_threshold(1)
+                .repetition_threshold,
+            2
+        );
+        assert_eq!(
+            Options::default()
+                .repetition_threshold(2)
+                .repetition_threshold,
+            2
+        );
+        let options: Options = Options {
+            repetition_threshold: 0,
+            ..Options::default()
+        };
+        assert_eq!(options.get_repetition_threshold(), 2);
     }
 }
@@ -0,0 +1,440 @@
+//! # PGN
+//!
+//! This module exposes the PGN (portable game notation) exporter for `Game`.
+//! <https://en.wikipedia.org/wiki/Portable_Game_Notation>
+
+use super::metadata::Player;
+use super::{EndGame, Game, GameState, MetadataResult, VictoryReason};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Max length for a movetext line
+const PGN_LINE_LENGTH: usize = 80;
+
+impl Game {
+    /// ### to_pgn
+    ///
+    /// Export the game to PGN.
+    /// The seven tag roster is always written; unknown values are reported as `?`.
+    /// If the game has ended, the `Termination` tag is written too.
+    pub fn to_pgn(&self) -> String {
+        let mut pgn: String = String::new();
+        for (key, value) in self.pgn_tags().iter() {
+            pgn.push_str(&tag_pair(key, value));
+        }
+        // Movetext
+        pgn.push('\n');
+        let mut line_length: usize = 0;
+        for token in self.movetext().split_whitespace() {
+            if line_length > 0 && line_length + token.len() + 1 > PGN_LINE_LENGTH {
+                pgn.push('\n');
+                line_length = 0;
+            } else if line_length > 0 {
+                pgn.push(' ');
+                line_length += 1;
+            }
+            pgn.push_str(token);
+            line_length += token.len();
+        }
+        pgn.push('\n');
+        pgn
+    }
+
+    /// ### pgn_tags
+    ///
+    /// Get the tag pairs exported by `to_pgn`, in order: the seven tag roster, the player tags, `Variant`,
+    /// `Termination` and the custom tags of the metadata.
+    pub fn pgn_tags(&self) -> Vec<(String, String)> {
+        let metadata = self.metadata();
+        // Seven tag roster
+        let mut tags: Vec<(String, String)> = vec![
+            (
+                String::from("Event"),
+                String::from(metadata.event().unwrap_or("?")),
+            ),
+            (
+                String::from("Site"),
+                metadata
+                    .site()
+                    .map(|x| format!("{}, {} {}", x.city(), x.region(), x.country()))
+                    .unwrap_or_else(|| String::from("?")),
+            ),
+            (
+                String::from("Date"),
+                metadata
+                    .date()
+                    .map(|x| format!("{:04}.{:02}.{:02}", x.year(), x.month(), x.day()))
+                    .unwrap_or_else(|| String::from("????.??.??")),
+            ),
+            (
+                String::from("Round"),
+                metadata
+                    .round()
+                    .map(|x| x.to_string())
+                    .unwrap_or_else(|| String::from("?")),
+            ),
+            (String::from("White"), player_name(metadata.white_player())),
+            (String::from("Black"), player_name(metadata.black_player())),
+            (String::from("Result"), String::from(self.result_token())),
+        ];
+        // Other tags
+        if let Some(player) = metadata.white_player() {
+            tags.extend(player_tags("White", player));
+        }
+        if let Some(player) = metadata.black_player() {
+            tags.extend(player_tags("Black", player));
+        }
+        if !self.variant().is_standard() {
+            tags.push((String::from("Variant"), String::from(self.variant().name())));
+        }
+        if let Some(termination) = self.termination_string() {
+            tags.push((String::from("Termination"), String::from(termination)));
+        }
+        // Custom tags; typed tags take precedence
+        for (key, value) in metadata.tags().iter() {
+            if !tags.iter().any(|(k, _)| k == key) {
+                tags.push((key.clone(), value.clone()));
+            }
+        }
+        tags
+    }
+
+    /// ### pgn_tag
+    ///
+    /// Get the value of any PGN tag exported by `to_pgn`, either typed (e.g. `Event`, `WhiteElo`) or custom (e.g. `ECO`).
+    /// Returns `None` if the tag is not exported
+    pub fn pgn_tag(&self, key: &str) -> Option<String> {
+        self.pgn_tags()
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// ### result_token
+    ///
+    /// Get the PGN game termination marker for the result of the game (`1-0`, `0-1`, `1/2-1/2` or `*`)
+    pub fn result_token(&self) -> &'static str {
+        self.metadata().result().token()
+    }
+
+    /// ### termination_string
+    ///
+    /// Get the value for the PGN `Termination` tag, given the reason which ended the game.
+    /// Returns `None` if the game is still in progress
+    pub fn termination_string(&self) -> Option<&'static str> {
+        if self.metadata().result() == MetadataResult::Abandoned {
+            return Some("Abandoned");
+        }
+        match self.game_state() {
+            GameState::Continuing => None,
+            GameState::Ended(EndGame::Victory(_, VictoryReason::Timeout)) => Some("Time forfeit"),
+            GameState::Ended(EndGame::Victory(_, VictoryReason::Forfeit)) => Some("Abandoned"),
+            GameState::Ended(_) => Some("Normal"),
+        }
+    }
+}
+
+/// ### tag_pair
+///
+/// Format a PGN tag pair, escaping the value
+fn tag_pair(key: &str, value: &str) -> String {
+    format!(
+        "[{} \"{}\"]\n",
+        key,
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// ### player_tags
+///
+/// Get player tag pairs (elo, title and FIDE id) for provided side (`White` or `Black`)
+fn player_tags(side: &str, player: &Player) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = vec![(format!("{}Elo", side), player.elo().to_string())];
+    if let Some(title) = player.title() {
+        tags.push((format!("{}Title", side), title.to_string()));
+    }
+    if let Some(fide_id) = player.fide_id() {
+        tags.push((format!("{}FideId", side), fide_id.to_string()));
+    }
+    tags
+}
+
+/// ### player_name
+///
+/// Format player name as "surname, name"
+fn player_name(player: Option<&Player>) -> String {
+    match player {
+        Some(player) => format!("{}, {}", player.surname(), player.name()),
+        None => String::from("?"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::game::metadata::{Country, Metadata, Title};
+    use crate::game::GameBuilder;
+    use crate::position::*;
+    use crate::Move;
+
+    use core::time::Duration;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn to_pgn() {
+        let mut game: Game = GameBuilder::default()
+            .metadata(
+                Metadata::default()
+                    .with_event("Oslo \"Open\"")
+                    .with_site("Oslo", "Oslo", Country::Norway)
+                    .with_date(2021, 6, 15)
+                    .with_round(4)
+                    .with_white_player(Player::new("magnus", "carlsen", Country::Norway, 2882)),
+            )
+            .build();
+        let moves: [Move; 4] = [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+            Move::Piece(D8, H4),
+        ];
+        for m in moves.iter() {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(
+            game.to_pgn().as_str(),
+            r#"[Event "Oslo \"Open\""]
+[Site "Oslo, Oslo NOR"]
+[Date "2021.06.15"]
+[Round "4"]
+[White "carlsen, magnus"]
+[Black "?"]
+[Result "0-1"]
+[WhiteElo "2882"]
+[Termination "Normal"]
+
+1. f3 e5 2. g4 Qh4# 0-1
+"#
+        );
+    }
+
+    #[test]
+    fn to_pgn_in_progress() {
+        let game: Game = Game::default();
+        assert_eq!(
+            game.to_pgn().as_str(),
+            r#"[Event "?"]
+[Site "?"]
+[Date "????.??.??"]
+[Round "?"]
+[White "?"]
+[Black "?"]
+[Result "*"]
+
+*
+"#
+        );
+    }
+
+    #[test]
+    fn to_pgn_player_tags() {
+        let game: Game = GameBuilder::default()
+            .metadata(
+                Metadata::default()
+                    .with_white_player(
+                        Player::new("magnus", "carlsen", Country::Norway, 2882)
+                            .with_title(Title::GrandMaster)
+                            .with_fide_id(1503014),
+                    )
+                    .with_black_player(
+                        Player::new("judit", "polgar", Country::Hungary, 2735)
+                            .with_title(Title::GrandMaster),
+                    ),
+            )
+            .build();
+        let pgn: String = game.to_pgn();
+        assert!(pgn.contains(
+            r#"[White "carlsen, magnus"]
+[Black "polgar, judit"]
+[Result "*"]
+[WhiteElo "2882"]
+[WhiteTitle "GM"]
+[WhiteFideId "1503014"]
+[BlackElo "2735"]
+[BlackTitle "GM"]
+"#
+        ));
+        assert!(!pgn.contains("BlackFideId"));
+    }
+
+    #[test]
+    fn to_pgn_custom_tags() {
+        let game: Game = GameBuilder::default()
+            .metadata(
+                Metadata::default()
+                    .with_event("Casual game")
+                    .with_tag("TimeControl", "300+2")
+                    .and_then(|x| x.with_tag("Event", "Ignored"))
+                    .and_then(|x| x.with_tag("ECO", "C55"))
+                    .and_then(|x| x.with_tag("Termination", "Unterminated"))
+                    .ok()
+                    .unwrap(),
+            )
+            .build();
+        assert_eq!(
+            game.to_pgn().as_str(),
+            r#"[Event "Casual game"]
+[Site "?"]
+[Date "????.??.??"]
+[Round "?"]
+[White "?"]
+[Black "?"]
+[Result "*"]
+[TimeControl "300+2"]
+[ECO "C55"]
+[Termination "Unterminated"]
+
+*
+"#
+        );
+    }
+
+    #[test]
+    fn to_pgn_round() {
+        let game: Game = GameBuilder::default()
+            .metadata(Metadata::default().with_round_str("3.1").ok().unwrap())
+            .build();
+        assert!(game.to_pgn().contains("[Round \"3.1\"]\n"));
+        let game: Game = GameBuilder::default()
+            .metadata(Metadata::default().with_round_str("?").ok().unwrap())
+            .build();
+        assert!(game.to_pgn().contains("[Round \"?\"]\n"));
+    }
+
+    #[test]
+    fn pgn_tag() {
+        let game: Game = GameBuilder::default()
+            .metadata(
+                Metadata::default()
+                    .with_event("Casual game")
+                    .with_white_player(Player::new("magnus", "carlsen", Country::Norway, 2882))
+                    .with_tag("ECO", "C50")
+                    .and_then(|x| x.with_tag("Event", "Ignored"))
+                    .ok()
+                    .unwrap(),
+            )
+            .build();
+        assert_eq!(game.pgn_tag("ECO").as_deref(), Some("C50"));
+        assert_eq!(game.pgn_tag("Event").as_deref(), Some("Casual game"));
+        assert_eq!(game.pgn_tag("WhiteElo").as_deref(), Some("2882"));
+        assert_eq!(game.pgn_tag("Date").as_deref(), Some("????.??.??"));
+        assert_eq!(game.pgn_tag("BlackElo"), None);
+        assert_eq!(game.pgn_tag("Opening"), None);
+        // Tags are exported in the same order
+        let pgn: String = game.to_pgn();
+        for (key, value) in game.pgn_tags().iter() {
+            assert!(pgn.contains(&tag_pair(key, value)));
+        }
+        assert!(pgn.contains("[WhiteElo \"2882\"]\n[ECO \"C50\"]\n"));
+        assert!(!pgn.contains("Ignored"));
+    }
+
+    #[test]
+    fn result_token_and_termination() {
+        // In progress
+        let mut game: Game = Game::default();
+        assert_eq!(game.result_token(), "*");
+        assert_eq!(game.termination_string(), None);
+        // Checkmate
+        for m in [
+            Move::Piece(F2, F3),
+            Move::Piece(E7, E5),
+            Move::Piece(G2, G4),
+            Move::Piece(D8, H4),
+        ]
+        .iter()
+        {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        assert_eq!(game.result_token(), "0-1");
+        assert_eq!(game.termination_string(), Some("Normal"));
+        // Resign
+        let mut game: Game = Game::default();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(1))
+            .is_ok());
+        assert!(game.resign().is_ok());
+        assert_eq!(game.result_token(), "1-0");
+        assert_eq!(game.termination_string(), Some("Normal"));
+        // Timeout
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(60), Duration::from_secs(60))
+            .build();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(61))
+            .is_ok());
+        assert_eq!(game.result_token(), "0-1");
+        assert_eq!(game.termination_string(), Some("Time forfeit"));
+        // Draw
+        let mut game: Game = Game::default();
+        assert!(game.draw().is_ok());
+        assert_eq!(game.result_token(), "1/2-1/2");
+        assert_eq!(game.termination_string(), Some("Normal"));
+        // Abandoned
+        let game: Game = GameBuilder::default()
+            .metadata(Metadata::default().with_result(MetadataResult::Abandoned))
+            .build();
+        assert_eq!(game.result_token(), "*");
+        assert_eq!(game.termination_string(), Some("Abandoned"));
+    }
+
+    #[test]
+    fn to_pgn_variant() {
+        let game: Game = GameBuilder::horde().build();
+        assert!(game
+            .to_pgn()
+            .contains("[Result \"*\"]\n[Variant \"Horde\"]\n"));
+        let game: Game = GameBuilder::default().build();
+        assert!(!game.to_pgn().contains("[Variant "));
+    }
+
+    #[test]
+    fn to_pgn_time_forfeit() {
+        let mut game: Game = GameBuilder::default()
+            .timeout(Duration::from_secs(60), Duration::from_secs(60))
+            .build();
+        assert!(game
+            .play_move(Move::Piece(E2, E4), Duration::from_secs(10))
+            .is_ok());
+        assert!(game
+            .play_move(Move::Piece(E7, E5), Duration::from_secs(61))
+            .is_ok());
+        let pgn: String = game.to_pgn();
+        assert!(pgn.contains("[Result \"1-0\"]\n"));
+        assert!(pgn.contains("[Termination \"Time forfeit\"]\n"));
+        assert!(pgn.ends_with("1. e4 1-0\n"));
+    }
+
+    #[test]
+    fn to_pgn_line_length() {
+        let mut game: Game = GameBuilder::default()
+            .options(crate::game::Options::default().threefold_repetition(false))
+            .build();
+        let moves: [Move; 4] = [
+            Move::Piece(G1, F3),
+            Move::Piece(G8, F6),
+            Move::Piece(F3, G1),
+            Move::Piece(F6, G8),
+        ];
+        for m in moves.iter().cycle().take(16) {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        let pgn: String = game.to_pgn();
+        assert!(pgn.lines().all(|x| x.len() <= PGN_LINE_LENGTH));
+        assert!(pgn.lines().count() > 9);
+    }
+}
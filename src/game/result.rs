@@ -1,6 +1,6 @@
 //! # Result
 //!
-//! Result types for Game.
+//! Result types for Game and the helpers to inspect a `GameResult`.
 
 use crate::{Color, Move};
 
@@ -26,6 +26,8 @@ pub enum GameError {
     IllegalMove(Move),
     /// Promotion is not allowed
     CantPromote,
+    /// The game has already ended, so it can't be played anymore
+    GameEnded,
 }
 
 /// ## GameState
@@ -47,20 +49,10 @@ pub enum EndGame {
     /// One player, the victor, checkmated the other.
     /// This stores the color of the winner and the reason
     Victory(Color, VictoryReason),
-    /// The game is draw. There are 3 conditions where this can happen:
-    ///
-    /// 1. The current player has no legal moves and not being in check
-    /// 2. both players have insufficient material on the board.
-    ///     Insufficient material consists of:
-    ///
-    ///     1. The player only has a king
-    ///     2. The player only has a king and a knight
-    ///     3. The player only has a king and two knights
-    ///     4. The player only has a king and a bishop
-    ///     5. The player only has a king and two bishops
-    ///
-    /// 3. Threefold repetition. The same moves are played for 3 turns
-    Draw,
+    /// The game is draw. This stores the reason which caused the draw
+    Draw(DrawReason),
+    /// The game has been abandoned and no player has been awarded the victory
+    Abandoned,
 }
 
 /// ## VictoryReason
@@ -71,6 +63,49 @@ pub enum VictoryReason {
     Checkmate,
     Resign,
     Timeout,
+    /// All the pieces of the opponent have been captured (Horde)
+    AllPiecesCaptured,
+    /// The opponent abandoned the game and the victory has been awarded by forfeit
+    Forfeit,
+    /// The player has given check three times (Three-check)
+    ThreeChecks,
+    /// The reason is not known (e.g. the result of a restored game has been set through the metadata)
+    Unknown,
+}
+
+/// ## DrawReason
+///
+/// Describes the reason that brought the game to a draw
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrawReason {
+    /// The current player has no legal moves and is not in check
+    Stalemate,
+    /// Both players have insufficient material on the board.
+    /// Insufficient material consists of:
+    ///
+    /// 1. The player only has a king
+    /// 2. The player only has a king and a knight
+    /// 3. The player only has a king and two knights
+    /// 4. The player only has a king and a bishop
+    /// 5. The player only has a king and two bishops
+    InsufficientMaterial,
+    /// The same position has occurred 3 times
+    ThreefoldRepetition,
+    /// The same position has occurred 5 times
+    FivefoldRepetition,
+    /// Players agreed to draw the game
+    Agreement,
+}
+
+/// ## DrawClaim
+///
+/// Describes the reason a player can claim a draw for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrawClaim {
+    /// The same position has occurred 3 times
+    ThreefoldRepetition,
+    /// No pawn has been moved and no piece has been taken in the last 50 turns
+    FiftyMoveRule,
 }
 
 // -- event
@@ -80,19 +115,29 @@ bitflags! {
     ///
     /// Describes an event "raised" after a move is played.
     /// An event is an intersection of different values
-    pub struct GameEvent: u8 {
+    pub struct GameEvent: u16 {
         /// No event reported
-        const NONE                  = 0b00000000;
+        const NONE                  = 0b00000000000;
         /// Opponent king is now in check
-        const CHECK                 = 0b00000010;
+        const CHECK                 = 0b00000000010;
         /// Opponent king is in checkmate
-        const CHECKMATE             = 0b00000100;
+        const CHECKMATE             = 0b00000000100;
         /// A promotion is available for one of current player pawn
-        const PROMOTION_AVAILABLE   = 0b00001000;
+        const PROMOTION_AVAILABLE   = 0b00000001000;
         /// Threefold repetition detected; it is reported even if not enabled in options
-        const THREEFOLD_REPETITION  = 0b00010000;
+        const THREEFOLD_REPETITION  = 0b00000010000;
         /// Fivefold repetition detected; it is reported only if enabled in options
-        const FIVEFOLD_REPETITION   = 0b00100000;
+        const FIVEFOLD_REPETITION   = 0b00000100000;
+        /// A piece has been taken from the opponent
+        const CAPTURE               = 0b00001000000;
+        /// An en passant capture has been performed
+        const EN_PASSANT            = 0b00010000000;
+        /// The game has been drawn by stalemate
+        const STALEMATE             = 0b00100000000;
+        /// Fifty moves have been played without moving pawns or taking pieces; a draw can be claimed
+        const FIFTY_MOVE            = 0b01000000000;
+        /// A draw has been offered by the player who has just moved
+        const DRAW_OFFERED          = 0b10000000000;
     }
 }
 
@@ -131,6 +176,41 @@ impl GameEvent {
     pub fn is_fivefold_repetition(&self) -> bool {
         self.intersects(GameEvent::FIVEFOLD_REPETITION)
     }
+
+    /// ### is_capture
+    ///
+    /// Returns whether a piece has been taken
+    pub fn is_capture(&self) -> bool {
+        self.intersects(GameEvent::CAPTURE)
+    }
+
+    /// ### is_en_passant
+    ///
+    /// Returns whether an en passant capture has been performed
+    pub fn is_en_passant(&self) -> bool {
+        self.intersects(GameEvent::EN_PASSANT)
+    }
+
+    /// ### is_stalemate
+    ///
+    /// Returns whether the game has been drawn by stalemate
+    pub fn is_stalemate(&self) -> bool {
+        self.intersects(GameEvent::STALEMATE)
+    }
+
+    /// ### is_fifty_move
+    ///
+    /// Returns whether the fifty-move rule has been reached
+    pub fn is_fifty_move(&self) -> bool {
+        self.intersects(GameEvent::FIFTY_MOVE)
+    }
+
+    /// ### is_draw_offered
+    ///
+    /// Returns whether a draw has been offered
+    pub fn is_draw_offered(&self) -> bool {
+        self.intersects(GameEvent::DRAW_OFFERED)
+    }
 }
 
 // -- fmt
@@ -142,6 +222,7 @@ impl fmt::Display for GameError {
                 write!(f, "Can't promote pawn, since there's no pawn to promote")
             }
             GameError::IllegalMove(m) => write!(f, "Illegal move: {}", m),
+            GameError::GameEnded => write!(f, "The game has already ended"),
         }
     }
 }
@@ -155,10 +236,34 @@ pub fn was_illegal_move(res: &GameResult) -> bool {
     matches!(res, Err(GameError::IllegalMove(_)))
 }
 
+/// ### is_ended
+///
+/// Returns whether the game has ended after the action
+pub fn is_ended(res: &GameResult) -> bool {
+    matches!(res, Ok((GameState::Ended(_), _)))
+}
+
+/// ### winner
+///
+/// Returns the winner of the game, if the action ended the game with a victory
+pub fn winner(res: &GameResult) -> Option<Color> {
+    match res {
+        Ok((GameState::Ended(EndGame::Victory(color, _)), _)) => Some(*color),
+        _ => None,
+    }
+}
+
+/// ### is_draw
+///
+/// Returns whether the action ended the game with a draw
+pub fn is_draw(res: &GameResult) -> bool {
+    matches!(res, Ok((GameState::Ended(EndGame::Draw(_)), _)))
+}
+
 /// ### set_result_event
 ///
 /// Set `GameEvent` to `GameResult`
-pub fn set_result_event(res: GameResult, ev: GameEvent) -> GameResult {
+pub(crate) fn set_result_event(res: GameResult, ev: GameEvent) -> GameResult {
     match res {
         Ok((state, mut event)) => {
             event.insert(ev);
@@ -168,11 +273,22 @@ pub fn set_result_event(res: GameResult, ev: GameEvent) -> GameResult {
     }
 }
 
+/// ### set_result_state
+///
+/// Set `GameState` to `GameResult`
+pub(crate) fn set_result_state(res: GameResult, state: GameState) -> GameResult {
+    match res {
+        Ok((_, event)) => Ok((state, event)),
+        Err(err) => Err(err),
+    }
+}
+
 #[cfg(test)]
 mod test {
 
     use super::*;
     use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn fmt_game_error() {
@@ -200,6 +316,62 @@ mod test {
         );
     }
 
+    #[test]
+    fn result_helpers() {
+        // Checkmate
+        let res: GameResult = Ok((
+            GameState::Ended(EndGame::Victory(Color::Black, VictoryReason::Checkmate)),
+            GameEvent::CHECKMATE,
+        ));
+        assert_eq!(super::is_ended(&res), true);
+        assert_eq!(super::winner(&res), Some(Color::Black));
+        assert_eq!(super::is_draw(&res), false);
+        // Draw
+        let res: GameResult = Ok((
+            GameState::Ended(EndGame::Draw(DrawReason::Stalemate)),
+            GameEvent::NONE,
+        ));
+        assert_eq!(super::is_ended(&res), true);
+        assert_eq!(super::winner(&res), None);
+        assert_eq!(super::is_draw(&res), true);
+        // Continuing
+        let res: GameResult = Ok((GameState::Continuing, GameEvent::CHECK));
+        assert_eq!(super::is_ended(&res), false);
+        assert_eq!(super::winner(&res), None);
+        assert_eq!(super::is_draw(&res), false);
+        // Abandoned
+        let res: GameResult = Ok((GameState::Ended(EndGame::Abandoned), GameEvent::NONE));
+        assert_eq!(super::is_ended(&res), true);
+        assert_eq!(super::winner(&res), None);
+        assert_eq!(super::is_draw(&res), false);
+        // Error
+        let res: GameResult = Err(GameError::CantPromote);
+        assert_eq!(super::is_ended(&res), false);
+        assert_eq!(super::winner(&res), None);
+        assert_eq!(super::is_draw(&res), false);
+    }
+
+    #[test]
+    fn set_result_state() {
+        assert_eq!(
+            super::set_result_state(
+                GameResult::Ok((GameState::Continuing, GameEvent::THREEFOLD_REPETITION)),
+                GameState::Ended(EndGame::Draw(DrawReason::ThreefoldRepetition))
+            ),
+            GameResult::Ok((
+                GameState::Ended(EndGame::Draw(DrawReason::ThreefoldRepetition)),
+                GameEvent::THREEFOLD_REPETITION
+            ))
+        );
+        assert_eq!(
+            super::set_result_state(
+                GameResult::Err(GameError::CantPromote),
+                GameState::Continuing
+            ),
+            GameResult::Err(GameError::CantPromote)
+        );
+    }
+
     #[test]
     fn set_result_event() {
         assert_eq!(
@@ -248,5 +420,15 @@ mod test {
             true
         );
         assert_eq!(GameEvent::NONE.is_check(), false);
+        assert_eq!(GameEvent::CAPTURE.is_capture(), true);
+        assert_eq!(GameEvent::NONE.is_capture(), false);
+        assert_eq!(GameEvent::EN_PASSANT.is_en_passant(), true);
+        assert_eq!(GameEvent::NONE.is_en_passant(), false);
+        assert_eq!(GameEvent::STALEMATE.is_stalemate(), true);
+        assert_eq!(GameEvent::NONE.is_stalemate(), false);
+        assert_eq!(GameEvent::FIFTY_MOVE.is_fifty_move(), true);
+        assert_eq!(GameEvent::NONE.is_fifty_move(), false);
+        assert_eq!(GameEvent::DRAW_OFFERED.is_draw_offered(), true);
+        assert_eq!(GameEvent::NONE.is_draw_offered(), false);
     }
 }
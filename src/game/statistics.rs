@@ -0,0 +1,175 @@
+//! # Statistics
+//!
+//! This module exposes the statistics summary for `Game`
+
+use super::{Game, GameMove};
+use crate::Color;
+
+use core::time::Duration;
+
+/// ## GameStatistics
+///
+/// A summary of the statistics of a game, computed from the move history
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct GameStatistics {
+    /// Statistics of the white player
+    pub white: PlayerStatistics,
+    /// Statistics of the black player
+    pub black: PlayerStatistics,
+    /// Amount of half-moves played
+    pub plies: usize,
+}
+
+/// ## PlayerStatistics
+///
+/// Statistics of a single player
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PlayerStatistics {
+    /// Amount of moves played
+    pub moves: usize,
+    /// Total time spent thinking
+    pub think_time: Duration,
+    /// Average time spent thinking on a move
+    pub average_think_time: Duration,
+    /// Longest time spent thinking on a move
+    pub longest_think: Duration,
+    /// Amount of pieces captured
+    pub captures: usize,
+    /// Amount of checks given
+    pub checks: usize,
+    /// Amount of castles (either 0 or 1)
+    pub castles: usize,
+    /// Amount of pawn promotions
+    pub promotions: usize,
+}
+
+impl Game {
+    /// ### statistics
+    ///
+    /// Compute the statistics of the game from the move history
+    pub fn statistics(&self) -> GameStatistics {
+        let mut stats: GameStatistics = GameStatistics {
+            plies: self.moves().len(),
+            ..GameStatistics::default()
+        };
+        for m in self.moves().iter() {
+            match m.player {
+                Color::White => stats.white.add_move(m),
+                Color::Black => stats.black.add_move(m),
+            }
+        }
+        stats.white.compute_average();
+        stats.black.compute_average();
+        stats
+    }
+}
+
+impl PlayerStatistics {
+    /// ### add_move
+    ///
+    /// Update statistics with a move played by the player
+    fn add_move(&mut self, m: &GameMove) {
+        self.moves += 1;
+        self.think_time += m.time;
+        if m.time > self.longest_think {
+            self.longest_think = m.time;
+        }
+        if m.piece_taken.is_some() {
+            self.captures += 1;
+        }
+        if m.is_check() {
+            self.checks += 1;
+        }
+        if m.is_castle() {
+            self.castles += 1;
+        }
+        if m.promotion.is_some() {
+            self.promotions += 1;
+        }
+    }
+
+    /// ### compute_average
+    ///
+    /// Compute average think time from total think time and moves
+    fn compute_average(&mut self) {
+        if self.moves > 0 {
+            self.average_think_time = self.think_time / self.moves as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+    use crate::Move;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn statistics() {
+        let mut game: Game = Game::default();
+        assert_eq!(game.statistics(), GameStatistics::default());
+        // Max Lange attack
+        let moves: [Move; 20] = [
+            Move::Piece(E2, E4),
+            Move::Piece(E7, E5),
+            Move::Piece(G1, F3),
+            Move::Piece(B8, C6),
+            Move::Piece(F1, C4),
+            Move::Piece(F8, C5),
+            Move::KingSideCastle,
+            Move::Piece(G8, F6),
+            Move::Piece(D2, D4),
+            Move::Piece(E5, D4),
+            Move::Piece(E4, E5),
+            Move::Piece(D7, D5),
+            Move::Piece(E5, F6),
+            Move::Piece(D5, C4),
+            Move::Piece(F1, E1),
+            Move::Piece(C8, E6),
+            Move::Piece(F3, G5),
+            Move::Piece(D8, D5),
+            Move::Piece(B1, C3),
+            Move::Piece(D5, F5),
+        ];
+        for (i, m) in moves.iter().enumerate() {
+            // White thinks (turn) seconds, black thinks (turn * 2) seconds
+            let turn: u64 = (i / 2) as u64 + 1;
+            let time: Duration = match i % 2 {
+                0 => Duration::from_secs(turn),
+                _ => Duration::from_secs(turn * 2),
+            };
+            assert!(game.play_move(*m, time).is_ok());
+        }
+        let stats: GameStatistics = game.statistics();
+        assert_eq!(stats.plies, 20);
+        assert_eq!(
+            stats.white,
+            PlayerStatistics {
+                moves: 10,
+                think_time: Duration::from_secs(55),
+                average_think_time: Duration::from_millis(5500),
+                longest_think: Duration::from_secs(10),
+                captures: 1,
+                checks: 1,
+                castles: 1,
+                promotions: 0,
+            }
+        );
+        assert_eq!(
+            stats.black,
+            PlayerStatistics {
+                moves: 10,
+                think_time: Duration::from_secs(110),
+                average_think_time: Duration::from_secs(11),
+                longest_think: Duration::from_secs(20),
+                captures: 2,
+                checks: 0,
+                castles: 0,
+                promotions: 0,
+            }
+        );
+    }
+}
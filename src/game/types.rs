@@ -2,7 +2,9 @@
 //!
 //! This module exposes different kind of types for `Game`
 
-use crate::{Color, Move, Piece, Promotion};
+use crate::{Board, Color, Move, MoveParseError, Piece, Promotion};
+
+use alloc::string::String;
 
 use core::time::Duration;
 
@@ -27,6 +29,68 @@ pub struct GameMove {
     pub piece_taken: Option<Piece>,
     /// the eventual pawn promotion performed on that turn
     pub promotion: Option<Promotion>,
+    /// the characteristics of the move (check, checkmate, castle, en passant)
+    pub flags: MoveFlags,
+}
+
+bitflags! {
+    /// ## MoveFlags
+    ///
+    /// Describes the characteristics of a played move.
+    /// Flags are an intersection of different values
+    pub struct MoveFlags: u8 {
+        /// No flag
+        const NONE          = 0b00000000;
+        /// The move gave check to the opponent king
+        const CHECK         = 0b00000001;
+        /// The move delivered checkmate
+        const CHECKMATE     = 0b00000010;
+        /// The move is a castle
+        const CASTLE        = 0b00000100;
+        /// The move is an en passant capture
+        const EN_PASSANT    = 0b00001000;
+    }
+}
+
+impl MoveFlags {
+    /// ### for_move
+    ///
+    /// Get the flags of move `m`, given the board before (`board`) and after (`next`) the move was played.
+    /// Check flags are set only if there's no pending promotion on `next`, since check is known only after promotion
+    pub fn for_move(board: &Board, next: &Board, m: Move) -> Self {
+        let mut flags: MoveFlags = MoveFlags::NONE;
+        match m {
+            Move::KingSideCastle | Move::QueenSideCastle => flags.insert(MoveFlags::CASTLE),
+            Move::Piece(from, to) => {
+                // Pawn moving diagonally to an empty square
+                if board.get_piece(from).map(|x| x.is_pawn()) == Some(true)
+                    && from.get_col() != to.get_col()
+                    && board.has_no_piece(to)
+                {
+                    flags.insert(MoveFlags::EN_PASSANT);
+                }
+            }
+            Move::Drop(_, _) => {}
+            Move::Resign => return flags,
+        }
+        if next.get_promoting_pawn().is_none() {
+            flags.insert(Self::for_check(next));
+        }
+        flags
+    }
+
+    /// ### for_check
+    ///
+    /// Get check and checkmate flags for the player to move on `board`
+    pub fn for_check(board: &Board) -> Self {
+        if board.is_checkmate() {
+            MoveFlags::CHECK | MoveFlags::CHECKMATE
+        } else if board.is_check() {
+            MoveFlags::CHECK
+        } else {
+            MoveFlags::NONE
+        }
+    }
 }
 
 impl GameMove {
@@ -48,14 +112,87 @@ impl GameMove {
             time,
             piece_taken,
             promotion,
+            flags: MoveFlags::NONE,
         }
     }
+
+    /// ### from_san
+    ///
+    /// Parse a move in SAN (see `Board::parse_san`) played by the player to move on `board`, which is the board
+    /// before the move, at the provided turn number and taking `time`.
+    /// The piece taken and the flags are computed playing the move on the board
+    pub fn from_san(
+        board: &Board,
+        san: &str,
+        turn: u16,
+        time: Duration,
+    ) -> Result<Self, MoveParseError> {
+        let (m, promotion): (Move, Option<Promotion>) = board.parse_san(san)?;
+        let next: Board = board.replay_move(m, promotion);
+        let flags: MoveFlags = MoveFlags::for_move(board, &next, m);
+        Ok(Self::new(
+            m,
+            board.get_turn(),
+            turn,
+            time,
+            next.get_taken_piece(),
+            promotion,
+        )
+        .with_flags(flags))
+    }
+
+    /// ### to_san
+    ///
+    /// Get the move in SAN (e.g. `Nbd7`, `exd5`, `e8=Q#`), given the board before the move was played
+    pub fn to_san(&self, board: &Board) -> String {
+        board.to_san(self.itself, self.promotion)
+    }
+
+    /// ### with_flags
+    ///
+    /// Set flags for move
+    pub fn with_flags(mut self, flags: MoveFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// ### is_check
+    ///
+    /// Returns whether the move gave check
+    pub fn is_check(&self) -> bool {
+        self.flags.intersects(MoveFlags::CHECK)
+    }
+
+    /// ### is_checkmate
+    ///
+    /// Returns whether the move delivered checkmate
+    pub fn is_checkmate(&self) -> bool {
+        self.flags.intersects(MoveFlags::CHECKMATE)
+    }
+
+    /// ### is_castle
+    ///
+    /// Returns whether the move is a castle
+    pub fn is_castle(&self) -> bool {
+        self.flags.intersects(MoveFlags::CASTLE)
+    }
+
+    /// ### is_en_passant
+    ///
+    /// Returns whether the move is an en passant capture
+    pub fn is_en_passant(&self) -> bool {
+        self.flags.intersects(MoveFlags::EN_PASSANT)
+    }
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
+    use crate::position::*;
+    use crate::{Game, MoveResult};
+
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn game_move() {
@@ -66,6 +203,7 @@ mod test {
             time: Duration::from_secs(5),
             piece_taken: None,
             promotion: None,
+            flags: MoveFlags::NONE,
         };
         assert_eq!(m.itself, Move::Resign);
         assert_eq!(m.turn, 2);
@@ -87,5 +225,102 @@ mod test {
         assert_eq!(m.time, Duration::from_secs(5));
         assert_eq!(m.piece_taken, None);
         assert_eq!(m.promotion, None);
+        assert_eq!(m.flags, MoveFlags::NONE);
+    }
+
+    #[test]
+    fn game_move_flags() {
+        let m: GameMove = GameMove::new(
+            Move::KingSideCastle,
+            Color::White,
+            2,
+            Duration::from_secs(5),
+            None,
+            None,
+        )
+        .with_flags(MoveFlags::CASTLE | MoveFlags::CHECK);
+        assert_eq!(m.is_castle(), true);
+        assert_eq!(m.is_check(), true);
+        assert_eq!(m.is_checkmate(), false);
+        assert_eq!(m.is_en_passant(), false);
+    }
+
+    #[test]
+    fn move_flags_for_move() {
+        let board: Board = Board::default();
+        let next: Board = board.replay_move(Move::Piece(E2, E4), None);
+        assert_eq!(
+            MoveFlags::for_move(&board, &next, Move::Piece(E2, E4)),
+            MoveFlags::NONE
+        );
+        // En passant
+        let board: Board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let next: Board = board.replay_move(Move::Piece(E5, D6), None);
+        assert_eq!(
+            MoveFlags::for_move(&board, &next, Move::Piece(E5, D6)),
+            MoveFlags::EN_PASSANT
+        );
+        // Castle with check
+        let board: Board = Board::from_fen("5k2/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+        let next: Board = board.replay_move(Move::KingSideCastle, None);
+        assert_eq!(
+            MoveFlags::for_move(&board, &next, Move::KingSideCastle),
+            MoveFlags::CASTLE | MoveFlags::CHECK
+        );
+        // Checkmate
+        let board: Board = Board::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1").unwrap();
+        let next: Board = board.replay_move(Move::Piece(H1, H8), None);
+        assert_eq!(
+            MoveFlags::for_move(&board, &next, Move::Piece(H1, H8)),
+            MoveFlags::CHECK | MoveFlags::CHECKMATE
+        );
+        // Check is unknown while promotion is pending
+        let board: Board = Board::from_fen("k7/4P3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let next: Board = match board.play_move(Move::Piece(E7, E8)) {
+            MoveResult::Promote(next, _) => next,
+            _ => panic!("promotion expected"),
+        };
+        assert_eq!(
+            MoveFlags::for_move(&board, &next, Move::Piece(E7, E8)),
+            MoveFlags::NONE
+        );
+        assert_eq!(
+            MoveFlags::for_move(&board, &board, Move::Resign),
+            MoveFlags::NONE
+        );
+    }
+
+    #[test]
+    fn game_move_san() {
+        let mut game: Game = Game::default();
+        for m in [
+            Move::Piece(E2, E4),
+            Move::Piece(D7, D5),
+            Move::Piece(E4, D5),
+            Move::Piece(E7, E5),
+            Move::Piece(D5, E6),
+        ]
+        .iter()
+        {
+            assert!(game.play_move(*m, Duration::from_secs(1)).is_ok());
+        }
+        // Capture
+        let board: Board = game.board_at(2).unwrap();
+        assert_eq!(game.moves()[2].to_san(&board).as_str(), "exd5");
+        assert_eq!(
+            GameMove::from_san(&board, "exd5", 2, Duration::from_secs(1)),
+            Ok(game.moves()[2])
+        );
+        // En passant
+        let board: Board = game.board_at(4).unwrap();
+        assert_eq!(game.moves()[4].to_san(&board).as_str(), "dxe6");
+        assert_eq!(
+            GameMove::from_san(&board, "dxe6", 3, Duration::from_secs(1)),
+            Ok(game.moves()[4])
+        );
+        assert_eq!(
+            GameMove::from_san(&board, "Qxd8", 3, Duration::from_secs(1)),
+            Err(MoveParseError::IllegalMove)
+        );
     }
 }
@@ -0,0 +1,108 @@
+//! # UCI
+//!
+//! This module exposes the constructor of `Game` from a list of moves in UCI notation
+
+use super::{Game, GameEvent};
+use crate::board::UciError;
+
+use core::time::Duration;
+
+impl Game {
+    /// ### from_uci_moves
+    ///
+    /// Create a new game from the starting position, playing the provided moves in UCI notation
+    /// (e.g. `e2e4`, `e1g1` or `e7e8q`), as in the `position startpos moves ...` command.
+    /// No time is charged to the players for the moves
+    pub fn from_uci_moves(moves: &[&str]) -> Result<Game, UciError> {
+        let mut game: Game = Game::default();
+        for (i, token) in moves.iter().enumerate() {
+            let (m, promotion) = game
+                .board()
+                .parse_uci_move(token)
+                .ok_or(UciError::BadMove(i))?;
+            if game.has_terminated() {
+                return Err(UciError::IllegalMove(i));
+            }
+            let (_, event) = game
+                .play_move(m, Duration::ZERO)
+                .map_err(|_| UciError::IllegalMove(i))?;
+            match (event.contains(GameEvent::PROMOTION_AVAILABLE), promotion) {
+                (true, Some(promotion)) => {
+                    game.promote(promotion, Duration::ZERO)
+                        .map_err(|_| UciError::IllegalMove(i))?;
+                }
+                (false, None) => {}
+                _ => return Err(UciError::IllegalMove(i)),
+            }
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::game::metadata::Result as MetadataResult;
+    use crate::position::*;
+    use crate::{Color, Move, Piece, Promotion};
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_uci_moves() {
+        let game: Game =
+            Game::from_uci_moves(&["e2e4", "e7e5", "g1f3", "b8c6", "f1c4", "g8f6", "e1g1"])
+                .ok()
+                .unwrap();
+        assert_eq!(
+            game.board().to_fen().as_str(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 0 1"
+        );
+        assert_eq!(game.moves().len(), 7);
+        assert_eq!(game.moves()[6].itself, Move::KingSideCastle);
+        // Checkmate
+        let game: Game = Game::from_uci_moves(&["f2f3", "e7e5", "g2g4", "d8h4"])
+            .ok()
+            .unwrap();
+        assert_eq!(game.metadata().result(), MetadataResult::BlackWins);
+        assert_eq!(
+            Game::from_uci_moves(&["f2f3", "e7e5", "g2g4", "d8h4", "a2a3"])
+                .err()
+                .unwrap(),
+            UciError::IllegalMove(4)
+        );
+    }
+
+    #[test]
+    fn from_uci_moves_promotion() {
+        let game: Game = Game::from_uci_moves(&[
+            "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "f8g7", "h6g7", "g8f6", "g7h8q",
+        ])
+        .ok()
+        .unwrap();
+        assert_eq!(
+            game.board().get_piece(H8),
+            Some(Piece::Queen(Color::White, H8))
+        );
+        assert_eq!(game.moves()[8].promotion, Some(Promotion::Queen));
+        // Missing promotion
+        assert_eq!(
+            Game::from_uci_moves(&[
+                "h2h4", "g7g5", "h4g5", "h7h6", "g5h6", "f8g7", "h6g7", "g8f6", "g7h8"
+            ])
+            .err()
+            .unwrap(),
+            UciError::IllegalMove(8)
+        );
+        // Bad moves
+        assert_eq!(
+            Game::from_uci_moves(&["e2e4", "e5"]).err().unwrap(),
+            UciError::BadMove(1)
+        );
+        assert_eq!(
+            Game::from_uci_moves(&["e2e5"]).err().unwrap(),
+            UciError::IllegalMove(0)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! # Variant
+//!
+//! This module exposes the chess variant played by a `Game`
+
+use crate::Rules;
+
+/// ## Variant
+///
+/// The chess variant played in the game
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Variant {
+    /// Standard chess
+    #[default]
+    Standard,
+    /// Horde chess <https://en.wikipedia.org/wiki/Dunsany%27s_chess#Horde_chess>
+    Horde,
+    /// Dunsany's chess <https://en.wikipedia.org/wiki/Dunsany%27s_chess>
+    Dunsany,
+    /// Three-check chess: the player who gives check three times wins <https://en.wikipedia.org/wiki/Three-check_chess>
+    ThreeCheck,
+    /// Crazyhouse: captured pieces can be dropped back on the board <https://en.wikipedia.org/wiki/Crazyhouse>
+    Crazyhouse,
+    /// Fischer random chess, with the number of the starting position (0-959)
+    Chess960 { sp: u16 },
+}
+
+impl Variant {
+    /// ### name
+    ///
+    /// Get the name of the variant, as used in the PGN `Variant` tag
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Horde => "Horde",
+            Self::Dunsany => "Dunsany",
+            Self::ThreeCheck => "Three-check",
+            Self::Crazyhouse => "Crazyhouse",
+            Self::Chess960 { .. } => "Chess960",
+        }
+    }
+
+    /// ### rules
+    ///
+    /// Get the rules used on the board by the variant
+    pub fn rules(&self) -> Rules {
+        match self {
+            Self::Horde => Rules::Horde,
+            Self::ThreeCheck => Rules::ThreeCheck,
+            Self::Crazyhouse => Rules::Crazyhouse,
+            Self::Standard | Self::Dunsany | Self::Chess960 { .. } => Rules::Standard,
+        }
+    }
+
+    /// ### is_standard
+    ///
+    /// Returns whether the variant is standard chess
+    pub fn is_standard(&self) -> bool {
+        *self == Self::Standard
+    }
+}
+
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn variant() {
+        assert_eq!(Variant::default(), Variant::Standard);
+        assert_eq!(Variant::Standard.is_standard(), true);
+        assert_eq!(Variant::Horde.is_standard(), false);
+        assert_eq!(Variant::Standard.to_string().as_str(), "Standard");
+        assert_eq!(Variant::Horde.to_string().as_str(), "Horde");
+        assert_eq!(Variant::Dunsany.to_string().as_str(), "Dunsany");
+        assert_eq!(Variant::ThreeCheck.to_string().as_str(), "Three-check");
+        assert_eq!(Variant::Crazyhouse.to_string().as_str(), "Crazyhouse");
+        assert_eq!(Variant::Standard.rules(), Rules::Standard);
+        assert_eq!(Variant::Horde.rules(), Rules::Horde);
+        assert_eq!(Variant::Dunsany.rules(), Rules::Standard);
+        assert_eq!(Variant::ThreeCheck.rules(), Rules::ThreeCheck);
+        assert_eq!(Variant::Crazyhouse.rules(), Rules::Crazyhouse);
+        assert_eq!(Variant::Chess960 { sp: 518 }.rules(), Rules::Standard);
+        assert_eq!(
+            Variant::Chess960 { sp: 518 }.to_string().as_str(),
+            "Chess960"
+        );
+    }
+}
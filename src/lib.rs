@@ -50,6 +50,7 @@
 //!         Move::Piece(from, to) => println!("move {} to {}", from, to),
 //!         Move::KingSideCastle => println!("castle kingside"),
 //!         Move::QueenSideCastle => println!("castle queenside"),
+//!         Move::Drop(kind, to) => println!("drop {:?} on {}", kind, to),
 //!         Move::Resign => println!("resign")
 //!     }
 //!     match board.play_move(best_move) {
@@ -122,24 +123,42 @@
     html_logo_url = "https://raw.githubusercontent.com/veeso/harmon/main/assets/cargo/harmon-512.png"
 )]
 #![no_std]
+// NOTE: lints raised by newer clippy releases on code written before they existed
+#![allow(
+    clippy::doc_overindented_list_items,
+    clippy::field_reassign_with_default,
+    clippy::manual_is_multiple_of,
+    clippy::map_flatten,
+    clippy::needless_late_init,
+    clippy::partialeq_to_none,
+    clippy::zero_prefixed_literal
+)]
 #[macro_use]
 extern crate alloc;
 #[macro_use]
 extern crate bitflags;
 
-#[cfg(test)] // NOTE: Enable std for test units
+#[cfg(any(test, feature = "std"))] // NOTE: Enable std for test units and the `std` feature
 extern crate std;
 
 // -- modules
 
 mod board;
-pub use board::{Board, BoardBuilder, MoveResult, Promotion};
+pub use board::{
+    Board, BoardBuilder, BoardDisplay, CastlingRights, CrazyhouseRules, FenCastling, FenError,
+    GameStatus, HordeRules, IllegalReason, MoveResult, Odds, PositionError, PositionKey, Promotion,
+    RenderError, RenderOptions, RepetitionTracker, Reserve, Rules, StandardRules, ThreeCheckRules,
+    UciError, VariantRules,
+};
+
+mod fmt;
+pub use fmt::MoveParseError;
 
 pub mod game;
 pub use game::{Game, GameBuilder, GameError, GameMove, GameResult, GameState};
 
 mod piece;
-pub use piece::Piece;
+pub use piece::{Piece, PieceKind};
 
 mod position;
 pub use position::*;
@@ -147,13 +166,15 @@ pub use position::*;
 mod square;
 pub use square::Square;
 
+mod tables;
+
 pub const WHITE: Color = Color::White;
 pub const BLACK: Color = Color::Black;
 
 /// ## Color
 ///
 /// The color of a piece.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Color {
     White,
     Black,
@@ -172,15 +193,34 @@ impl core::fmt::Display for Color {
     }
 }
 
+impl Color {
+    /// ### opponent
+    ///
+    /// Get the color of the opponent: `Color::White` becomes `Color::Black` and vice versa.
+    /// This is the same as `!color`, but it can be used in `const` contexts
+    #[inline]
+    pub const fn opponent(self) -> Self {
+        match self {
+            Self::White => Self::Black,
+            Self::Black => Self::White,
+        }
+    }
+
+    /// ### both
+    ///
+    /// Get both colors, white first, to do something for each player (e.g. `for color in Color::both()`)
+    #[inline]
+    pub const fn both() -> [Self; 2] {
+        [Self::White, Self::Black]
+    }
+}
+
 /// A color can be inverted using the `!` operator.
 /// `!Color::White` becomes `Color::Black` and vice versa.
 impl core::ops::Not for Color {
     type Output = Self;
     fn not(self) -> Self {
-        match self {
-            Self::White => Self::Black,
-            Self::Black => Self::White,
-        }
+        self.opponent()
     }
 }
 
@@ -189,7 +229,7 @@ impl core::ops::Not for Color {
 /// A move that can be applied to a board.
 /// When applied to a board, the board assumes that the move is
 /// being applied for the current turn's player.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Move {
     /// If the current player is white, move the king to the C1 square, and the kingside rook to
     /// the D1 square. If the current player is black, however, move the king to the C8 square,
@@ -219,10 +259,72 @@ pub enum Move {
     /// the allied pawn. After the one turn a player has to en-passant capture, the
     /// en-passant square is forgotten and can no longer be used.
     Piece(Position, Position),
+    /// Drop a piece of the player's reserve on an empty square (Crazyhouse).
+    /// Pawns can't be dropped on the first and on the last rank.
+    Drop(PieceKind, Position),
     /// When played by another player, it awards victory to the other.
     Resign,
 }
 
+impl Move {
+    /// ### is_castle
+    ///
+    /// Returns whether the move is a castle (either kingside or queenside)
+    pub fn is_castle(&self) -> bool {
+        self.is_kingside_castle() || self.is_queenside_castle()
+    }
+
+    /// ### is_kingside_castle
+    ///
+    /// Returns whether the move is a kingside castle
+    pub fn is_kingside_castle(&self) -> bool {
+        matches!(self, Self::KingSideCastle)
+    }
+
+    /// ### is_queenside_castle
+    ///
+    /// Returns whether the move is a queenside castle
+    pub fn is_queenside_castle(&self) -> bool {
+        matches!(self, Self::QueenSideCastle)
+    }
+
+    /// ### is_resign
+    ///
+    /// Returns whether the move is a resign
+    pub fn is_resign(&self) -> bool {
+        matches!(self, Self::Resign)
+    }
+
+    /// ### is_drop
+    ///
+    /// Returns whether the move is a piece drop
+    pub fn is_drop(&self) -> bool {
+        matches!(self, Self::Drop(_, _))
+    }
+
+    /// ### from
+    ///
+    /// Get the square the piece is moved from.
+    /// Returns `None` for castles, drops and resign
+    pub fn from(&self) -> Option<Position> {
+        match self {
+            Self::Piece(from, _) => Some(*from),
+            _ => None,
+        }
+    }
+
+    /// ### to
+    ///
+    /// Get the square the piece is moved (or dropped) to.
+    /// Returns `None` for castles and resign
+    pub fn to(&self) -> Option<Position> {
+        match self {
+            Self::Piece(_, to) | Self::Drop(_, to) => Some(*to),
+            _ => None,
+        }
+    }
+}
+
 impl core::fmt::Display for Move {
     // TODO: use PGN formatter
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
@@ -231,6 +333,7 @@ impl core::fmt::Display for Move {
             Move::Piece(from, to) => write!(f, "{} to {}", from, to),
             Move::KingSideCastle => write!(f, "O-O"),
             Move::QueenSideCastle => write!(f, "O-O-O"),
+            Move::Drop(kind, to) => write!(f, "{}@{}", kind.to_ascii_char(), to),
             Move::Resign => write!(f, "Resign"),
         }
     }
@@ -241,8 +344,55 @@ mod test {
 
     use super::*;
     use alloc::string::ToString;
+    use alloc::vec::Vec;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn color_opponent() {
+        const OPPONENT: Color = WHITE.opponent();
+        assert_eq!(OPPONENT, BLACK);
+        assert_eq!(WHITE.opponent(), BLACK);
+        assert_eq!(BLACK.opponent(), WHITE);
+        assert_eq!(WHITE.opponent(), !WHITE);
+        const PAWN_UP: Position = E2.pawn_up(BLACK.opponent());
+        assert_eq!(PAWN_UP, E3);
+    }
+
+    #[test]
+    fn color_both() {
+        assert_eq!(Color::both(), [WHITE, BLACK]);
+        let mut colors: Vec<Color> = Vec::new();
+        for color in Color::both().iter() {
+            colors.push(*color);
+            colors.push(!*color);
+        }
+        assert_eq!(colors, vec![WHITE, BLACK, BLACK, WHITE]);
+    }
+
+    #[test]
+    fn move_predicates() {
+        assert_eq!(Move::KingSideCastle.is_castle(), true);
+        assert_eq!(Move::QueenSideCastle.is_castle(), true);
+        assert_eq!(Move::Piece(E2, E4).is_castle(), false);
+        assert_eq!(Move::Resign.is_castle(), false);
+        assert_eq!(Move::KingSideCastle.is_kingside_castle(), true);
+        assert_eq!(Move::QueenSideCastle.is_kingside_castle(), false);
+        assert_eq!(Move::QueenSideCastle.is_queenside_castle(), true);
+        assert_eq!(Move::KingSideCastle.is_queenside_castle(), false);
+        assert_eq!(Move::Resign.is_resign(), true);
+        assert_eq!(Move::Piece(E2, E4).is_resign(), false);
+    }
+
+    #[test]
+    fn move_squares() {
+        assert_eq!(Move::Piece(E2, E4).from(), Some(E2));
+        assert_eq!(Move::Piece(E2, E4).to(), Some(E4));
+        assert_eq!(Move::KingSideCastle.from(), None);
+        assert_eq!(Move::QueenSideCastle.to(), None);
+        assert_eq!(Move::Resign.from(), None);
+        assert_eq!(Move::Resign.to(), None);
+    }
+
     #[test]
     fn fmt_color() {
         assert_eq!(BLACK.to_string(), "Black");
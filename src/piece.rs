@@ -2,8 +2,10 @@
 //!
 //! Exposes the piece type and its related functions
 
-use super::{Board, Color, Move, Position, BLACK, WHITE};
+use super::tables::{KING_ATTACKS, KNIGHT_ATTACKS};
+use super::{Board, Color, Direction, Move, Position, Promotion, BLACK, WHITE};
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 /// ## Piece
 ///
@@ -16,7 +18,7 @@ use alloc::vec::Vec;
 /// 2. The validity of legal attacks
 /// 3. Move generation
 /// 4. Material and positional value
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Piece {
     King(Color, Position),
     Queen(Color, Position),
@@ -26,6 +28,98 @@ pub enum Piece {
     Pawn(Color, Position),
 }
 
+/// ## PieceKind
+///
+/// The kind of a piece, regardless of its color and position
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PieceKind {
+    King,
+    Queen,
+    Rook,
+    Bishop,
+    Knight,
+    Pawn,
+}
+
+impl PieceKind {
+    /// ### to_ascii_char
+    ///
+    /// Get the uppercase ASCII character for the kind of piece (e.g. `N` for knight)
+    pub fn to_ascii_char(&self) -> char {
+        match self {
+            Self::King => 'K',
+            Self::Queen => 'Q',
+            Self::Rook => 'R',
+            Self::Bishop => 'B',
+            Self::Knight => 'N',
+            Self::Pawn => 'P',
+        }
+    }
+
+    /// ### from_ascii_char
+    ///
+    /// Get the kind of piece from its ASCII character, either uppercase or lowercase.
+    /// Returns `None` if the character is not a piece
+    pub fn from_ascii_char(ch: char) -> Option<Self> {
+        Piece::from_ascii_char(ch, Position::new(0, 0)).map(|x| x.kind())
+    }
+
+    /// ### name
+    ///
+    /// Get the name of the kind of piece such as `"pawn"` or `"king"`.
+    /// All names are lowercase.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::King => "king",
+            Self::Queen => "queen",
+            Self::Rook => "rook",
+            Self::Bishop => "bishop",
+            Self::Knight => "knight",
+            Self::Pawn => "pawn",
+        }
+    }
+
+    /// ### material_value
+    ///
+    /// Get the material value for the kind of piece (see `Piece::get_material_value`)
+    pub fn material_value(&self) -> i32 {
+        match self {
+            Self::King => 99999,
+            Self::Queen => 9,
+            Self::Rook => 5,
+            Self::Bishop => 3,
+            Self::Knight => 3,
+            Self::Pawn => 1,
+        }
+    }
+}
+
+impl From<Promotion> for PieceKind {
+    fn from(promotion: Promotion) -> Self {
+        match promotion {
+            Promotion::Queen => Self::Queen,
+            Promotion::Rook => Self::Rook,
+            Promotion::Bishop => Self::Bishop,
+            Promotion::Knight => Self::Knight,
+        }
+    }
+}
+
+/// Pawns can be promoted to any kind of piece, except for kings and pawns
+impl TryFrom<PieceKind> for Promotion {
+    type Error = &'static str;
+
+    fn try_from(kind: PieceKind) -> Result<Self, Self::Error> {
+        match kind {
+            PieceKind::Queen => Ok(Self::Queen),
+            PieceKind::Rook => Ok(Self::Rook),
+            PieceKind::Bishop => Ok(Self::Bishop),
+            PieceKind::Knight => Ok(Self::Knight),
+            PieceKind::King | PieceKind::Pawn => Err("Pawns can't be promoted to this piece"),
+        }
+    }
+}
+
 const WHITE_KING_POSITION_WEIGHTS: [[f64; 8]; 8] = [
     [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
     [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
@@ -158,19 +252,112 @@ const BLACK_PAWN_POSITION_WEIGHTS: [[f64; 8]; 8] = [
 ];
 
 impl Piece {
+    /// ### new
+    ///
+    /// Instantiate a new piece of the provided kind
+    pub fn new(kind: PieceKind, color: Color, pos: Position) -> Self {
+        match kind {
+            PieceKind::King => Self::King(color, pos),
+            PieceKind::Queen => Self::Queen(color, pos),
+            PieceKind::Rook => Self::Rook(color, pos),
+            PieceKind::Bishop => Self::Bishop(color, pos),
+            PieceKind::Knight => Self::Knight(color, pos),
+            PieceKind::Pawn => Self::Pawn(color, pos),
+        }
+    }
+
+    /// ### kind
+    ///
+    /// Get the kind of the piece
+    pub fn kind(&self) -> PieceKind {
+        match self {
+            Self::King(_, _) => PieceKind::King,
+            Self::Queen(_, _) => PieceKind::Queen,
+            Self::Rook(_, _) => PieceKind::Rook,
+            Self::Bishop(_, _) => PieceKind::Bishop,
+            Self::Knight(_, _) => PieceKind::Knight,
+            Self::Pawn(_, _) => PieceKind::Pawn,
+        }
+    }
+
     /// ### get_name
     ///
     /// Get the name of the piece such as `"pawn"` or `"king"`.
     /// All names are lowercase.
     #[inline]
     pub fn get_name(&self) -> &'static str {
-        match self {
-            Self::King(_, _) => "king",
-            Self::Queen(_, _) => "queen",
-            Self::Rook(_, _) => "rook",
-            Self::Bishop(_, _) => "bishop",
-            Self::Knight(_, _) => "knight",
-            Self::Pawn(_, _) => "pawn",
+        self.kind().name()
+    }
+
+    /// ### to_ascii_char
+    ///
+    /// Get the ASCII character for the piece, as used in FEN notation.
+    /// White pieces are uppercase (`K`, `Q`, `R`, `B`, `N`, `P`), while black pieces are lowercase.
+    pub fn to_ascii_char(&self) -> char {
+        let ch: char = match self {
+            Self::King(_, _) => 'k',
+            Self::Queen(_, _) => 'q',
+            Self::Rook(_, _) => 'r',
+            Self::Bishop(_, _) => 'b',
+            Self::Knight(_, _) => 'n',
+            Self::Pawn(_, _) => 'p',
+        };
+        match self.get_color() {
+            WHITE => ch.to_ascii_uppercase(),
+            BLACK => ch,
+        }
+    }
+
+    /// ### from_ascii_char
+    ///
+    /// Get the piece at `pos` from its ASCII character, as used in FEN notation.
+    /// Uppercase letters are white pieces, while lowercase letters are black pieces.
+    /// Returns `None` if the character is not a piece
+    pub fn from_ascii_char(ch: char, pos: Position) -> Option<Self> {
+        let color: Color = if ch.is_ascii_uppercase() {
+            WHITE
+        } else {
+            BLACK
+        };
+        match ch.to_ascii_lowercase() {
+            'k' => Some(Self::King(color, pos)),
+            'q' => Some(Self::Queen(color, pos)),
+            'r' => Some(Self::Rook(color, pos)),
+            'b' => Some(Self::Bishop(color, pos)),
+            'n' => Some(Self::Knight(color, pos)),
+            'p' => Some(Self::Pawn(color, pos)),
+            _ => None,
+        }
+    }
+
+    /// ### from_promotion
+    ///
+    /// Get the piece of `color` at `pos` a pawn is promoted to
+    pub fn from_promotion(promotion: Promotion, color: Color, pos: Position) -> Self {
+        Self::new(PieceKind::from(promotion), color, pos)
+    }
+
+    /// ### to_unicode_char
+    ///
+    /// Get the unicode chess glyph for the piece (e.g. `♔` for white king)
+    pub fn to_unicode_char(&self) -> char {
+        match self.get_color() {
+            WHITE => match self {
+                Self::King(_, _) => '♔',
+                Self::Queen(_, _) => '♕',
+                Self::Rook(_, _) => '♖',
+                Self::Knight(_, _) => '♘',
+                Self::Bishop(_, _) => '♗',
+                Self::Pawn(_, _) => '♙',
+            },
+            BLACK => match self {
+                Self::King(_, _) => '♚',
+                Self::Queen(_, _) => '♛',
+                Self::Rook(_, _) => '♜',
+                Self::Knight(_, _) => '♞',
+                Self::Bishop(_, _) => '♝',
+                Self::Pawn(_, _) => '♟',
+            },
         }
     }
 
@@ -217,13 +404,30 @@ impl Piece {
     /// | Pawn | 1 |
     #[inline]
     pub fn get_material_value(&self) -> i32 {
+        self.kind().material_value()
+    }
+
+    /// ### value_centipawns
+    ///
+    /// Get the material value for a piece in centipawns, the unit used by most chess engines.
+    /// The king is not counted, since both players always have one.
+    /// | Name | Value |
+    /// |-|-|
+    /// | King | 0 |
+    /// | Queen | 900 |
+    /// | Rook | 500 |
+    /// | Bishop | 330 |
+    /// | Knight | 320 |
+    /// | Pawn | 100 |
+    #[inline]
+    pub fn value_centipawns(&self) -> i32 {
         match self {
-            Self::King(_, _) => 99999,
-            Self::Queen(_, _) => 9,
-            Self::Rook(_, _) => 5,
-            Self::Bishop(_, _) => 3,
-            Self::Knight(_, _) => 3,
-            Self::Pawn(_, _) => 1,
+            Self::King(_, _) => 0,
+            Self::Queen(_, _) => 900,
+            Self::Rook(_, _) => 500,
+            Self::Bishop(_, _) => 330,
+            Self::Knight(_, _) => 320,
+            Self::Pawn(_, _) => 100,
         }
     }
 
@@ -239,6 +443,14 @@ impl Piece {
     /// its material value, plus or minus a weight ranging between 5.0 and -5.0.
     #[inline]
     pub fn get_weighted_value(&self) -> f64 {
+        self.position_weight() + (self.get_material_value() * 10) as f64
+    }
+
+    /// ### position_weight
+    ///
+    /// Get the weight of the piece position, from the piece-square tables (ranging between 5.0 and -5.0)
+    #[inline]
+    pub(crate) fn position_weight(&self) -> f64 {
         let weights = match self {
             Self::King(c, _) => match c {
                 Color::White => WHITE_KING_POSITION_WEIGHTS,
@@ -266,7 +478,6 @@ impl Piece {
             },
         };
         weights[(7 - self.get_pos().get_row()) as usize][self.get_pos().get_col() as usize]
-            + (self.get_material_value() * 10) as f64
     }
 
     /// ### is_king
@@ -418,6 +629,21 @@ impl Piece {
     /// This is used for move generation.
     #[inline]
     pub(crate) fn get_legal_moves(&self, board: &Board) -> Vec<Move> {
+        self.legal_moves(board).collect::<Vec<Move>>()
+    }
+
+    /// ### has_legal_move
+    ///
+    /// Returns whether the piece has at least a legal move, stopping at the first one found
+    #[inline]
+    pub(crate) fn has_legal_move(&self, board: &Board) -> bool {
+        self.legal_moves(board).next().is_some()
+    }
+
+    /// ### legal_moves
+    ///
+    /// Iterate over the legal moves of the piece; legality is checked lazily
+    fn legal_moves<'a>(&self, board: &'a Board) -> impl Iterator<Item = Move> + 'a {
         let result: Vec<Move> = match *self {
             Self::Pawn(ally_color, pos) => Self::get_pawn_legal_moves(ally_color, pos, board),
             Self::King(ally_color, pos) => Self::get_king_legal_moves(ally_color, pos, board),
@@ -428,20 +654,10 @@ impl Piece {
         };
 
         let color = self.get_color();
-        // Filter illegal moves and off-boards from result
+        // Filter illegal moves from result; generators only return targets on board
         result
             .into_iter()
-            .filter(|x| match x {
-                Move::Piece(from, to) => {
-                    if from.is_on_board() && to.is_on_board() {
-                        board.is_legal_move(*x, color)
-                    } else {
-                        false
-                    }
-                }
-                _ => board.is_legal_move(*x, color),
-            })
-            .collect::<Vec<Move>>()
+            .filter(move |x| board.is_legal_move(*x, color))
     }
 
     /// ### is_legal_move
@@ -494,36 +710,37 @@ impl Piece {
     /// Get all legal moves for provided pawn
     fn get_pawn_legal_moves(ally_color: Color, pos: Position, board: &Board) -> Vec<Move> {
         let mut result: Vec<Move> = Vec::new();
-        let up = pos.pawn_up(ally_color);
-        let next_up = up.pawn_up(ally_color);
-        let up_left = up.next_left();
-        let up_right = up.next_right();
+        let up: Position = match pos.try_pawn_up(ally_color) {
+            Some(up) => up,
+            None => return result,
+        };
+        let up_left: Option<Position> = up.try_next_left();
+        let up_right: Option<Position> = up.try_next_right();
 
         if let Some(en_passant) = board.get_en_passant() {
-            if en_passant == up_left || en_passant == up_right {
+            if up_left == Some(en_passant) || up_right == Some(en_passant) {
                 result.push(Move::Piece(pos, en_passant));
             }
         }
 
-        if next_up.is_on_board()
-            && pos.is_starting_pawn(ally_color)
-            && board.has_no_piece(up)
-            && board.has_no_piece(next_up)
-        {
-            result.push(Move::Piece(pos, next_up))
+        if let Some(next_up) = up.try_pawn_up(ally_color) {
+            if board.is_double_push_square(pos, ally_color)
+                && board.has_no_piece(up)
+                && board.has_no_piece(next_up)
+            {
+                result.push(Move::Piece(pos, next_up))
+            }
         }
 
-        if up.is_on_board() && board.has_no_piece(up) {
+        if board.has_no_piece(up) {
             result.push(Move::Piece(pos, up))
         }
 
-        // Check up_left NOTE: don't use else if, you can have both of them
-        if up_left.is_on_board() && board.has_enemy_piece(up_left, ally_color) {
-            result.push(Move::Piece(pos, up.next_left()))
-        }
-        // Check up_right
-        if up_right.is_on_board() && board.has_enemy_piece(up.next_right(), ally_color) {
-            result.push(Move::Piece(pos, up.next_right()))
+        // Check up_left and up_right NOTE: don't use else if, you can have both of them
+        for target in [up_left, up_right].iter().flatten() {
+            if board.has_enemy_piece(*target, ally_color) {
+                result.push(Move::Piece(pos, *target))
+            }
         }
         result
     }
@@ -532,21 +749,12 @@ impl Piece {
     ///
     /// Get all legal moves for provided king
     fn get_king_legal_moves(ally_color: Color, pos: Position, board: &Board) -> Vec<Move> {
-        let mut result: Vec<Move> = Vec::new();
-        for p in &[
-            pos.next_left(),
-            pos.next_right(),
-            pos.next_above(),
-            pos.next_below(),
-            pos.next_left().next_above(),
-            pos.next_left().next_below(),
-            pos.next_right().next_above(),
-            pos.next_right().next_below(),
-        ] {
-            if p.is_on_board() && !board.has_ally_piece(*p, ally_color) {
-                result.push(Move::Piece(pos, *p))
-            }
-        }
+        let mut result: Vec<Move> = KING_ATTACKS
+            .get(pos)
+            .iter()
+            .filter(|x| !board.has_ally_piece(**x, ally_color))
+            .map(|x| Move::Piece(pos, *x))
+            .collect();
         // Castling; don't  check with else if, you can sometimes do both moves
         if board.can_kingside_castle(ally_color) {
             result.push(Move::KingSideCastle);
@@ -570,25 +778,18 @@ impl Piece {
     ///
     /// Get all legal moves for provided rook
     fn get_rook_legal_moves(ally_color: Color, pos: Position, board: &Board) -> Vec<Move> {
-        let mut result: Vec<Move> = Vec::new();
-        for row in 0..8 {
-            let new_pos = Position::new(row, pos.get_col());
-            if new_pos != pos
-                && !board.has_ally_piece(new_pos, ally_color)
-                && new_pos.is_orthogonal_to(pos)
-            {
-                result.push(Move::Piece(pos, new_pos));
-            }
-        }
-        for col in 0..8 {
-            let new_pos = Position::new(pos.get_row(), col);
-            if new_pos != pos
-                && !board.has_ally_piece(new_pos, ally_color)
-                && new_pos.is_orthogonal_to(pos)
-            {
-                result.push(Move::Piece(pos, new_pos));
-            }
-        }
+        let mut result: Vec<Move> = Self::get_sliding_legal_moves(
+            ally_color,
+            pos,
+            board,
+            &[Direction::South, Direction::North],
+        );
+        result.extend(Self::get_sliding_legal_moves(
+            ally_color,
+            pos,
+            board,
+            &[Direction::West, Direction::East],
+        ));
         result
     }
 
@@ -596,41 +797,49 @@ impl Piece {
     ///
     /// Get all legal moves for provided bishop
     fn get_bishop_legal_moves(ally_color: Color, pos: Position, board: &Board) -> Vec<Move> {
-        let mut result: Vec<Move> = Vec::new();
-        for row in 0..8 {
-            for col in 0..8 {
-                let new_pos = Position::new(row, col);
-                if new_pos != pos
-                    && !board.has_ally_piece(new_pos, ally_color)
-                    && new_pos.is_diagonal_to(pos)
-                {
-                    result.push(Move::Piece(pos, new_pos));
+        Self::get_sliding_legal_moves(ally_color, pos, board, &Direction::DIAGONALS)
+    }
+
+    /// ### get_sliding_legal_moves
+    ///
+    /// Get all legal moves for a piece sliding along `directions`.
+    /// Each ray is walked until the first piece, which is included only if it's an enemy.
+    /// Moves are sorted by target position, so the order doesn't depend on the order of the directions
+    fn get_sliding_legal_moves(
+        ally_color: Color,
+        pos: Position,
+        board: &Board,
+        directions: &[Direction],
+    ) -> Vec<Move> {
+        let mut targets: Vec<Position> = Vec::new();
+        for direction in directions {
+            for new_pos in pos.ray(*direction) {
+                if board.has_ally_piece(new_pos, ally_color) {
+                    break;
+                }
+                targets.push(new_pos);
+                if board.has_piece(new_pos) {
+                    break;
                 }
             }
         }
-        result
+        targets.sort_unstable();
+        targets
+            .into_iter()
+            .map(|new_pos| Move::Piece(pos, new_pos))
+            .collect()
     }
 
-    /// ### get_pawn_legal_moves
+    /// ### get_knight_legal_moves
     ///
-    /// Get all legal moves for provided pawn
+    /// Get all legal moves for provided knight
     fn get_knight_legal_moves(ally_color: Color, pos: Position, board: &Board) -> Vec<Move> {
-        let mut result: Vec<Move> = Vec::new();
-        for p in &[
-            pos.next_left().next_left().next_above(),
-            pos.next_left().next_above().next_above(),
-            pos.next_left().next_left().next_below(),
-            pos.next_left().next_below().next_below(),
-            pos.next_right().next_right().next_above(),
-            pos.next_right().next_above().next_above(),
-            pos.next_right().next_right().next_below(),
-            pos.next_right().next_below().next_below(),
-        ] {
-            if p.is_on_board() && !board.has_ally_piece(*p, ally_color) {
-                result.push(Move::Piece(pos, *p))
-            }
-        }
-        result
+        KNIGHT_ATTACKS
+            .get(pos)
+            .iter()
+            .filter(|x| !board.has_ally_piece(**x, ally_color))
+            .map(|x| Move::Piece(pos, *x))
+            .collect()
     }
 
     /// ### is_legal_pawn_move
@@ -650,7 +859,7 @@ impl Piece {
             (en_passant == up_left || en_passant == up_right) && (new_pos == en_passant)
         } else {
             false
-        }) || (pos.is_starting_pawn(ally_color)
+        }) || (board.is_double_push_square(pos, ally_color)
             && board.has_no_piece(new_pos)
             && board.has_no_piece(up)
             && new_pos == up.pawn_up(ally_color))
@@ -672,38 +881,14 @@ impl Piece {
     ///
     /// Checks whether provided move is legal for a rook
     fn is_legal_rook_move(pos: Position, new_pos: Position, board: &Board) -> bool {
-        if pos.is_orthogonal_to(new_pos) {
-            let mut traveling = pos.orthogonals_to(new_pos);
-            traveling.pop();
-
-            for pos in traveling {
-                if board.has_piece(pos) {
-                    return false;
-                }
-            }
-            true
-        } else {
-            false
-        }
+        pos.is_orthogonal_to(new_pos) && board.path_clear(pos, new_pos)
     }
 
     /// ### is_legal_bishop_move
     ///
     /// Checks whether provided move is legal for a bishop
     fn is_legal_bishop_move(pos: Position, new_pos: Position, board: &Board) -> bool {
-        if pos.is_diagonal_to(new_pos) {
-            let mut traveling = pos.diagonals_to(new_pos);
-            traveling.pop();
-
-            for pos in traveling {
-                if board.has_piece(pos) {
-                    return false;
-                }
-            }
-            true
-        } else {
-            false
-        }
+        pos.is_diagonal_to(new_pos) && board.path_clear(pos, new_pos)
     }
 
     /// ### is_legal_pawn_attack
@@ -737,65 +922,20 @@ impl Piece {
     ///
     /// Checks whether provided position is a valid attack for a rook
     fn is_legal_rook_attack(pos: Position, new_pos: Position, board: &Board) -> bool {
-        if pos.is_orthogonal_to(new_pos) {
-            let mut traveling = pos.orthogonals_to(new_pos);
-            traveling.pop();
-
-            for pos in traveling {
-                if board.has_piece(pos) {
-                    return false;
-                }
-            }
-            true
-        } else {
-            false
-        }
+        pos.is_orthogonal_to(new_pos) && board.path_clear(pos, new_pos)
     }
 
     /// ### is_legal_bishop_attack
     ///
     /// Checks whether provided position is a valid attack for a bishop
     fn is_legal_bishop_attack(pos: Position, new_pos: Position, board: &Board) -> bool {
-        if pos.is_diagonal_to(new_pos) {
-            let mut traveling = pos.diagonals_to(new_pos);
-            traveling.pop();
-
-            for pos in traveling {
-                if board.has_piece(pos) {
-                    return false;
-                }
-            }
-            true
-        } else {
-            false
-        }
+        pos.is_diagonal_to(new_pos) && board.path_clear(pos, new_pos)
     }
 }
 
 impl core::fmt::Display for Piece {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
-        write!(
-            f,
-            "{}",
-            match self.get_color() {
-                WHITE => match self {
-                    Self::King(_, _) => "♔",
-                    Self::Queen(_, _) => "♕",
-                    Self::Rook(_, _) => "♖",
-                    Self::Knight(_, _) => "♘",
-                    Self::Bishop(_, _) => "♗",
-                    Self::Pawn(_, _) => "♙",
-                },
-                BLACK => match self {
-                    Self::King(_, _) => "♚",
-                    Self::Queen(_, _) => "♛",
-                    Self::Rook(_, _) => "♜",
-                    Self::Knight(_, _) => "♞",
-                    Self::Bishop(_, _) => "♝",
-                    Self::Pawn(_, _) => "♟",
-                },
-            }
-        )
+        write!(f, "{}", self.to_unicode_char())
     }
 }
 
@@ -850,6 +990,16 @@ mod test {
         assert_eq!(Piece::Rook(BLACK, D4).get_material_value(), 5);
     }
 
+    #[test]
+    fn value_centipawns() {
+        assert_eq!(Piece::Bishop(WHITE, D4).value_centipawns(), 330);
+        assert_eq!(Piece::King(BLACK, D4).value_centipawns(), 0);
+        assert_eq!(Piece::Knight(WHITE, D4).value_centipawns(), 320);
+        assert_eq!(Piece::Queen(BLACK, D4).value_centipawns(), 900);
+        assert_eq!(Piece::Pawn(WHITE, D4).value_centipawns(), 100);
+        assert_eq!(Piece::Rook(BLACK, D4).value_centipawns(), 500);
+    }
+
     #[test]
     fn get_weighted_value() {
         assert_eq!(Piece::Bishop(WHITE, C2).get_weighted_value(), 30.0);
@@ -1746,6 +1896,119 @@ mod test {
         assert_eq!(Piece::Pawn(WHITE, E4).is_legal_move(E5, &board), false);
     }
 
+    #[test]
+    fn to_ascii_char() {
+        assert_eq!(Piece::King(WHITE, A1).to_ascii_char(), 'K');
+        assert_eq!(Piece::King(BLACK, A1).to_ascii_char(), 'k');
+        assert_eq!(Piece::Queen(WHITE, A1).to_ascii_char(), 'Q');
+        assert_eq!(Piece::Queen(BLACK, A1).to_ascii_char(), 'q');
+        assert_eq!(Piece::Rook(WHITE, A1).to_ascii_char(), 'R');
+        assert_eq!(Piece::Rook(BLACK, A1).to_ascii_char(), 'r');
+        assert_eq!(Piece::Bishop(WHITE, A1).to_ascii_char(), 'B');
+        assert_eq!(Piece::Bishop(BLACK, A1).to_ascii_char(), 'b');
+        assert_eq!(Piece::Knight(WHITE, A1).to_ascii_char(), 'N');
+        assert_eq!(Piece::Knight(BLACK, A1).to_ascii_char(), 'n');
+        assert_eq!(Piece::Pawn(WHITE, A1).to_ascii_char(), 'P');
+        assert_eq!(Piece::Pawn(BLACK, A1).to_ascii_char(), 'p');
+    }
+
+    #[test]
+    fn from_ascii_char() {
+        for piece in [
+            Piece::King(WHITE, A1),
+            Piece::Queen(BLACK, A1),
+            Piece::Rook(WHITE, A1),
+            Piece::Bishop(BLACK, A1),
+            Piece::Knight(WHITE, A1),
+            Piece::Pawn(BLACK, A1),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Piece::from_ascii_char(piece.to_ascii_char(), A1),
+                Some(*piece)
+            );
+        }
+        assert_eq!(Piece::from_ascii_char('x', A1), None);
+    }
+
+    #[test]
+    fn piece_kind() {
+        let kinds: [PieceKind; 6] = [
+            PieceKind::King,
+            PieceKind::Queen,
+            PieceKind::Rook,
+            PieceKind::Bishop,
+            PieceKind::Knight,
+            PieceKind::Pawn,
+        ];
+        for kind in kinds.iter() {
+            for color in Color::both().iter() {
+                let piece: Piece = Piece::new(*kind, *color, D4);
+                assert_eq!(piece.kind(), *kind);
+                assert_eq!(piece.get_color(), *color);
+                assert_eq!(piece.get_pos(), D4);
+                assert_eq!(piece.get_name(), kind.name());
+                assert_eq!(piece.get_material_value(), kind.material_value());
+            }
+            assert_eq!(
+                PieceKind::from_ascii_char(kind.to_ascii_char()),
+                Some(*kind)
+            );
+        }
+        assert_eq!(PieceKind::Knight.name(), "knight");
+        assert_eq!(PieceKind::Rook.material_value(), 5);
+    }
+
+    #[test]
+    fn piece_kind_promotion() {
+        for promotion in [
+            Promotion::Queen,
+            Promotion::Rook,
+            Promotion::Bishop,
+            Promotion::Knight,
+        ]
+        .iter()
+        {
+            let kind: PieceKind = PieceKind::from(*promotion);
+            assert_eq!(Promotion::try_from(kind), Ok(*promotion));
+        }
+        assert_eq!(PieceKind::from(Promotion::Queen), PieceKind::Queen);
+        assert_eq!(PieceKind::from(Promotion::Knight), PieceKind::Knight);
+        assert!(Promotion::try_from(PieceKind::King).is_err());
+        assert!(Promotion::try_from(PieceKind::Pawn).is_err());
+    }
+
+    #[test]
+    fn from_promotion() {
+        for color in [WHITE, BLACK].iter() {
+            assert_eq!(
+                Piece::from_promotion(Promotion::Queen, *color, E8),
+                Piece::Queen(*color, E8)
+            );
+            assert_eq!(
+                Piece::from_promotion(Promotion::Rook, *color, E8),
+                Piece::Rook(*color, E8)
+            );
+            assert_eq!(
+                Piece::from_promotion(Promotion::Bishop, *color, E8),
+                Piece::Bishop(*color, E8)
+            );
+            assert_eq!(
+                Piece::from_promotion(Promotion::Knight, *color, E8),
+                Piece::Knight(*color, E8)
+            );
+        }
+    }
+
+    #[test]
+    fn to_unicode_char() {
+        assert_eq!(Piece::King(WHITE, A1).to_unicode_char(), '♔');
+        assert_eq!(Piece::King(BLACK, A1).to_unicode_char(), '♚');
+        assert_eq!(Piece::Pawn(WHITE, A1).to_unicode_char(), '♙');
+        assert_eq!(Piece::Pawn(BLACK, A1).to_unicode_char(), '♟');
+    }
+
     #[test]
     fn fmt() {
         assert_eq!(Piece::Bishop(WHITE, A1).to_string(), "♗");
@@ -7,10 +7,12 @@
 //! - the column
 //!
 //! This module also exposes all the alias for the positions (e.g. `D4` or `C6`)
+//! and the `File` and `Rank` types.
 //!
 
 use super::{Color, BLACK, WHITE};
-use alloc::{str::FromStr, string::String, vec::Vec};
+use alloc::{str::FromStr, vec::Vec};
+use core::convert::TryFrom;
 
 // -- alias
 
@@ -89,7 +91,7 @@ pub const H8: Position = Position::new(7, 7);
 /// ## Position
 ///
 /// Defines a position on the chess board
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Position {
     row: i32,
     col: i32,
@@ -97,22 +99,230 @@ pub struct Position {
 
 impl core::fmt::Display for Position {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
-        write!(
-            f,
-            "{}{}",
-            match self.col {
-                0 => 'a',
-                1 => 'b',
-                2 => 'c',
-                3 => 'd',
-                4 => 'e',
-                5 => 'f',
-                6 => 'g',
-                7 => 'h',
-                _ => '?',
-            },
-            self.row + 1
-        )
+        match self.file() {
+            Some(file) => write!(f, "{}", file)?,
+            None => write!(f, "?")?,
+        }
+        write!(f, "{}", self.row + 1)
+    }
+}
+
+/// ## File
+///
+/// Describes a column of the chess board, from `A` to `H`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum File {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+/// ## Rank
+///
+/// Describes a row of the chess board, from `First` (white's back rank) to `Eighth` (black's back rank)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+    Eighth,
+}
+
+impl File {
+    /// ### from_index
+    ///
+    /// Get file from column index (0 to 7).
+    /// Returns `None` if index is out of range
+    pub fn from_index(index: i32) -> Option<Self> {
+        match index {
+            0 => Some(Self::A),
+            1 => Some(Self::B),
+            2 => Some(Self::C),
+            3 => Some(Self::D),
+            4 => Some(Self::E),
+            5 => Some(Self::F),
+            6 => Some(Self::G),
+            7 => Some(Self::H),
+            _ => None,
+        }
+    }
+
+    /// ### index
+    ///
+    /// Get column index (0 to 7) for file
+    pub const fn index(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl From<File> for i32 {
+    fn from(file: File) -> Self {
+        file.index()
+    }
+}
+
+impl TryFrom<i32> for File {
+    type Error = &'static str;
+
+    fn try_from(index: i32) -> Result<Self, Self::Error> {
+        Self::from_index(index).ok_or("Invalid column")
+    }
+}
+
+impl From<File> for char {
+    fn from(file: File) -> Self {
+        (b'a' + file.index() as u8) as char
+    }
+}
+
+impl TryFrom<char> for File {
+    type Error = &'static str;
+
+    /// The letter of the file can be either lowercase or uppercase
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch.to_ascii_lowercase() {
+            letter @ 'a'..='h' => Self::try_from((letter as u8 - b'a') as i32),
+            _ => Err("Invalid column"),
+        }
+    }
+}
+
+impl Rank {
+    /// ### from_index
+    ///
+    /// Get rank from row index (0 to 7).
+    /// Returns `None` if index is out of range
+    pub fn from_index(index: i32) -> Option<Self> {
+        match index {
+            0 => Some(Self::First),
+            1 => Some(Self::Second),
+            2 => Some(Self::Third),
+            3 => Some(Self::Fourth),
+            4 => Some(Self::Fifth),
+            5 => Some(Self::Sixth),
+            6 => Some(Self::Seventh),
+            7 => Some(Self::Eighth),
+            _ => None,
+        }
+    }
+
+    /// ### index
+    ///
+    /// Get row index (0 to 7) for rank
+    pub const fn index(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl From<Rank> for i32 {
+    fn from(rank: Rank) -> Self {
+        rank.index()
+    }
+}
+
+impl TryFrom<i32> for Rank {
+    type Error = &'static str;
+
+    fn try_from(index: i32) -> Result<Self, Self::Error> {
+        Self::from_index(index).ok_or("Invalid row")
+    }
+}
+
+impl From<Rank> for char {
+    fn from(rank: Rank) -> Self {
+        (b'1' + rank.index() as u8) as char
+    }
+}
+
+impl TryFrom<char> for Rank {
+    type Error = &'static str;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            digit @ '1'..='8' => Self::try_from((digit as u8 - b'1') as i32),
+            _ => Err("Invalid row"),
+        }
+    }
+}
+
+/// ## Direction
+///
+/// Describes a compass direction on the chess board, where north is towards black's back rank
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The directions a rook moves along
+    pub const ORTHOGONALS: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+    /// The directions a bishop moves along
+    pub const DIAGONALS: [Direction; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    /// ### offset
+    ///
+    /// Get the row and column offset of a single step in this direction
+    pub const fn offset(&self) -> (i32, i32) {
+        match self {
+            Self::North => (1, 0),
+            Self::NorthEast => (1, 1),
+            Self::East => (0, 1),
+            Self::SouthEast => (-1, 1),
+            Self::South => (-1, 0),
+            Self::SouthWest => (-1, -1),
+            Self::West => (0, -1),
+            Self::NorthWest => (1, -1),
+        }
+    }
+}
+
+impl core::fmt::Display for File {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl core::fmt::Display for Rank {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl From<(File, Rank)> for Position {
+    fn from((file, rank): (File, Rank)) -> Self {
+        Self::from_file_rank(file, rank)
+    }
+}
+
+impl core::convert::TryFrom<Position> for (File, Rank) {
+    type Error = &'static str;
+
+    fn try_from(pos: Position) -> Result<Self, Self::Error> {
+        match (pos.file(), pos.rank()) {
+            (Some(file), Some(rank)) => Ok((file, rank)),
+            _ => Err("Position is off board"),
+        }
     }
 }
 
@@ -132,6 +342,16 @@ impl Position {
         Self { row, col }
     }
 
+    /// ### from_file_rank
+    ///
+    /// Create a `Position` from its file and rank, which can't be swapped by mistake as row and column numbers.
+    ///
+    /// Example: `E4 = Position::from_file_rank(File::E, Rank::Fourth)`
+    #[inline]
+    pub const fn from_file_rank(file: File, rank: Rank) -> Self {
+        Self::new(rank.index(), file.index())
+    }
+
     /// ### king_pos
     ///
     /// Return the starting position for a given color's king.
@@ -158,7 +378,7 @@ impl Position {
     ///
     /// Is this position a valid spot on the board?
     #[inline]
-    pub fn is_on_board(&self) -> bool {
+    pub const fn is_on_board(&self) -> bool {
         !self.is_off_board()
     }
 
@@ -166,7 +386,7 @@ impl Position {
     ///
     /// Is this position NOT a valid spot on the board?
     #[inline]
-    pub fn is_off_board(&self) -> bool {
+    pub const fn is_off_board(&self) -> bool {
         self.row < 0 || self.row > 7 || self.col < 0 || self.col > 7
     }
 
@@ -175,7 +395,7 @@ impl Position {
     /// Get the row number of the position.
     /// This can be any of 0, 1, 2, 3, 4, 5, 6, or 7.
     #[inline]
-    pub fn get_row(&self) -> i32 {
+    pub const fn get_row(&self) -> i32 {
         self.row
     }
 
@@ -183,10 +403,71 @@ impl Position {
     ///
     /// Get column index from 0 to 7
     #[inline]
-    pub fn get_col(&self) -> i32 {
+    pub const fn get_col(&self) -> i32 {
         self.col
     }
 
+    /// ### file
+    ///
+    /// Get the file of the position.
+    /// Returns `None` if the position is off board
+    #[inline]
+    pub fn file(&self) -> Option<File> {
+        File::from_index(self.col)
+    }
+
+    /// ### rank
+    ///
+    /// Get the rank of the position.
+    /// Returns `None` if the position is off board
+    #[inline]
+    pub fn rank(&self) -> Option<Rank> {
+        Rank::from_index(self.row)
+    }
+
+    /// ### with_file
+    ///
+    /// Get the position on the same rank, on the provided file
+    #[inline]
+    pub fn with_file(&self, file: File) -> Self {
+        Self::new(self.row, file.index())
+    }
+
+    /// ### with_rank
+    ///
+    /// Get the position on the same file, on the provided rank
+    #[inline]
+    pub fn with_rank(&self, rank: Rank) -> Self {
+        Self::new(rank.index(), self.col)
+    }
+
+    /// ### is_light_square
+    ///
+    /// Is this position a light square? (e.g. `h1`)
+    #[inline]
+    pub const fn is_light_square(&self) -> bool {
+        (self.row + self.col) % 2 == 1
+    }
+
+    /// ### square_color
+    ///
+    /// Get the color of the square: `White` for light squares (e.g. `h1`) and `Black` for dark squares (e.g. `a1`)
+    #[inline]
+    pub const fn square_color(&self) -> Color {
+        match self.is_light_square() {
+            true => WHITE,
+            false => BLACK,
+        }
+    }
+
+    /// ### is_dark_square
+    ///
+    /// Is this position a dark square? (e.g. `a1`)
+    #[inline]
+    pub const fn is_dark_square(&self) -> bool {
+        !self.is_light_square()
+    }
+
     /// ### add_row
     ///
     /// Increment row by drow
@@ -240,6 +521,45 @@ impl Position {
         (self.col - other.col).abs() + (self.row - other.row).abs()
     }
 
+    /// ### chebyshev_distance
+    ///
+    /// Get the chebyshev distance between two positions, which is the amount of moves
+    /// a king needs to go from a position to the other
+    #[inline]
+    pub fn chebyshev_distance(&self, other: Self) -> i32 {
+        core::cmp::max((self.col - other.col).abs(), (self.row - other.row).abs())
+    }
+
+    /// ### distance
+    ///
+    /// Get the distance between two positions, measured in king moves (the Chebyshev distance).
+    /// For example, the distance between `a1` and `h8` is 7
+    #[inline]
+    pub fn distance(&self, other: Self) -> i32 {
+        self.chebyshev_distance(other)
+    }
+
+    /// ### manhattan_distance
+    ///
+    /// Get the Manhattan distance between two positions, which is the sum of the row and column differences,
+    /// that is the amount of moves a rook needs to go from a position to the other moving one square at a time.
+    /// For example, the distance between `a1` and `h8` is 14
+    #[inline]
+    pub fn manhattan_distance(&self, other: Self) -> i32 {
+        (self.col - other.col).abs() + (self.row - other.row).abs()
+    }
+
+    /// ### distance_to_edge
+    ///
+    /// Get the distance between the position and the nearest edge of the board
+    #[inline]
+    pub fn distance_to_edge(&self) -> i32 {
+        core::cmp::min(
+            core::cmp::min(self.row, 7 - self.row),
+            core::cmp::min(self.col, 7 - self.col),
+        )
+    }
+
     /// ### is_adjacent_to
     ///
     /// Is this position adjacent to another position?
@@ -313,7 +633,7 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn next_below(&self) -> Self {
+    pub const fn next_below(&self) -> Self {
         Self::new(self.row - 1, self.col)
     }
 
@@ -326,7 +646,7 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn next_above(&self) -> Self {
+    pub const fn next_above(&self) -> Self {
         Self::new(self.row + 1, self.col)
     }
 
@@ -340,7 +660,7 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn pawn_up(&self, ally_color: Color) -> Self {
+    pub const fn pawn_up(&self, ally_color: Color) -> Self {
         match ally_color {
             WHITE => self.next_above(),
             BLACK => self.next_below(),
@@ -357,8 +677,8 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn pawn_back(&self, ally_color: Color) -> Self {
-        self.pawn_up(!ally_color)
+    pub const fn pawn_back(&self, ally_color: Color) -> Self {
+        self.pawn_up(ally_color.opponent())
     }
     /// ### next_left
     ///
@@ -369,7 +689,7 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn next_left(&self) -> Self {
+    pub const fn next_left(&self) -> Self {
         Self::new(self.row, self.col - 1)
     }
 
@@ -382,15 +702,80 @@ impl Position {
     /// position if you do not check with the `is_on_board`
     /// method!
     #[inline]
-    pub fn next_right(&self) -> Self {
+    pub const fn next_right(&self) -> Self {
         Self::new(self.row, self.col + 1)
     }
 
+    /// ### offset
+    ///
+    /// Get the position `drow` rows and `dcol` columns away from this position.
+    /// Returns `None` if the position is off board
+    #[inline]
+    pub const fn offset(&self, drow: i32, dcol: i32) -> Option<Self> {
+        let result: Self = Self::new(self.row + drow, self.col + dcol);
+        if result.is_on_board() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// ### try_next_below
+    ///
+    /// Get the position directly below this position, if on board
+    #[inline]
+    pub const fn try_next_below(&self) -> Option<Self> {
+        self.offset(-1, 0)
+    }
+
+    /// ### try_next_above
+    ///
+    /// Get the position directly above this position, if on board
+    #[inline]
+    pub const fn try_next_above(&self) -> Option<Self> {
+        self.offset(1, 0)
+    }
+
+    /// ### try_pawn_up
+    ///
+    /// Get the next square upwards from a respective player's pawn, if on board
+    #[inline]
+    pub const fn try_pawn_up(&self, ally_color: Color) -> Option<Self> {
+        match ally_color {
+            WHITE => self.try_next_above(),
+            BLACK => self.try_next_below(),
+        }
+    }
+
+    /// ### try_pawn_back
+    ///
+    /// Get the next square backwards from a respective player's pawn, if on board
+    #[inline]
+    pub const fn try_pawn_back(&self, ally_color: Color) -> Option<Self> {
+        self.try_pawn_up(ally_color.opponent())
+    }
+
+    /// ### try_next_left
+    ///
+    /// Get the position directly left of this position, if on board
+    #[inline]
+    pub const fn try_next_left(&self) -> Option<Self> {
+        self.offset(0, -1)
+    }
+
+    /// ### try_next_right
+    ///
+    /// Get the position directly right of this position, if on board
+    #[inline]
+    pub const fn try_next_right(&self) -> Option<Self> {
+        self.offset(0, 1)
+    }
+
     /// ### is_starting_pawn
     ///
     /// Is this pawn on the starting rank for the respective player?
     #[inline]
-    pub fn is_starting_pawn(&self, color: Color) -> bool {
+    pub const fn is_starting_pawn(&self, color: Color) -> bool {
         match color {
             WHITE => self.row == 1,
             BLACK => self.row == 6,
@@ -401,7 +786,7 @@ impl Position {
     ///
     /// Is this pawn on the last rank for the respective player?
     #[inline]
-    pub fn is_promoting_pawn(&self, color: Color) -> bool {
+    pub const fn is_promoting_pawn(&self, color: Color) -> bool {
         match color {
             WHITE => self.row == 7,
             BLACK => self.row == 0,
@@ -497,6 +882,16 @@ impl Position {
         result
     }
 
+    /// ### ray
+    ///
+    /// Get the successive positions from this position moving in `direction`, until the edge of the board.
+    ///
+    /// This does _not_ include the `from` position.
+    pub fn ray(&self, direction: Direction) -> impl Iterator<Item = Position> {
+        let (drow, dcol) = direction.offset();
+        core::iter::successors(self.offset(drow, dcol), move |pos| pos.offset(drow, dcol))
+    }
+
     /// ### is_knight_move
     ///
     /// Checks whether the provided position is a valid knight move
@@ -507,34 +902,42 @@ impl Position {
     }
 }
 
+/// ## ParsePositionError
+///
+/// Describes why a position couldn't be parsed from a string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParsePositionError {
+    /// The position is not made up of exactly a file and a rank
+    BadLength,
+    /// The file is not a letter between `a` and `h`
+    BadFile(char),
+    /// The rank is not a digit between `1` and `8`
+    BadRank(char),
+}
+
+impl core::fmt::Display for ParsePositionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadLength => write!(f, "A position must be made up of a file and a rank"),
+            Self::BadFile(ch) => write!(f, "Invalid file '{}'", ch),
+            Self::BadRank(ch) => write!(f, "Invalid rank '{}'", ch),
+        }
+    }
+}
+
+/// Parse a position such as `"e4"`.
+/// The letter of the file can be either lowercase or uppercase and surrounding whitespace is ignored
 impl FromStr for Position {
-    type Err = &'static str;
+    type Err = ParsePositionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check string length
-        if s.len() != 2 {
-            return Err("Invalid position");
-        }
-        // Convert string to uppercase
-        let s: String = s.to_uppercase();
-        // Get column
-        let row: i32 = (s.chars().nth(1).unwrap().to_digit(10).unwrap_or(0) as i32) - 1;
-        let col: i32 = match s.chars().next().unwrap() {
-            'A' => 0,
-            'B' => 1,
-            'C' => 2,
-            'D' => 3,
-            'E' => 4,
-            'F' => 5,
-            'G' => 6,
-            'H' => 7,
-            _ => return Err("Invalid column"),
-        };
-        let position: Position = Position::new(row, col);
-        if position.is_on_board() {
-            Ok(position)
-        } else {
-            Err("Invalid row")
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(file), Some(rank), None) => Ok(Self::from_file_rank(
+                File::try_from(file).map_err(|_| ParsePositionError::BadFile(file))?,
+                Rank::try_from(rank).map_err(|_| ParsePositionError::BadRank(rank))?,
+            )),
+            _ => Err(ParsePositionError::BadLength),
         }
     }
 }
@@ -544,7 +947,7 @@ mod test {
 
     use super::*;
 
-    use alloc::string::ToString;
+    use alloc::string::{String, ToString};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -574,6 +977,44 @@ mod test {
         assert_eq!(D5.get_row(), 4);
     }
 
+    #[test]
+    fn file_and_rank() {
+        assert_eq!(A1.file(), Some(File::A));
+        assert_eq!(A1.rank(), Some(Rank::First));
+        assert_eq!(H8.file(), Some(File::H));
+        assert_eq!(H8.rank(), Some(Rank::Eighth));
+        assert_eq!(Position::new(-1, 0).rank(), None);
+        assert_eq!(Position::new(0, 8).file(), None);
+        // Conversions
+        assert_eq!(Position::from((File::A, Rank::First)), A1);
+        assert_eq!(Position::from((File::A, Rank::Eighth)), A8);
+        assert_eq!(Position::from((File::H, Rank::First)), H1);
+        assert_eq!(Position::from((File::H, Rank::Eighth)), H8);
+        assert_eq!(<(File, Rank)>::try_from(A8), Ok((File::A, Rank::Eighth)));
+        assert_eq!(<(File, Rank)>::try_from(H1), Ok((File::H, Rank::First)));
+        assert!(<(File, Rank)>::try_from(Position::new(8, 0)).is_err());
+        // With
+        assert_eq!(E2.with_rank(Rank::Fourth), E4);
+        assert_eq!(E2.with_file(File::D), D2);
+        // Fmt
+        assert_eq!(File::C.to_string().as_str(), "c");
+        assert_eq!(Rank::Sixth.to_string().as_str(), "6");
+        assert_eq!(File::from_index(8), None);
+        assert_eq!(Rank::from_index(3), Some(Rank::Fourth));
+    }
+
+    #[test]
+    fn square_color() {
+        assert_eq!(A1.is_dark_square(), true);
+        assert_eq!(A1.is_light_square(), false);
+        assert_eq!(H1.is_light_square(), true);
+        assert_eq!(H1.is_dark_square(), false);
+        assert_eq!(A8.is_light_square(), true);
+        assert_eq!(H8.is_dark_square(), true);
+        assert_eq!(D1.is_light_square(), true);
+        assert_eq!(E1.is_dark_square(), true);
+    }
+
     #[test]
     fn add_col() {
         assert_eq!(D5.add_col(2), F5);
@@ -584,6 +1025,39 @@ mod test {
         assert_eq!(A2.add_row(3), A5);
     }
 
+    #[test]
+    fn ray() {
+        assert_eq!(
+            D4.ray(Direction::North).collect::<Vec<Position>>(),
+            vec![D5, D6, D7, D8]
+        );
+        assert_eq!(
+            D4.ray(Direction::SouthWest).collect::<Vec<Position>>(),
+            vec![C3, B2, A1]
+        );
+        assert_eq!(
+            B7.ray(Direction::NorthEast).collect::<Vec<Position>>(),
+            vec![C8]
+        );
+        assert_eq!(H5.ray(Direction::East).count(), 0);
+        assert_eq!(A1.ray(Direction::East).last(), Some(H1));
+        // Rays of a rook and a bishop cover every square they can reach
+        assert_eq!(
+            Direction::ORTHOGONALS
+                .iter()
+                .map(|x| E4.ray(*x).count())
+                .sum::<usize>(),
+            14
+        );
+        assert_eq!(
+            Direction::DIAGONALS
+                .iter()
+                .map(|x| E4.ray(*x).count())
+                .sum::<usize>(),
+            13
+        );
+    }
+
     #[test]
     fn is_diagonal_to() {
         assert_eq!(A2.is_diagonal_to(E6), true);
@@ -620,6 +1094,44 @@ mod test {
         ); // Negative
     }
 
+    #[test]
+    fn chebyshev_distance() {
+        assert_eq!(A1.chebyshev_distance(A1), 0);
+        assert_eq!(A1.chebyshev_distance(H8), 7);
+        assert_eq!(D4.chebyshev_distance(F5), 2);
+        assert_eq!(D4.chebyshev_distance(B1), 3);
+    }
+
+    #[test]
+    fn distance() {
+        // Corners
+        assert_eq!(A1.distance(H8), 7);
+        assert_eq!(A8.distance(H1), 7);
+        assert_eq!(A1.distance(H1), 7);
+        assert_eq!(A1.manhattan_distance(H8), 14);
+        assert_eq!(A8.manhattan_distance(H1), 14);
+        assert_eq!(A1.manhattan_distance(H1), 7);
+        // Center
+        assert_eq!(D4.distance(E5), 1);
+        assert_eq!(D4.manhattan_distance(E5), 2);
+        assert_eq!(E4.distance(A1), 4);
+        assert_eq!(E4.manhattan_distance(A1), 7);
+        assert_eq!(D5.distance(D5), 0);
+        assert_eq!(D5.manhattan_distance(D5), 0);
+        // Distance is symmetric
+        assert_eq!(H8.distance(C2), C2.distance(H8));
+        assert_eq!(H8.manhattan_distance(C2), C2.manhattan_distance(H8));
+    }
+
+    #[test]
+    fn distance_to_edge() {
+        assert_eq!(A1.distance_to_edge(), 0);
+        assert_eq!(H5.distance_to_edge(), 0);
+        assert_eq!(B7.distance_to_edge(), 1);
+        assert_eq!(C5.distance_to_edge(), 2);
+        assert_eq!(E4.distance_to_edge(), 3);
+    }
+
     #[test]
     fn is_adjacent_to() {
         assert_eq!(D4.is_adjacent_to(C3), true);
@@ -691,6 +1203,39 @@ mod test {
         assert_eq!(D6.pawn_back(BLACK), D7);
     }
 
+    #[test]
+    fn offset() {
+        assert_eq!(E4.offset(1, 1), Some(F5));
+        assert_eq!(E4.offset(-3, -4), Some(A1));
+        assert_eq!(E4.offset(5, 0), None);
+        assert_eq!(A1.offset(0, -1), None);
+        // Same as the unchecked arithmetic on board
+        for row in 0..8 {
+            for col in 0..8 {
+                let pos: Position = Position::new(row, col);
+                for (checked, unchecked) in [
+                    (pos.try_next_left(), pos.next_left()),
+                    (pos.try_next_right(), pos.next_right()),
+                    (pos.try_next_above(), pos.next_above()),
+                    (pos.try_next_below(), pos.next_below()),
+                    (pos.try_pawn_up(WHITE), pos.pawn_up(WHITE)),
+                    (pos.try_pawn_up(BLACK), pos.pawn_up(BLACK)),
+                    (pos.try_pawn_back(WHITE), pos.pawn_back(WHITE)),
+                ] {
+                    match checked {
+                        Some(checked) => assert_eq!(checked, unchecked),
+                        None => assert_eq!(unchecked.is_off_board(), true),
+                    }
+                }
+            }
+        }
+        assert_eq!(H8.try_next_right(), None);
+        assert_eq!(H8.try_next_above(), None);
+        assert_eq!(A1.try_next_below(), None);
+        assert_eq!(A8.try_pawn_up(WHITE), None);
+        assert_eq!(A8.try_pawn_up(BLACK), Some(A7));
+    }
+
     #[test]
     fn next_left() {
         assert_eq!(D3.next_left(), C3);
@@ -814,6 +1359,98 @@ mod test {
         assert!(Position::from_str("A9").is_err());
         assert!(Position::from_str("J5").is_err());
         assert!(Position::from_str("a01").is_err());
+        assert_eq!(Position::from_str(" e4\n"), Ok(E4));
+        assert_eq!(Position::from_str("\tE4 "), Ok(E4));
+    }
+
+    #[test]
+    fn position_from_str_errors() {
+        assert_eq!(Position::from_str(""), Err(ParsePositionError::BadLength));
+        assert_eq!(Position::from_str("e"), Err(ParsePositionError::BadLength));
+        assert_eq!(
+            Position::from_str("e44"),
+            Err(ParsePositionError::BadLength)
+        );
+        assert_eq!(
+            Position::from_str("e 4"),
+            Err(ParsePositionError::BadLength)
+        );
+        assert_eq!(
+            Position::from_str("j5"),
+            Err(ParsePositionError::BadFile('j'))
+        );
+        assert_eq!(
+            Position::from_str("45"),
+            Err(ParsePositionError::BadFile('4'))
+        );
+        assert_eq!(
+            Position::from_str("aX"),
+            Err(ParsePositionError::BadRank('X'))
+        );
+        assert_eq!(
+            Position::from_str("a0"),
+            Err(ParsePositionError::BadRank('0'))
+        );
+        assert_eq!(
+            Position::from_str("a9"),
+            Err(ParsePositionError::BadRank('9'))
+        );
+        assert_eq!(
+            ParsePositionError::BadRank('9').to_string().as_str(),
+            "Invalid rank '9'"
+        );
+    }
+
+    #[test]
+    fn position_as_map_key() {
+        use std::collections::HashMap;
+        let mut squares: HashMap<Position, &str> = HashMap::new();
+        squares.insert(E4, "e4");
+        squares.insert(Position::new(3, 4), "again e4");
+        squares.insert(D5, "d5");
+        assert_eq!(squares.len(), 2);
+        assert_eq!(squares.get(&E4), Some(&"again e4"));
+        assert_eq!(squares.get(&A1), None);
+    }
+
+    #[test]
+    fn file_rank_conversions() {
+        assert_eq!(i32::from(File::C), 2);
+        assert_eq!(File::try_from(7), Ok(File::H));
+        assert!(File::try_from(8).is_err());
+        assert!(File::try_from(-1).is_err());
+        assert_eq!(char::from(File::E), 'e');
+        assert_eq!(File::try_from('e'), Ok(File::E));
+        assert_eq!(File::try_from('E'), Ok(File::E));
+        assert!(File::try_from('i').is_err());
+        assert_eq!(i32::from(Rank::Eighth), 7);
+        assert_eq!(Rank::try_from(0), Ok(Rank::First));
+        assert!(Rank::try_from(8).is_err());
+        assert_eq!(char::from(Rank::Third), '3');
+        assert_eq!(Rank::try_from('8'), Ok(Rank::Eighth));
+        assert!(Rank::try_from('0').is_err());
+        assert!(Rank::try_from('9').is_err());
+    }
+
+    #[test]
+    fn from_file_rank() {
+        assert_eq!(Position::from_file_rank(File::E, Rank::Fourth), E4);
+        for row in 0..8 {
+            for col in 0..8 {
+                let pos: Position = Position::new(row, col);
+                let file: File = File::try_from(col).ok().unwrap();
+                let rank: Rank = Rank::try_from(row).ok().unwrap();
+                assert_eq!(Position::from_file_rank(file, rank), pos);
+                assert_eq!(pos.file(), Some(file));
+                assert_eq!(pos.rank(), Some(rank));
+                // Display and FromStr use the file and rank letters
+                let name: String = pos.to_string();
+                assert_eq!(name.chars().next(), Some(char::from(file)));
+                assert_eq!(name.chars().nth(1), Some(char::from(rank)));
+                assert_eq!(Position::from_str(name.as_str()), Ok(pos));
+                assert_eq!(Position::from_str(name.to_uppercase().as_str()), Ok(pos));
+            }
+        }
     }
 
     #[test]
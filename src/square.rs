@@ -7,7 +7,7 @@ use super::Piece;
 /// ## Square
 ///
 /// Essentially a container for a single piece on a board.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Square {
     piece: Option<Piece>,
 }
@@ -41,14 +41,58 @@ impl Square {
     pub fn get_piece(&self) -> Option<Piece> {
         self.piece
     }
+
+    /// ### set_piece
+    ///
+    /// Put `piece` in this square, replacing the piece it contains
+    #[inline]
+    pub fn set_piece(&mut self, piece: Piece) {
+        self.piece = Some(piece);
+    }
+
+    /// ### take
+    ///
+    /// Take the piece out of this square, leaving it empty
+    #[inline]
+    pub fn take(&mut self) -> Option<Piece> {
+        self.piece.take()
+    }
+
+    /// ### replace
+    ///
+    /// Put `piece` in this square, returning the piece it contained
+    #[inline]
+    pub fn replace(&mut self, piece: Piece) -> Option<Piece> {
+        self.piece.replace(piece)
+    }
+
+    /// ### to_char
+    ///
+    /// Get the unicode character of the piece contained in this square, or `empty` if the square is empty
+    pub fn to_char(&self, empty: char) -> char {
+        match self.piece {
+            Some(piece) => piece.to_unicode_char(),
+            None => empty,
+        }
+    }
+}
+
+/// A square is displayed as the unicode character of its piece, or as `·` if it's empty.
+/// Use `to_char` to choose a different marker for the empty squares
+impl core::fmt::Display for Square {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.to_char('·'))
+    }
 }
 
 #[cfg(test)]
 mod test {
 
     use super::*;
-    use crate::position::C8;
-    use crate::WHITE;
+    use crate::position::{C8, E4};
+    use crate::{BLACK, WHITE};
+
+    use alloc::string::ToString;
 
     use pretty_assertions::assert_eq;
 
@@ -63,4 +107,44 @@ mod test {
         assert_eq!(square.is_empty(), false);
         assert_eq!(square.get_piece().unwrap().is_queen(), true);
     }
+
+    #[test]
+    fn set_piece() {
+        let mut square: Square = Square::empty();
+        square.set_piece(Piece::Queen(WHITE, C8));
+        assert_eq!(square.get_piece(), Some(Piece::Queen(WHITE, C8)));
+        square.set_piece(Piece::Rook(BLACK, C8));
+        assert_eq!(square.get_piece(), Some(Piece::Rook(BLACK, C8)));
+    }
+
+    #[test]
+    fn take() {
+        let mut square: Square = Square::from(Piece::Queen(WHITE, C8));
+        assert_eq!(square.take(), Some(Piece::Queen(WHITE, C8)));
+        assert_eq!(square.is_empty(), true);
+        assert_eq!(square.take(), None);
+    }
+
+    #[test]
+    fn replace() {
+        let mut square: Square = Square::empty();
+        assert_eq!(square.replace(Piece::Pawn(WHITE, E4)), None);
+        assert_eq!(
+            square.replace(Piece::Knight(BLACK, E4)),
+            Some(Piece::Pawn(WHITE, E4))
+        );
+        assert_eq!(square.get_piece(), Some(Piece::Knight(BLACK, E4)));
+    }
+
+    #[test]
+    fn fmt_square() {
+        assert_eq!(Square::empty().to_string().as_str(), "·");
+        assert_eq!(Square::empty().to_char('.'), '.');
+        let square: Square = Square::from(Piece::Queen(WHITE, C8));
+        assert_eq!(square.to_string(), Piece::Queen(WHITE, C8).to_string());
+        assert_eq!(
+            square.to_char('.'),
+            Piece::Queen(WHITE, C8).to_unicode_char()
+        );
+    }
 }
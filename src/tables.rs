@@ -0,0 +1,143 @@
+//! # Tables
+//!
+//! This module exposes the attack tables precomputed at compile time for the pieces moving by a fixed offset
+//! (knight and king), so the move generation can index a table instead of computing the target squares.
+
+use super::Position;
+
+/// Offsets (row, col) of the knight moves
+const KNIGHT_OFFSETS: [(i32, i32); 8] = [
+    (1, -2),
+    (2, -1),
+    (-1, -2),
+    (-2, -1),
+    (1, 2),
+    (2, 1),
+    (-1, 2),
+    (-2, 1),
+];
+
+/// Offsets (row, col) of the king moves
+const KING_OFFSETS: [(i32, i32); 8] = [
+    (0, -1),
+    (0, 1),
+    (1, 0),
+    (-1, 0),
+    (1, -1),
+    (-1, -1),
+    (1, 1),
+    (-1, 1),
+];
+
+/// Knight attacks for each square
+pub(crate) static KNIGHT_ATTACKS: AttackTable = AttackTable::new(&KNIGHT_OFFSETS);
+/// King attacks for each square
+pub(crate) static KING_ATTACKS: AttackTable = AttackTable::new(&KING_OFFSETS);
+
+/// ## AttackTable
+///
+/// Describes the squares attacked from each square of the board by a piece moving by fixed offsets.
+/// Squares are indexed as `row * 8 + col`; the targets keep the order of the offsets
+pub(crate) struct AttackTable {
+    targets: [[Position; 8]; 64],
+    len: [usize; 64],
+}
+
+impl AttackTable {
+    /// ### new
+    ///
+    /// Compute the attack table for the provided offsets, discarding the targets off board
+    const fn new(offsets: &[(i32, i32); 8]) -> Self {
+        let mut targets: [[Position; 8]; 64] = [[Position::new(0, 0); 8]; 64];
+        let mut len: [usize; 64] = [0; 64];
+        let mut square: usize = 0;
+        while square < 64 {
+            let pos: Position = Position::new((square / 8) as i32, (square % 8) as i32);
+            let mut i: usize = 0;
+            while i < offsets.len() {
+                if let Some(target) = pos.offset(offsets[i].0, offsets[i].1) {
+                    targets[square][len[square]] = target;
+                    len[square] += 1;
+                }
+                i += 1;
+            }
+            square += 1;
+        }
+        Self { targets, len }
+    }
+
+    /// ### get
+    ///
+    /// Get the squares attacked from `pos`. If `pos` is off board, the slice is empty
+    pub(crate) fn get(&self, pos: Position) -> &[Position] {
+        if pos.is_off_board() {
+            return &[];
+        }
+        let square: usize = (pos.get_row() * 8 + pos.get_col()) as usize;
+        &self.targets[square][..self.len[square]]
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::position::*;
+
+    use alloc::vec::Vec;
+    use pretty_assertions::assert_eq;
+
+    /// Get all the squares of the board
+    fn squares() -> Vec<Position> {
+        (0..64).map(|x| Position::new(x / 8, x % 8)).collect()
+    }
+
+    #[test]
+    fn knight_attacks() {
+        assert_eq!(KNIGHT_ATTACKS.get(A1), &[C2, B3]);
+        assert_eq!(KNIGHT_ATTACKS.get(D4).len(), 8);
+        assert_eq!(KNIGHT_ATTACKS.get(Position::new(8, 0)), &[]);
+        // Same squares as the knight move check, in the same order as the chained moves
+        for pos in squares() {
+            let mut expected: Vec<Position> = squares()
+                .into_iter()
+                .filter(|x| pos.is_knight_move(*x))
+                .collect();
+            let mut targets: Vec<Position> = KNIGHT_ATTACKS.get(pos).to_vec();
+            let chained: Vec<Position> = [
+                pos.next_left().next_left().next_above(),
+                pos.next_left().next_above().next_above(),
+                pos.next_left().next_left().next_below(),
+                pos.next_left().next_below().next_below(),
+                pos.next_right().next_right().next_above(),
+                pos.next_right().next_above().next_above(),
+                pos.next_right().next_right().next_below(),
+                pos.next_right().next_below().next_below(),
+            ]
+            .iter()
+            .copied()
+            .filter(|x| x.is_on_board())
+            .collect();
+            assert_eq!(targets, chained);
+            expected.sort();
+            targets.sort();
+            assert_eq!(targets, expected);
+        }
+    }
+
+    #[test]
+    fn king_attacks() {
+        assert_eq!(KING_ATTACKS.get(A1), &[B1, A2, B2]);
+        assert_eq!(KING_ATTACKS.get(E4).len(), 8);
+        for pos in squares() {
+            let mut expected: Vec<Position> = squares()
+                .into_iter()
+                .filter(|x| pos.is_adjacent_to(*x))
+                .collect();
+            let mut targets: Vec<Position> = KING_ATTACKS.get(pos).to_vec();
+            expected.sort();
+            targets.sort();
+            assert_eq!(targets, expected);
+        }
+    }
+}